@@ -1,11 +1,39 @@
+mod body_analyzer;
 mod google_certs_provider;
+mod image_processor;
+mod media_repository;
+mod media_storage;
+mod pages_repository;
+mod post_query_repository;
 mod posts_repository;
-mod search_client;
+mod search_index;
+mod series_repository;
 
+pub use body_analyzer::BodyAnalyzer;
+#[cfg(test)]
+pub use body_analyzer::MockBodyAnalyzer;
 pub use google_certs_provider::GoogleCertsProvider;
+pub use image_processor::ImageProcessor;
+#[cfg(test)]
+pub use image_processor::MockImageProcessor;
+#[cfg(test)]
+pub use media_repository::MockMediaRepository;
+pub use media_repository::{MediaRepository, MediaRepositoryError};
+pub use media_storage::MediaStorage;
+#[cfg(test)]
+pub use media_storage::MockMediaStorage;
+#[cfg(test)]
+pub use pages_repository::MockPagesRepository;
+pub use pages_repository::PagesRepository;
+#[cfg(test)]
+pub use post_query_repository::MockPostQueryRepository;
+pub use post_query_repository::PostQueryRepository;
 #[cfg(test)]
 pub use posts_repository::MockPostsRepository;
-pub use posts_repository::PostsRepository;
+pub use posts_repository::{PostsRepository, PostsRepositoryError};
+#[cfg(test)]
+pub use search_index::MockSearchIndex;
+pub use search_index::SearchIndex;
 #[cfg(test)]
-pub use search_client::MockSearchClient;
-pub use search_client::SearchClient;
+pub use series_repository::MockSeriesRepository;
+pub use series_repository::{SeriesRepository, SeriesRepositoryError};
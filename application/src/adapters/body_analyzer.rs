@@ -0,0 +1,7 @@
+use domain::entities::PostStats;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait BodyAnalyzer {
+    /// 本文の文字数と読むのにかかる時間の目安を数えます
+    fn stats(&self, body: &str) -> PostStats;
+}
@@ -0,0 +1,10 @@
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait ImageProcessor {
+    /// 画像の幅と高さを返します。画像として読めなければ`None`を返します
+    fn dimensions(&self, data: &[u8]) -> Option<(u32, u32)>;
+    /// 画像を`width`×`height`に縮小し、元と同じ形式で返します
+    ///
+    /// デコードと縮小には時間がかかるので、非同期のワーカーを止めないように実装してください
+    async fn resize(&self, data: &[u8], width: u32, height: u32) -> anyhow::Result<Vec<u8>>;
+}
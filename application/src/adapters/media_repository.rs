@@ -0,0 +1,20 @@
+use domain::entities::{Media, MediaId, NewMedia};
+
+/// `MediaRepository`が返すエラーのうち、呼び出し側で扱いを変えたいもの
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum MediaRepositoryError {
+    /// 同じ名前のMediaがすでにある
+    #[error("Media name is already used")]
+    DuplicateName,
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait MediaRepository {
+    async fn get(&self, id: &MediaId) -> anyhow::Result<Option<Media>>;
+    /// すべてのMediaを`created_at`降順で返します
+    async fn get_all(&self) -> anyhow::Result<Vec<Media>>;
+    /// Mediaを追加します。名前がすでに使われているときは`MediaRepositoryError::DuplicateName`を返します
+    async fn add(&self, new_media: NewMedia) -> anyhow::Result<Media>;
+    async fn remove(&self, id: &MediaId) -> anyhow::Result<()>;
+}
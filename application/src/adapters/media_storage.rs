@@ -0,0 +1,9 @@
+/// アップロードされたファイルの中身を保存する場所
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait MediaStorage {
+    /// `name`という名前でファイルを保存します。同じ名前のファイルがあれば上書きします
+    async fn put(&self, name: &str, data: &[u8]) -> anyhow::Result<()>;
+    /// `name`という名前のファイルを削除します。ファイルがなければ何もしません
+    async fn delete(&self, name: &str) -> anyhow::Result<()>;
+}
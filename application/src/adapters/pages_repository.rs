@@ -0,0 +1,12 @@
+use domain::entities::{Page, PageName};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait PagesRepository {
+    async fn get(&self, name: &PageName) -> anyhow::Result<Option<Page>>;
+    /// すべてのPageを名前順で返します
+    async fn get_all(&self) -> anyhow::Result<Vec<Page>>;
+    /// Pageを保存します。同じ名前のPageがあれば上書きします
+    async fn save(&self, page: &Page) -> anyhow::Result<Page>;
+    async fn remove(&self, name: &PageName) -> anyhow::Result<()>;
+}
@@ -0,0 +1,143 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::entities::{PostId, Tag};
+
+use crate::models::{
+    Audience, Cursor, MonthDay, MonthlyStats, PostHeadline, SearchResult, TagCount, YearMonth,
+    YearMonthCount,
+};
+
+/// Postを日付やタグなどの条件で探すためのリポジトリ
+///
+/// キーワードでの検索は`SearchIndex`で行います
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait PostQueryRepository {
+    /// `ids`のうち`tags`がすべてついたPostのIDを`created_at`降順で返します。`ids`が`None`ならすべてのPostから絞り込みます
+    async fn find_by_ids_and_tags<'a>(
+        &self,
+        ids: Option<&'a [PostId]>,
+        tags: &'a [Tag],
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult>;
+    async fn find_by_year_month(
+        &self,
+        year_month: &YearMonth,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult>;
+    async fn find_by_date(
+        &self,
+        date: &NaiveDate,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult>;
+
+    /// 年によらず`month_day`の日に書かれたPostのIDを`created_at`降順で返します
+    async fn find_by_month_day(
+        &self,
+        month_day: &MonthDay,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult>;
+
+    /// `tag`のついたPostのIDを`created_at`降順で返します
+    async fn find_by_tag(
+        &self,
+        tag: &Tag,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult>;
+
+    /// Postのある年月とその月のPostの数を古い順に返します
+    async fn get_year_months(&self, audience: Audience) -> anyhow::Result<Vec<YearMonthCount>>;
+    /// Postの見出しを`created_at`降順で返します。`year`を指定するとその年のものだけを返します
+    async fn get_headlines(
+        &self,
+        year: Option<u16>,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostHeadline>>;
+    async fn get_days_in_year_month(
+        &self,
+        ym: &YearMonth,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<u8>>;
+    async fn get_latest_posts(
+        &self,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult>;
+    /// `cursor`より古いPostのIDを新しい順に最大`limit`件返します。`cursor`がなければ最新のPostから返します
+    async fn get_before_cursor<'a>(
+        &self,
+        cursor: Option<&'a Cursor>,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostId>>;
+    /// `cursor`より新しいPostのIDを古い順に最大`limit`件返します
+    async fn get_after_cursor(
+        &self,
+        cursor: &Cursor,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostId>>;
+    /// 無作為に選んだPostのIDを最大`limit`件返します
+    async fn get_random(&self, limit: usize, audience: Audience) -> anyhow::Result<Vec<PostId>>;
+    /// 先頭に固定されたPostのIDを固定した日時の降順ですべて返します
+    async fn get_pinned(&self, audience: Audience) -> anyhow::Result<Vec<PostId>>;
+    /// 月ごとの記事数と本文の統計の合計を古い順にすべて返します
+    async fn get_monthly_stats(&self, audience: Audience) -> anyhow::Result<Vec<MonthlyStats>>;
+    /// Postのある日付を古い順にすべて返します
+    async fn get_dates(&self, audience: Audience) -> anyhow::Result<Vec<NaiveDate>>;
+    /// 本文の文字数が多いPostのIDを多い順に最大`limit`件返します
+    async fn get_longest(&self, limit: usize, audience: Audience) -> anyhow::Result<Vec<PostId>>;
+    /// 本文の文字数が少ないPostのIDを少ない順に最大`limit`件返します
+    async fn get_shortest(&self, limit: usize, audience: Audience) -> anyhow::Result<Vec<PostId>>;
+    /// リビジョンの多いPostのIDとリビジョンの数を多い順に最大`limit`件返します
+    async fn get_most_revised(
+        &self,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<(PostId, usize)>>;
+    /// 本文の統計をまだ数えていないPostのIDをすべて返します。下書きやゴミ箱に入っているものも含みます
+    async fn get_without_stats(&self) -> anyhow::Result<Vec<PostId>>;
+    /// Postについているタグとその件数を名前順ですべて返します
+    async fn get_tags(&self, audience: Audience) -> anyhow::Result<Vec<TagCount>>;
+    /// 公開済みのPostのうち最も新しい`updated_at`を返します
+    async fn get_last_updated(&self) -> anyhow::Result<Option<DateTime<Utc>>>;
+    /// 下書きのPostのIDを`updated_at`降順ですべて返します
+    async fn get_drafts(&self) -> anyhow::Result<Vec<PostId>>;
+    /// ゴミ箱に入っているPostのIDを`deleted_at`降順ですべて返します
+    async fn get_trashed(&self) -> anyhow::Result<Vec<PostId>>;
+    /// `from`より後（`from`を**含まない**）、`until`以前（`until`を**含む**）に公開日時を迎えたPostのIDを`published_at`昇順ですべて返します
+    ///
+    /// 下書きは含みません
+    async fn get_published_between(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<PostId>>;
+
+    /// `from`以降（`from`を**含む**）のPostのIDを`created_at`昇順で最大`limit`件返します
+    async fn get_from_date(
+        &self,
+        from: DateTime<Utc>,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostId>>;
+    /// `until`以前（`until`を**含まない**）のPostのIDを`created_at`降順で最大`limit`件返します
+    async fn get_until_date(
+        &self,
+        until: DateTime<Utc>,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostId>>;
+}
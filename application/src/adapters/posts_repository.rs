@@ -1,11 +1,55 @@
-use domain::entities::{NewPost, Post, PostId};
+use chrono::{DateTime, Utc};
+use domain::entities::{NewPost, Post, PostId, PostStats, Revision, RevisionId, Slug, Tag};
+
+/// `PostsRepository`が返すエラーのうち、呼び出し側で扱いを変えたいもの
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum PostsRepositoryError {
+    /// 読み込んだときから別の保存でバージョンが進んでいた
+    #[error("Post {0} has been updated by another request")]
+    VersionConflict(PostId),
+    /// 同じスラッグのPostがすでにある
+    #[error("Slug is already used")]
+    DuplicateSlug,
+}
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait::async_trait]
 pub trait PostsRepository {
     async fn get_by_id(&self, id: &PostId) -> anyhow::Result<Option<Post>>;
     async fn get_by_ids(&self, ids: &[PostId]) -> anyhow::Result<Vec<Post>>;
+    /// スラッグからPostを取得します。ゴミ箱に入っているものも含みます
+    async fn get_by_slug(&self, slug: &Slug) -> anyhow::Result<Option<Post>>;
+    /// Postを追加します。タグと最初のリビジョンも同じトランザクションで記録します
+    ///
+    /// スラッグがすでに使われているときは`PostsRepositoryError::DuplicateSlug`を返します
     async fn add(&self, new_post: NewPost) -> anyhow::Result<Post>;
+    /// Postを保存します。タグを`post.tags`に置き換え、タイトルか本文が変わっていれば新しいリビジョンを記録します
+    ///
+    /// どれも同じトランザクションで行います。`post.version`が保存されているバージョンと異なるときは
+    /// `PostsRepositoryError::VersionConflict`を、スラッグがすでに使われているときは
+    /// `PostsRepositoryError::DuplicateSlug`を返します
     async fn save(&self, post: &Post) -> anyhow::Result<Post>;
+    /// Postを完全に削除します
     async fn remove(&self, id: &PostId) -> anyhow::Result<()>;
+    /// Postをゴミ箱に入れます
+    async fn move_to_trash(&self, id: &PostId) -> anyhow::Result<()>;
+    /// Postをトップページの先頭に固定します。`None`なら固定を外します
+    ///
+    /// 記事の内容は変わらないので、バージョンもリビジョンも更新しません
+    async fn set_pinned_at(
+        &self,
+        id: &PostId,
+        pinned_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()>;
+    /// Postの本文の統計を保存します。バージョンもリビジョンも更新しません
+    async fn set_stats(&self, id: &PostId, stats: &PostStats) -> anyhow::Result<()>;
+    /// ゴミ箱からPostを戻します
+    async fn restore_from_trash(&self, id: &PostId) -> anyhow::Result<Post>;
+    /// Postにタグをつけます。すでについているタグは無視します
+    async fn attach_tags(&self, id: &PostId, tags: &[Tag]) -> anyhow::Result<()>;
+    /// Postからタグを外します
+    async fn detach_tags(&self, id: &PostId, tags: &[Tag]) -> anyhow::Result<()>;
+    /// Postのリビジョンを新しい順ですべて返します
+    async fn get_revisions(&self, id: &PostId) -> anyhow::Result<Vec<Revision>>;
+    async fn get_revision(&self, id: &RevisionId) -> anyhow::Result<Option<Revision>>;
 }
@@ -0,0 +1,14 @@
+use domain::entities::{Post, PostId};
+
+/// Postの全文検索のための索引
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait SearchIndex {
+    /// タイトルか本文に`keywords`をすべて含むPostのIDを返します。順番は決まっていません
+    ///
+    /// 下書きや公開していないPostも含むので、見せてよいものは`PostQueryRepository`で絞り込みます
+    async fn search<'a>(&self, keywords: &'a [&'a str]) -> anyhow::Result<Vec<PostId>>;
+    /// Postを索引に入れます。すでにあれば置き換えます
+    async fn save(&self, post: &Post) -> anyhow::Result<()>;
+    async fn delete(&self, id: &PostId) -> anyhow::Result<()>;
+}
@@ -0,0 +1,26 @@
+use domain::entities::{PostId, Series, SeriesId};
+
+/// `SeriesRepository`が返すエラーのうち、呼び出し側で扱いを変えたいもの
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum SeriesRepositoryError {
+    /// 記事がすでにほかのSeriesに入っている
+    #[error("Post is already in another series")]
+    PostAlreadyInSeries,
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait SeriesRepository {
+    async fn get(&self, id: &SeriesId) -> anyhow::Result<Option<Series>>;
+    /// すべてのSeriesをID順で返します
+    async fn get_all(&self) -> anyhow::Result<Vec<Series>>;
+    /// 記事が入っているSeriesを返します
+    async fn get_by_post_id(&self, post_id: &PostId) -> anyhow::Result<Option<Series>>;
+    /// 記事の入っていないSeriesを作成します
+    async fn add(&self, title: &str) -> anyhow::Result<Series>;
+    /// タイトルと記事の並び順を保存します
+    ///
+    /// 記事がすでにほかのSeriesに入っているときは`SeriesRepositoryError::PostAlreadyInSeries`を返します
+    async fn save(&self, series: &Series) -> anyhow::Result<Series>;
+    async fn remove(&self, id: &SeriesId) -> anyhow::Result<()>;
+}
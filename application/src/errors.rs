@@ -1,11 +1,37 @@
+use domain::entities::Post;
+
 #[derive(thiserror::Error, Debug)]
 pub enum ApplicationError {
     #[error("Not Found")]
     PostNotFound,
+    /// 編集を始めてから他の編集が保存された。現在保存されているPostを持つ
+    #[error("Post Conflict")]
+    PostConflict(Box<Post>),
+    /// 同じスラッグのPostがすでに存在する
+    #[error("Duplicate Slug")]
+    DuplicateSlug,
+    #[error("Page Not Found")]
+    PageNotFound,
+    #[error("Series Not Found")]
+    SeriesNotFound,
+    /// 記事がすでにほかのシリーズに入っている
+    #[error("Post Already In Series")]
+    PostAlreadyInSeries,
+    #[error("Media Not Found")]
+    MediaNotFound,
+    /// 同じ名前のMediaがすでに存在する
+    #[error("Duplicate Media Name")]
+    DuplicateMediaName,
+    #[error("Revision Not Found")]
+    RevisionNotFound,
     #[error("Invalid YearMonth")]
     InvalidYearMonth,
+    #[error("Invalid MonthDay")]
+    InvalidMonthDay,
     #[error("Invalid PageNumber")]
     InvalidPageNumber,
+    #[error("Invalid Cursor")]
+    InvalidCursor,
     #[error(transparent)]
     JwtError(#[from] jsonwebtoken::errors::Error),
     #[error(transparent)]
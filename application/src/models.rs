@@ -1,9 +1,34 @@
+mod archive;
+mod audience;
 mod config;
+mod cursor;
+mod month_day;
+mod monthly_stats;
 mod page;
+mod post_headline;
 mod search_result;
+mod series_navigation;
+mod statistics;
+mod tag_count;
+mod uploaded_file;
 mod year_month;
+mod year_month_count;
 
-pub use config::{AuthenticationSettings, Author, Config, Link, Site};
+pub use archive::{ArchiveMonth, ArchiveYear, YearArchive};
+pub use audience::Audience;
+pub use config::{
+    AuthenticationSettings, Author, Config, FrontPageSettings, Link, ListingSettings, ListingStyle,
+    PaginationSettings, SearchEngine, SearchSettings, Site, TrashSettings,
+};
+pub use cursor::{Cursor, PageCursor};
+pub use month_day::MonthDay;
+pub use monthly_stats::MonthlyStats;
 pub use page::{AdjacentPageInfo, Page, PageNumber};
+pub use post_headline::PostHeadline;
 pub use search_result::SearchResult;
+pub use series_navigation::{SeriesNavigation, SeriesPosts};
+pub use statistics::{Statistics, Streak, YearlyStats};
+pub use tag_count::TagCount;
+pub use uploaded_file::UploadedFile;
 pub use year_month::YearMonth;
+pub use year_month_count::YearMonthCount;
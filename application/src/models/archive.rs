@@ -0,0 +1,70 @@
+use std::cmp::Reverse;
+
+use super::{PostHeadline, YearMonth, YearMonthCount};
+
+/// 1年分の記事の見出し
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveYear {
+    pub year: u16,
+    /// 新しい順
+    pub months: Vec<ArchiveMonth>,
+}
+
+/// 1か月分の記事の見出し
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveMonth {
+    pub year_month: YearMonth,
+    pub count: usize,
+    /// 新しい順
+    pub posts: Vec<PostHeadline>,
+}
+
+impl ArchiveYear {
+    pub fn count(&self) -> usize {
+        self.months.iter().map(|month| month.count).sum()
+    }
+
+    /// 月ごとの記事数と見出しを年ごとにまとめて新しい順に返します
+    pub fn group(year_months: &[YearMonthCount], headlines: Vec<PostHeadline>) -> Vec<Self> {
+        let mut months = year_months
+            .iter()
+            .map(|ym| ArchiveMonth {
+                year_month: ym.year_month,
+                count: ym.count,
+                posts: vec![],
+            })
+            .collect::<Vec<_>>();
+        months.sort_by_key(|month| Reverse(month.year_month));
+        for headline in headlines {
+            let year_month = YearMonth::from(headline.created_at);
+            if let Some(month) = months.iter_mut().find(|m| m.year_month == year_month) {
+                month.posts.push(headline);
+            }
+        }
+        for month in &mut months {
+            month.posts.sort_by_key(|post| Reverse(post.created_at));
+        }
+
+        let mut years: Vec<Self> = vec![];
+        for month in months {
+            match years.last_mut() {
+                Some(year) if year.year == month.year_month.year => year.months.push(month),
+                _ => years.push(Self {
+                    year: month.year_month.year,
+                    months: vec![month],
+                }),
+            }
+        }
+        years
+    }
+}
+
+/// 年ごとのアーカイブのページ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearArchive {
+    pub archive: ArchiveYear,
+    /// 記事のある前の年
+    pub prev_year: Option<u16>,
+    /// 記事のある次の年
+    pub next_year: Option<u16>,
+}
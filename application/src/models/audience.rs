@@ -0,0 +1,34 @@
+use domain::entities::Post;
+
+/// 記事を閲覧する主体。公開前の記事を含めるかどうかを決めます
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Audience {
+    /// 一般の閲覧者。公開済みで非公開でない記事のみが対象
+    #[default]
+    Public,
+    /// ログイン済みの管理者。下書きや非公開の記事を含むすべての記事が対象
+    Admin,
+}
+
+impl Audience {
+    pub fn new(is_authorized: bool) -> Self {
+        if is_authorized {
+            Self::Admin
+        } else {
+            Self::Public
+        }
+    }
+
+    /// 記事を閲覧できるかどうかを返します。ゴミ箱に入っている記事は誰も閲覧できません
+    pub fn can_read(&self, post: &Post) -> bool {
+        if post.is_deleted() {
+            return false;
+        }
+        *self == Self::Admin || !(post.is_draft() || post.is_scheduled() || post.is_private())
+    }
+
+    /// 記事を一覧に載せてよいかどうかを返します。限定公開の記事はURLを知っている人だけが読める
+    pub fn can_list(&self, post: &Post) -> bool {
+        self.can_read(post) && (*self == Self::Admin || post.is_listed())
+    }
+}
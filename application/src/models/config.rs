@@ -1,3 +1,5 @@
+use std::{num::NonZeroUsize, path::PathBuf};
+
 use serde::Deserialize;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -8,6 +10,16 @@ pub struct Config {
     pub hatena_star_token: String,
     /// Google Analytics トラッキングコード
     pub ga_code: String,
+    #[serde(default)]
+    pub trash: TrashSettings,
+    #[serde(default)]
+    pub listing: ListingSettings,
+    #[serde(default)]
+    pub front_page: FrontPageSettings,
+    #[serde(default)]
+    pub pagination: PaginationSettings,
+    #[serde(default)]
+    pub search: SearchSettings,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -15,7 +27,6 @@ pub struct Site {
     pub title: String,
     pub description: String,
     pub generator: String,
-    pub about: String,
     pub url: url::Url,
     pub links: Vec<Link>,
     pub hash: String,
@@ -41,3 +52,115 @@ pub struct AuthenticationSettings {
     /// 管理者になるユーザーのGoogle User ID
     pub admin_user_id: String,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrashSettings {
+    /// ゴミ箱に入れた記事を完全に削除するまでの日数
+    pub retention_days: u32,
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        Self { retention_days: 30 }
+    }
+}
+
+/// 記事一覧やフィードに本文をどう表示するか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ListingStyle {
+    /// 本文をすべて表示する
+    #[default]
+    Full,
+    /// 要約か本文の抜粋だけを表示する
+    Summary,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListingSettings {
+    #[serde(default)]
+    pub style: ListingStyle,
+    /// 要約がないときに本文から切り出す抜粋の最大文字数
+    pub excerpt_length: usize,
+}
+
+impl ListingSettings {
+    pub fn is_summary(&self) -> bool {
+        self.style == ListingStyle::Summary
+    }
+}
+
+impl Default for ListingSettings {
+    fn default() -> Self {
+        Self {
+            style: ListingStyle::default(),
+            excerpt_length: 200,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct FrontPageSettings {
+    /// トップページに過去の同じ日付の記事を表示するかどうか
+    #[serde(default)]
+    pub on_this_day: bool,
+}
+
+/// 1ページに表示する記事の数
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PaginationSettings {
+    /// トップページ
+    pub front_page: NonZeroUsize,
+    /// 日付や年月、タグごとの記事一覧
+    pub archives: NonZeroUsize,
+    /// 検索結果
+    pub search: NonZeroUsize,
+    /// Atomフィード
+    pub feeds: NonZeroUsize,
+}
+
+impl Default for PaginationSettings {
+    fn default() -> Self {
+        let per_page = NonZeroUsize::new(10).unwrap();
+        Self {
+            front_page: per_page,
+            archives: per_page,
+            search: per_page,
+            feeds: per_page,
+        }
+    }
+}
+
+/// キーワード検索に使うエンジン
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchEngine {
+    /// Elasticsearchで検索する。失敗したときはPostgreSQLで検索する
+    #[default]
+    Elasticsearch,
+    /// PostgreSQLだけで検索する。Elasticsearchには接続しない
+    Postgres,
+    /// ファイルに保存したTantivyの索引で検索する。失敗したときはPostgreSQLで検索する
+    Tantivy,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SearchSettings {
+    pub engine: SearchEngine,
+    /// Tantivyの索引を保存するディレクトリ
+    pub index_path: PathBuf,
+    /// Tantivyで形態素解析に使うLinderaの辞書のディレクトリ。なければbigramだけで検索する
+    pub dictionary_path: Option<PathBuf>,
+}
+
+impl Default for SearchSettings {
+    fn default() -> Self {
+        Self {
+            engine: SearchEngine::default(),
+            index_path: PathBuf::from("search_index"),
+            dictionary_path: None,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, TimeZone as _, Utc};
+use domain::entities::{Post, PostId};
+
+use crate::errors::ApplicationError;
+
+/// 記事の並び（`created_at`降順、同じなら`id`降順）の中の位置
+///
+/// URLでは`{created_at のUNIX時間のマイクロ秒}_{id}`と表します
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub id: PostId,
+}
+
+impl Cursor {
+    pub fn new(post: &Post) -> Self {
+        Self {
+            created_at: post.created_at,
+            id: post.id,
+        }
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.created_at.timestamp_micros(), self.id)
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = ApplicationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (micros, id) = s.split_once('_').ok_or(ApplicationError::InvalidCursor)?;
+        let micros = micros
+            .parse::<i64>()
+            .map_err(|_| ApplicationError::InvalidCursor)?;
+        let id = id
+            .parse::<i32>()
+            .map_err(|_| ApplicationError::InvalidCursor)?;
+        Ok(Self {
+            created_at: Utc
+                .timestamp_micros(micros)
+                .single()
+                .ok_or(ApplicationError::InvalidCursor)?,
+            id: PostId(id),
+        })
+    }
+}
+
+/// カーソルで指定したページの位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageCursor {
+    /// 最新の記事から
+    #[default]
+    Latest,
+    /// カーソルより古い記事
+    Before(Cursor),
+    /// カーソルより新しい記事
+    After(Cursor),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cursor_round_trip() {
+        let cursor = Cursor {
+            created_at: Utc.with_ymd_and_hms(2021, 1, 2, 3, 4, 5).unwrap(),
+            id: PostId(42),
+        };
+        assert_eq!(cursor.to_string(), "1609556645000000_42");
+        assert_eq!("1609556645000000_42".parse::<Cursor>().unwrap(), cursor);
+        assert_matches!("42".parse::<Cursor>(), Err(ApplicationError::InvalidCursor));
+        assert_matches!(
+            "a_42".parse::<Cursor>(),
+            Err(ApplicationError::InvalidCursor)
+        );
+    }
+}
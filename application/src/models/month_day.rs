@@ -0,0 +1,74 @@
+use chrono::{Datelike as _, Days, NaiveDate};
+
+use crate::{errors::ApplicationError, ApplicationResult};
+
+/// 年によらない月日。2月29日も含む
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, Hash)]
+#[non_exhaustive]
+pub struct MonthDay {
+    pub month: u8,
+    pub day: u8,
+}
+
+impl MonthDay {
+    pub fn new(month: u8, day: u8) -> ApplicationResult<Self> {
+        Self::in_leap_year(month, day).ok_or(ApplicationError::InvalidMonthDay)?;
+        Ok(Self { month, day })
+    }
+
+    /// 前の日を返します。1月1日の前は12月31日
+    pub fn prev(self) -> Self {
+        let date = Self::in_leap_year(self.month, self.day).expect("valid month and day");
+        match date.checked_sub_days(Days::new(1)) {
+            Some(prev) if prev.year() == date.year() => prev.into(),
+            _ => Self { month: 12, day: 31 },
+        }
+    }
+
+    /// 次の日を返します。12月31日の次は1月1日
+    pub fn next(self) -> Self {
+        let date = Self::in_leap_year(self.month, self.day).expect("valid month and day");
+        match date.checked_add_days(Days::new(1)) {
+            Some(next) if next.year() == date.year() => next.into(),
+            _ => Self { month: 1, day: 1 },
+        }
+    }
+
+    /// 2月29日も扱えるように、うるう年の日付にします
+    fn in_leap_year(month: u8, day: u8) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(2000, month as u32, day as u32)
+    }
+}
+
+impl From<NaiveDate> for MonthDay {
+    fn from(date: NaiveDate) -> Self {
+        MonthDay {
+            month: date.month() as u8,
+            day: date.day() as u8,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn leap_day_is_valid() {
+        assert!(MonthDay::new(2, 29).is_ok());
+        assert_matches!(MonthDay::new(2, 30), Err(ApplicationError::InvalidMonthDay));
+        assert_matches!(MonthDay::new(13, 1), Err(ApplicationError::InvalidMonthDay));
+    }
+
+    #[test]
+    fn adjacent_days_wrap_around_year() {
+        let new_year = MonthDay::new(1, 1).unwrap();
+        assert_eq!(new_year.prev(), MonthDay::new(12, 31).unwrap());
+        assert_eq!(new_year.prev().next(), new_year);
+        assert_eq!(
+            MonthDay::new(2, 28).unwrap().next(),
+            MonthDay::new(2, 29).unwrap()
+        );
+    }
+}
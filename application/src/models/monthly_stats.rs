@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+use super::YearMonth;
+
+/// 月ごとの記事数と本文の統計の合計
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyStats {
+    pub year_month: YearMonth,
+    pub post_count: usize,
+    pub char_count: usize,
+    pub reading_minutes: usize,
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{PostId, Slug};
+use serde::Serialize;
+
+/// 一覧に表示するためのPostの見出し。本文は読み込まない
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostHeadline {
+    pub id: PostId,
+    pub title: String,
+    pub slug: Option<Slug>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,20 @@
+use domain::entities::{Post, Series};
+
+/// Seriesと、一覧に載せてよい記事を並び順で並べたもの
+#[derive(Debug, Clone)]
+pub struct SeriesPosts {
+    pub series: Series,
+    pub posts: Vec<Post>,
+}
+
+/// 記事が入っているSeriesの中での前後の記事
+#[derive(Debug, Clone)]
+pub struct SeriesNavigation {
+    pub series: Series,
+    /// Seriesの中で何番目の記事か（1始まり）
+    pub position: usize,
+    /// Seriesに入っている一覧に載せてよい記事の数
+    pub count: usize,
+    pub prev: Option<Post>,
+    pub next: Option<Post>,
+}
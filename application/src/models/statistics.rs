@@ -0,0 +1,121 @@
+use chrono::{Days, NaiveDate};
+use domain::entities::Post;
+
+use super::MonthlyStats;
+
+/// 管理ページに表示する統計
+#[derive(Debug, Clone)]
+pub struct Statistics {
+    /// 月ごとの記事数と文字数。古い順
+    pub monthly: Vec<MonthlyStats>,
+    /// 連続して記事を書いた最長の期間
+    pub longest_streak: Option<Streak>,
+    /// 今日か昨日まで続いている連続して記事を書いた期間
+    pub current_streak: Option<Streak>,
+    /// 文字数の多い記事
+    pub longest_posts: Vec<Post>,
+    /// 文字数の少ない記事
+    pub shortest_posts: Vec<Post>,
+    /// リビジョンの多い記事とそのリビジョンの数
+    pub most_revised_posts: Vec<(Post, usize)>,
+}
+
+impl Statistics {
+    /// 年ごとに集計し直した統計を古い順に返します
+    pub fn yearly(&self) -> Vec<YearlyStats> {
+        let mut yearly: Vec<YearlyStats> = vec![];
+        for month in &self.monthly {
+            match yearly.last_mut() {
+                Some(year) if year.year == month.year_month.year => {
+                    year.post_count += month.post_count;
+                    year.char_count += month.char_count;
+                }
+                _ => yearly.push(YearlyStats {
+                    year: month.year_month.year,
+                    post_count: month.post_count,
+                    char_count: month.char_count,
+                }),
+            }
+        }
+        yearly
+    }
+
+    pub fn total_post_count(&self) -> usize {
+        self.monthly.iter().map(|month| month.post_count).sum()
+    }
+
+    pub fn total_char_count(&self) -> usize {
+        self.monthly.iter().map(|month| month.char_count).sum()
+    }
+}
+
+/// 年ごとの記事数と文字数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearlyStats {
+    pub year: u16,
+    pub post_count: usize,
+    pub char_count: usize,
+}
+
+/// 毎日記事を書いた期間。`from`と`until`を**含む**
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    pub from: NaiveDate,
+    pub until: NaiveDate,
+}
+
+impl Streak {
+    /// 期間の日数を返します
+    pub fn days(&self) -> usize {
+        (self.until - self.from).num_days() as usize + 1
+    }
+
+    /// 昇順に並んだ重複のない日付を、連続した期間に分けます
+    pub fn from_dates(dates: &[NaiveDate]) -> Vec<Streak> {
+        let mut streaks: Vec<Streak> = vec![];
+        for &date in dates {
+            match streaks.last_mut() {
+                Some(streak) if streak.until.checked_add_days(Days::new(1)) == Some(date) => {
+                    streak.until = date;
+                }
+                _ => streaks.push(Streak {
+                    from: date,
+                    until: date,
+                }),
+            }
+        }
+        streaks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    #[test]
+    fn streaks_from_dates() {
+        let streaks = Streak::from_dates(&[date(1), date(2), date(3), date(5), date(7), date(8)]);
+        assert_eq!(
+            streaks,
+            vec![
+                Streak {
+                    from: date(1),
+                    until: date(3)
+                },
+                Streak {
+                    from: date(5),
+                    until: date(5)
+                },
+                Streak {
+                    from: date(7),
+                    until: date(8)
+                },
+            ]
+        );
+        assert_eq!(streaks[0].days(), 3);
+    }
+}
@@ -0,0 +1,9 @@
+use domain::entities::Tag;
+use serde::Serialize;
+
+/// タグとそのタグがついたPostの数
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TagCount {
+    pub tag: Tag,
+    pub count: usize,
+}
@@ -0,0 +1,8 @@
+/// アップロードされたファイル
+#[derive(Debug, Clone, Copy)]
+pub struct UploadedFile<'a> {
+    /// 元のファイル名
+    pub name: &'a str,
+    pub content_type: &'a str,
+    pub data: &'a [u8],
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+use super::YearMonth;
+
+/// 年月とその月に書かれたPostの数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct YearMonthCount {
+    #[serde(flatten)]
+    pub year_month: YearMonth,
+    pub count: usize,
+}
@@ -1,25 +1,66 @@
 mod authenticate;
 mod create_new_post;
 mod delete_post;
+mod fill_post_stats;
+mod get_archive;
 mod get_days_in_year_month;
+mod get_drafts;
 mod get_last_updated_date;
 mod get_latest_posts;
+mod get_monthly_stats;
 mod get_post_by_id;
+mod get_post_id_by_slug;
 mod get_posts_by_date;
+mod get_posts_by_tag;
 mod get_posts_by_year_month;
+mod get_posts_on_this_day;
+mod get_random_posts;
+mod get_revisions;
+mod get_statistics;
+mod get_tags;
 mod get_year_months;
+mod media;
+mod pages;
+mod pin_post;
+mod publish_scheduled_posts;
+mod restore_revision;
 mod search_posts;
+mod series;
+mod trash;
 mod update_post;
 
 pub use authenticate::AuthenticateUseCase;
 pub use create_new_post::CreateNewPostUseCase;
 pub use delete_post::DeletePostUseCase;
+pub use fill_post_stats::FillPostStatsUseCase;
+pub use get_archive::{GetArchiveUseCase, GetYearArchiveUseCase};
 pub use get_days_in_year_month::GetDaysInYearMonthUseCase;
+pub use get_drafts::GetDraftsUseCase;
 pub use get_last_updated_date::GetLastUpdatedDateUseCase;
-pub use get_latest_posts::GetLatestPostsUseCase;
+pub use get_latest_posts::{GetLatestPostsByCursorUseCase, GetLatestPostsUseCase};
+pub use get_monthly_stats::GetMonthlyStatsUseCase;
 pub use get_post_by_id::GetPostByIdUseCase;
+pub use get_post_id_by_slug::GetPostIdBySlugUseCase;
 pub use get_posts_by_date::GetPostsByDateUseCase;
+pub use get_posts_by_tag::GetPostsByTagUseCase;
 pub use get_posts_by_year_month::GetPostsByYearMonthUseCase;
+pub use get_posts_on_this_day::GetPostsOnThisDayUseCase;
+pub use get_random_posts::GetRandomPostsUseCase;
+pub use get_revisions::{GetRevisionUseCase, GetRevisionsUseCase};
+pub use get_statistics::GetStatisticsUseCase;
+pub use get_tags::GetTagsUseCase;
 pub use get_year_months::GetYearMonthsUseCase;
+pub use media::{DeleteMediaUseCase, GetAllMediaUseCase, UploadMediaUseCase};
+pub use pages::{DeletePageUseCase, GetPageUseCase, GetPagesUseCase, SavePageUseCase};
+pub use pin_post::{PinPostUseCase, UnpinPostUseCase};
+pub use publish_scheduled_posts::PublishScheduledPostsUseCase;
+pub use restore_revision::RestoreRevisionUseCase;
 pub use search_posts::SearchPostsUseCase;
+pub use series::{
+    AddPostToSeriesUseCase, CreateSeriesUseCase, DeleteSeriesUseCase, GetAllSeriesUseCase,
+    GetSeriesNavigationUseCase, GetSeriesUseCase, UpdateSeriesUseCase,
+};
+pub use trash::{
+    GetTrashedPostsUseCase, PurgeExpiredPostsUseCase, PurgePostUseCase, RestorePostUseCase,
+};
 pub use update_post::UpdatePostUseCase;
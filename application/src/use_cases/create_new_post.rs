@@ -1,22 +1,72 @@
 use domain::entities::{NewPost, Post};
 
 use crate::{
-    adapters::{PostsRepository, SearchClient},
+    adapters::{BodyAnalyzer, PostsRepository, PostsRepositoryError, SearchIndex},
+    errors::ApplicationError,
     ApplicationResult,
 };
 
 pub struct CreateNewPostUseCase;
 
 impl CreateNewPostUseCase {
+    /// 本文の統計を数えてPostを追加します
     pub async fn execute(
-        posts: &impl PostsRepository,
-        search_client: &impl SearchClient,
-        new_post: NewPost,
+        posts: &(impl PostsRepository + ?Sized),
+        search_index: &(impl SearchIndex + ?Sized),
+        body_analyzer: &impl BodyAnalyzer,
+        mut new_post: NewPost,
     ) -> ApplicationResult<Post> {
-        let post = posts.add(new_post).await?;
-        if let Err(e) = search_client.save(&post).await {
+        if let Some(slug) = &new_post.slug {
+            if posts.get_by_slug(slug).await?.is_some() {
+                return Err(ApplicationError::DuplicateSlug);
+            }
+        }
+        new_post.stats = Some(body_analyzer.stats(&new_post.body));
+        // 確かめてから追加するまでに同じスラッグで追加されることもある
+        let post = posts
+            .add(new_post)
+            .await
+            .map_err(|e| match e.downcast_ref() {
+                Some(PostsRepositoryError::DuplicateSlug) => ApplicationError::DuplicateSlug,
+                _ => e.into(),
+            })?;
+        if let Err(e) = search_index.save(&post).await {
             log::warn!("failed to create search index: {e}");
         }
         Ok(post)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use assert_matches::assert_matches;
+    use chrono::Utc;
+    use domain::entities::{PostStats, Slug};
+
+    #[tokio::test]
+    async fn test_create_new_post_duplicate_slug_while_adding() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let mut new_post = NewPost::new("title", "body", Utc::now());
+        new_post.slug = Some(Slug::new("hello").unwrap());
+        // 確かめたときはなかったが、追加するまでに同じスラッグで追加された
+        mock_posts.expect_get_by_slug().returning(|_| Ok(None));
+        mock_posts
+            .expect_add()
+            .times(1)
+            .returning(|_| Err(PostsRepositoryError::DuplicateSlug.into()));
+        let mut mock_analyzer = MockBodyAnalyzer::new();
+        mock_analyzer
+            .expect_stats()
+            .returning(|body| PostStats::new(body.chars().count()));
+        mock_search.expect_save().never();
+
+        let result =
+            CreateNewPostUseCase::execute(&mock_posts, &mock_search, &mock_analyzer, new_post)
+                .await;
+
+        assert_matches!(result, Err(ApplicationError::DuplicateSlug));
+    }
+}
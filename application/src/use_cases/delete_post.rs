@@ -1,20 +1,21 @@
 use domain::entities::PostId;
 
 use crate::{
-    adapters::{PostsRepository, SearchClient},
+    adapters::{PostsRepository, SearchIndex},
     ApplicationResult,
 };
 
 pub struct DeletePostUseCase;
 
 impl DeletePostUseCase {
+    /// Postをゴミ箱に入れます。完全に削除するには`PurgePostUseCase`を使います
     pub async fn execute(
-        posts: &impl PostsRepository,
-        search_client: &impl SearchClient,
+        posts: &(impl PostsRepository + ?Sized),
+        search_index: &(impl SearchIndex + ?Sized),
         id: &PostId,
     ) -> ApplicationResult<()> {
-        posts.remove(id).await?;
-        if let Err(e) = search_client.delete(id).await {
+        posts.move_to_trash(id).await?;
+        if let Err(e) = search_index.delete(id).await {
             log::warn!("failed to delete search index: {e}");
         }
         Ok(())
@@ -0,0 +1,28 @@
+use domain::entities::PostId;
+
+use crate::{
+    adapters::{BodyAnalyzer, PostQueryRepository, PostsRepository},
+    ApplicationResult,
+};
+
+pub struct FillPostStatsUseCase;
+
+impl FillPostStatsUseCase {
+    /// 本文の統計をまだ数えていないPostについて数えて保存し、そのPostのIDを返します
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        body_analyzer: &impl BodyAnalyzer,
+    ) -> ApplicationResult<Vec<PostId>> {
+        let post_ids = post_queries.get_without_stats().await?;
+        if post_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        for post in posts.get_by_ids(&post_ids).await? {
+            posts
+                .set_stats(&post.id, &body_analyzer.stats(&post.body))
+                .await?;
+        }
+        Ok(post_ids)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    adapters::PostQueryRepository,
+    models::{ArchiveYear, Audience, YearArchive},
+    ApplicationResult,
+};
+
+pub struct GetArchiveUseCase;
+
+impl GetArchiveUseCase {
+    /// すべてのPostの見出しを年月ごとにまとめて返します
+    pub async fn execute(
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        audience: Audience,
+    ) -> ApplicationResult<Vec<ArchiveYear>> {
+        let year_months = post_queries.get_year_months(audience).await?;
+        let headlines = post_queries.get_headlines(None, audience).await?;
+        Ok(ArchiveYear::group(&year_months, headlines))
+    }
+}
+
+pub struct GetYearArchiveUseCase;
+
+impl GetYearArchiveUseCase {
+    /// `year`に書かれたPostの見出しを月ごとにまとめて返します。Postがなければ`None`を返します
+    pub async fn execute(
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        year: u16,
+        audience: Audience,
+    ) -> ApplicationResult<Option<YearArchive>> {
+        let year_months = post_queries.get_year_months(audience).await?;
+        let prev_year = year_months
+            .iter()
+            .map(|ym| ym.year_month.year)
+            .filter(|y| *y < year)
+            .max();
+        let next_year = year_months
+            .iter()
+            .map(|ym| ym.year_month.year)
+            .filter(|y| *y > year)
+            .min();
+        let year_months = year_months
+            .into_iter()
+            .filter(|ym| ym.year_month.year == year)
+            .collect::<Vec<_>>();
+        if year_months.is_empty() {
+            return Ok(None);
+        }
+        let headlines = post_queries.get_headlines(Some(year), audience).await?;
+        Ok(ArchiveYear::group(&year_months, headlines)
+            .pop()
+            .map(|archive| YearArchive {
+                archive,
+                prev_year,
+                next_year,
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::MockPostQueryRepository,
+        models::{PostHeadline, YearMonth, YearMonthCount},
+    };
+    use chrono::{Local, TimeZone as _, Utc};
+    use domain::entities::PostId;
+    use mockall::predicate::*;
+    use pretty_assertions::assert_eq;
+
+    fn headline(id: i32, month: u32, day: u32) -> PostHeadline {
+        PostHeadline {
+            id: PostId(id),
+            title: format!("title {id}"),
+            slug: None,
+            created_at: Local
+                .with_ymd_and_hms(2021, month, day, 0, 0, 0)
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_year_archive() {
+        let mut mock_queries = MockPostQueryRepository::new();
+        mock_queries.expect_get_year_months().returning(|_| {
+            Ok([(2019, 12, 1), (2021, 1, 2), (2021, 3, 1), (2023, 5, 4)]
+                .into_iter()
+                .map(|(y, m, count)| YearMonthCount {
+                    year_month: YearMonth::new(y, m).unwrap(),
+                    count,
+                })
+                .collect())
+        });
+        mock_queries
+            .expect_get_headlines()
+            .with(eq(Some(2021)), eq(Audience::Public))
+            .returning(|_, _| {
+                Ok(vec![
+                    headline(3, 3, 2),
+                    headline(2, 1, 5),
+                    headline(1, 1, 3),
+                ])
+            });
+
+        let year = GetYearArchiveUseCase::execute(&mock_queries, 2021, Audience::Public)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(year.prev_year, Some(2019));
+        assert_eq!(year.next_year, Some(2023));
+        assert_eq!(year.archive.count(), 3);
+        // 月も記事も新しい順
+        let months = year
+            .archive
+            .months
+            .iter()
+            .map(|m| (m.year_month.month, m.posts.iter().map(|p| p.id).collect()))
+            .collect::<Vec<(u8, Vec<PostId>)>>();
+        assert_eq!(
+            months,
+            vec![(3, vec![PostId(3)]), (1, vec![PostId(2), PostId(1)])]
+        );
+
+        let year = GetYearArchiveUseCase::execute(&mock_queries, 2020, Audience::Public)
+            .await
+            .unwrap();
+        assert_eq!(year, None);
+    }
+}
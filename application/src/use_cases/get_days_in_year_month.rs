@@ -1,12 +1,17 @@
-use crate::{adapters::SearchClient, models::YearMonth, ApplicationResult};
+use crate::{
+    adapters::PostQueryRepository,
+    models::{Audience, YearMonth},
+    ApplicationResult,
+};
 
 pub struct GetDaysInYearMonthUseCase;
 
 impl GetDaysInYearMonthUseCase {
     pub async fn execute(
-        search_client: &impl SearchClient,
+        post_queries: &(impl PostQueryRepository + ?Sized),
         ym: &YearMonth,
+        audience: Audience,
     ) -> ApplicationResult<Vec<u8>> {
-        Ok(search_client.get_days_in_year_month(ym).await?)
+        Ok(post_queries.get_days_in_year_month(ym, audience).await?)
     }
 }
@@ -0,0 +1,21 @@
+use domain::entities::Post;
+
+use crate::{
+    adapters::{PostQueryRepository, PostsRepository},
+    ApplicationResult,
+};
+
+pub struct GetDraftsUseCase;
+
+impl GetDraftsUseCase {
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+    ) -> ApplicationResult<Vec<Post>> {
+        let post_ids = post_queries.get_drafts().await?;
+        if post_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(posts.get_by_ids(&post_ids).await?)
+    }
+}
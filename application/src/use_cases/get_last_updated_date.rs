@@ -1,13 +1,13 @@
 use chrono::{DateTime, Utc};
 
-use crate::adapters::SearchClient;
+use crate::adapters::PostQueryRepository;
 
 pub struct GetLastUpdatedDateUseCase;
 
 impl GetLastUpdatedDateUseCase {
     pub async fn execute(
-        search_client: &impl SearchClient,
+        post_queries: &(impl PostQueryRepository + ?Sized),
     ) -> anyhow::Result<Option<DateTime<Utc>>> {
-        search_client.get_last_updated().await
+        post_queries.get_last_updated().await
     }
 }
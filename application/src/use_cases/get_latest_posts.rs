@@ -1,22 +1,29 @@
+use domain::entities::Post;
+
 use crate::{
-    adapters::{PostsRepository, SearchClient},
-    models::{AdjacentPageInfo, Page, PageNumber},
+    adapters::{PostQueryRepository, PostsRepository},
+    models::{AdjacentPageInfo, Audience, Cursor, Page, PageCursor, PageNumber},
     ApplicationResult,
 };
 
 pub struct GetLatestPostsUseCase;
 
 impl GetLatestPostsUseCase {
+    /// 新しい順に並べたPostのページと、先頭に固定されたPostを返します
+    ///
+    /// 固定されたPostは1ページ目でだけ返します。ページの一覧やページ分けは固定の有無に影響されません
     pub async fn execute(
-        posts: &impl PostsRepository,
-        search_client: &impl SearchClient,
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
         page_index: PageNumber,
-    ) -> ApplicationResult<Page<'static, (), PageNumber>> {
-        let result = search_client
-            .get_latest_posts((page_index.0 - 1) * 10, 10) // TODO: per_page
+        per_page: usize,
+        audience: Audience,
+    ) -> ApplicationResult<(Vec<Post>, Page<'static, (), PageNumber>)> {
+        let result = post_queries
+            .get_latest_posts((page_index.0 - 1) * per_page, per_page, audience)
             .await?;
 
-        let next_page = if page_index.0 * 10 < result.total_count {
+        let next_page = if page_index.0 * per_page < result.total_count {
             Some(AdjacentPageInfo::PageIndex(page_index.next()))
         } else {
             None
@@ -29,12 +36,266 @@ impl GetLatestPostsUseCase {
             None
         };
 
-        Ok(Page {
-            condition: &(),
-            index: page_index,
-            posts: posts.get_by_ids(&result.post_ids).await?,
-            next_page,
-            prev_page,
-        })
+        let pinned_posts = if page_index.0 == 1 {
+            get_pinned_posts(posts, post_queries, audience).await?
+        } else {
+            vec![]
+        };
+
+        Ok((
+            pinned_posts,
+            Page {
+                condition: &(),
+                index: page_index,
+                posts: posts.get_by_ids(&result.post_ids).await?,
+                next_page,
+                prev_page,
+            },
+        ))
+    }
+}
+
+pub struct GetLatestPostsByCursorUseCase;
+
+impl GetLatestPostsByCursorUseCase {
+    /// `GetLatestPostsUseCase`と同じく、新しい順に並べたPostのページと先頭に固定されたPostを返します
+    ///
+    /// ページの位置をカーソルで指定するため、記事が増えてもページの中身がずれません
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        cursor: PageCursor,
+        per_page: usize,
+        audience: Audience,
+    ) -> ApplicationResult<(Vec<Post>, Page<'static, (), PageCursor>)> {
+        // 1件多く取得して、その先にまだPostがあるかを調べる
+        let (post_ids, has_older, has_newer) = match cursor {
+            PageCursor::Latest | PageCursor::Before(_) => {
+                let before = match &cursor {
+                    PageCursor::Before(cursor) => Some(cursor),
+                    _ => None,
+                };
+                let mut post_ids = post_queries
+                    .get_before_cursor(before, per_page + 1, audience)
+                    .await?;
+                let has_older = post_ids.len() > per_page;
+                post_ids.truncate(per_page);
+                (post_ids, has_older, before.is_some())
+            }
+            PageCursor::After(ref cursor) => {
+                let mut post_ids = post_queries
+                    .get_after_cursor(cursor, per_page + 1, audience)
+                    .await?;
+                let has_newer = post_ids.len() > per_page;
+                post_ids.truncate(per_page);
+                post_ids.reverse();
+                (post_ids, true, has_newer)
+            }
+        };
+        let page_posts = if post_ids.is_empty() {
+            vec![]
+        } else {
+            posts.get_by_ids(&post_ids).await?
+        };
+
+        let next_page = match page_posts.last() {
+            Some(post) if has_older => Some(AdjacentPageInfo::PageIndex(PageCursor::Before(
+                Cursor::new(post),
+            ))),
+            _ => None,
+        };
+        let prev_page = match page_posts.first() {
+            Some(post) if has_newer => Some(AdjacentPageInfo::PageIndex(PageCursor::After(
+                Cursor::new(post),
+            ))),
+            _ => None,
+        };
+
+        let pinned_posts = if cursor == PageCursor::Latest {
+            get_pinned_posts(posts, post_queries, audience).await?
+        } else {
+            vec![]
+        };
+
+        Ok((
+            pinned_posts,
+            Page {
+                condition: &(),
+                index: cursor,
+                posts: page_posts,
+                next_page,
+                prev_page,
+            },
+        ))
+    }
+}
+
+async fn get_pinned_posts(
+    posts: &(impl PostsRepository + ?Sized),
+    post_queries: &(impl PostQueryRepository + ?Sized),
+    audience: Audience,
+) -> ApplicationResult<Vec<Post>> {
+    let pinned_ids = post_queries.get_pinned(audience).await?;
+    Ok(if pinned_ids.is_empty() {
+        vec![]
+    } else {
+        posts.get_by_ids(&pinned_ids).await?
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use crate::models::SearchResult;
+    use chrono::Utc;
+    use domain::entities::PostId;
+    use mockall::predicate::*;
+
+    fn mock_posts() -> MockPostsRepository {
+        let mut mock_posts = MockPostsRepository::new();
+        mock_posts.expect_get_by_ids().returning(|ids| {
+            let now = Utc::now();
+            Ok(ids
+                .iter()
+                .map(|id| Post::new(*id, "title", "body", now, now))
+                .collect())
+        });
+        mock_posts
+    }
+
+    #[tokio::test]
+    async fn pinned_posts_on_first_page() {
+        let mut mock_queries = MockPostQueryRepository::new();
+        mock_queries
+            .expect_get_latest_posts()
+            .with(eq(0), eq(10), eq(Audience::Public))
+            .returning(|_, _, _| {
+                Ok(SearchResult {
+                    post_ids: vec![PostId(5), PostId(4), PostId(3)],
+                    total_count: 15,
+                })
+            });
+        mock_queries
+            .expect_get_pinned()
+            .with(eq(Audience::Public))
+            .returning(|_| Ok(vec![PostId(4), PostId(1)]));
+
+        let (pinned, page) = GetLatestPostsUseCase::execute(
+            &mock_posts(),
+            &mock_queries,
+            PageNumber(1),
+            10,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+        let pinned_ids = pinned.iter().map(|post| post.id).collect::<Vec<_>>();
+        let post_ids = page.posts.iter().map(|post| post.id).collect::<Vec<_>>();
+        assert_eq!(pinned_ids, vec![PostId(4), PostId(1)]);
+        assert_eq!(post_ids, vec![PostId(5), PostId(4), PostId(3)]);
+        assert_eq!(
+            page.next_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber(2)))
+        );
+    }
+
+    #[tokio::test]
+    async fn no_pinned_posts_on_other_pages() {
+        let mut mock_queries = MockPostQueryRepository::new();
+        mock_queries
+            .expect_get_latest_posts()
+            .with(eq(10), eq(10), eq(Audience::Public))
+            .returning(|_, _, _| {
+                Ok(SearchResult {
+                    post_ids: vec![PostId(2), PostId(1)],
+                    total_count: 12,
+                })
+            });
+        mock_queries.expect_get_pinned().never();
+
+        let (pinned, page) = GetLatestPostsUseCase::execute(
+            &mock_posts(),
+            &mock_queries,
+            PageNumber(2),
+            10,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+        let post_ids = page.posts.iter().map(|post| post.id).collect::<Vec<_>>();
+        assert!(pinned.is_empty());
+        assert_eq!(post_ids, vec![PostId(2), PostId(1)]);
+        assert_eq!(page.next_page, None);
+    }
+
+    #[tokio::test]
+    async fn posts_before_cursor() {
+        let mut mock_queries = MockPostQueryRepository::new();
+        let now = Utc::now();
+        let cursor = Cursor {
+            created_at: now,
+            id: PostId(6),
+        };
+        mock_queries
+            .expect_get_before_cursor()
+            .withf(move |c, limit, audience| {
+                c == &Some(&cursor) && *limit == 4 && *audience == Audience::Public
+            })
+            .returning(|_, _, _| Ok(vec![PostId(5), PostId(4), PostId(3), PostId(2)]));
+        mock_queries.expect_get_pinned().never();
+
+        let (pinned, page) = GetLatestPostsByCursorUseCase::execute(
+            &mock_posts(),
+            &mock_queries,
+            PageCursor::Before(cursor),
+            3,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+        let post_ids = page.posts.iter().map(|post| post.id).collect::<Vec<_>>();
+        assert!(pinned.is_empty());
+        assert_eq!(post_ids, vec![PostId(5), PostId(4), PostId(3)]);
+        assert_eq!(
+            page.next_page,
+            Some(AdjacentPageInfo::PageIndex(PageCursor::Before(
+                Cursor::new(&page.posts[2])
+            )))
+        );
+        assert_eq!(
+            page.prev_page,
+            Some(AdjacentPageInfo::PageIndex(PageCursor::After(Cursor::new(
+                &page.posts[0]
+            ))))
+        );
+    }
+
+    #[tokio::test]
+    async fn posts_after_cursor_up_to_latest() {
+        let mut mock_queries = MockPostQueryRepository::new();
+        let cursor = Cursor {
+            created_at: Utc::now(),
+            id: PostId(2),
+        };
+        // 新しいPostは古い順に返ってくる
+        mock_queries
+            .expect_get_after_cursor()
+            .with(eq(cursor), eq(4), eq(Audience::Public))
+            .returning(|_, _, _| Ok(vec![PostId(3), PostId(4)]));
+
+        let (_, page) = GetLatestPostsByCursorUseCase::execute(
+            &mock_posts(),
+            &mock_queries,
+            PageCursor::After(cursor),
+            3,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+        let post_ids = page.posts.iter().map(|post| post.id).collect::<Vec<_>>();
+        assert_eq!(post_ids, vec![PostId(4), PostId(3)]);
+        assert_eq!(page.prev_page, None);
+        assert!(page.next_page.is_some());
     }
 }
@@ -0,0 +1,16 @@
+use crate::{
+    adapters::PostQueryRepository,
+    models::{Audience, MonthlyStats},
+    ApplicationResult,
+};
+
+pub struct GetMonthlyStatsUseCase;
+
+impl GetMonthlyStatsUseCase {
+    pub async fn execute(
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        audience: Audience,
+    ) -> ApplicationResult<Vec<MonthlyStats>> {
+        Ok(post_queries.get_monthly_stats(audience).await?)
+    }
+}
@@ -1,9 +1,9 @@
 use domain::entities::PostId;
 
 use crate::{
-    adapters::{PostsRepository, SearchClient},
+    adapters::{PostQueryRepository, PostsRepository},
     errors::ApplicationError,
-    models::{AdjacentPageInfo, Page},
+    models::{AdjacentPageInfo, Audience, Page},
     ApplicationResult,
 };
 
@@ -11,21 +11,26 @@ pub struct GetPostByIdUseCase;
 
 impl GetPostByIdUseCase {
     pub async fn execute<'a>(
-        posts: &impl PostsRepository,
-        search_client: &impl SearchClient,
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
         id: &'a PostId,
+        audience: Audience,
     ) -> ApplicationResult<Page<'a, PostId, ()>> {
-        let Some(post) = posts.get_by_id(id).await? else {
+        let Some(post) = posts
+            .get_by_id(id)
+            .await?
+            .filter(|post| audience.can_read(post))
+        else {
             return Err(ApplicationError::PostNotFound);
         };
 
-        let next_post_id = search_client
-            .get_from_date(post.created_at, 1, 1)
+        let next_post_id = post_queries
+            .get_from_date(post.created_at, 1, 1, audience)
             .await?
             .first()
             .cloned();
-        let prev_post_id = search_client
-            .get_until_date(post.created_at, 0, 1)
+        let prev_post_id = post_queries
+            .get_until_date(post.created_at, 0, 1, audience)
             .await?
             .first()
             .cloned();
@@ -45,15 +50,15 @@ mod tests {
     use super::*;
     use crate::adapters::*;
     use assert_matches::assert_matches;
-    use chrono::Utc;
-    use domain::entities::Post;
+    use chrono::{Duration, Utc};
+    use domain::entities::{Post, PostStatus, PostVisibility};
     use mockall::predicate::*;
     use pretty_assertions::assert_eq;
 
     #[tokio::test]
     async fn test_get_post_by_id() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let now = Utc::now();
         let post_id = PostId(629);
         mock_posts
@@ -68,18 +73,19 @@ mod tests {
                     now,
                 )))
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(now), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(630)]));
-        mock_search
+            .with(eq(now), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(630)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(now), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(now), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
 
-        let page = GetPostByIdUseCase::execute(&mock_posts, &mock_search, &post_id)
-            .await
-            .unwrap();
+        let page =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await
+                .unwrap();
 
         assert_eq!(page.condition, &post_id);
         assert_eq!(page.posts.len(), 1);
@@ -97,7 +103,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_post_by_id_first_post() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let now = Utc::now();
         let post_id = PostId(1);
         mock_posts
@@ -112,18 +118,19 @@ mod tests {
                     now,
                 )))
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(now), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(2)]));
-        mock_search
+            .with(eq(now), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(2)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(now), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
+            .with(eq(now), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
 
-        let page = GetPostByIdUseCase::execute(&mock_posts, &mock_search, &post_id)
-            .await
-            .unwrap();
+        let page =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await
+                .unwrap();
 
         assert_eq!(page.condition, &post_id);
         assert_eq!(page.posts.len(), 1);
@@ -138,7 +145,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_post_by_id_last_post() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let now = Utc::now();
         let post_id = PostId(629);
         mock_posts
@@ -153,18 +160,19 @@ mod tests {
                     now,
                 )))
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(now), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
-        mock_search
+            .with(eq(now), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(now), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(now), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
 
-        let page = GetPostByIdUseCase::execute(&mock_posts, &mock_search, &post_id)
-            .await
-            .unwrap();
+        let page =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await
+                .unwrap();
 
         assert_eq!(page.condition, &post_id);
         assert_eq!(page.posts.len(), 1);
@@ -179,7 +187,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_post_by_id_only_one() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let now = Utc::now();
         let post_id = PostId(629);
         mock_posts
@@ -194,18 +202,19 @@ mod tests {
                     now,
                 )))
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(now), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
-        mock_search
+            .with(eq(now), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(now), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
+            .with(eq(now), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
 
-        let page = GetPostByIdUseCase::execute(&mock_posts, &mock_search, &post_id)
-            .await
-            .unwrap();
+        let page =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await
+                .unwrap();
 
         assert_eq!(page.condition, &post_id);
         assert_eq!(page.posts.len(), 1);
@@ -217,17 +226,176 @@ mod tests {
     #[tokio::test]
     async fn test_get_post_by_id_not_found() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let post_id = PostId(629);
         mock_posts
             .expect_get_by_id()
             .with(eq(post_id))
             .returning(move |_| Ok(None));
-        mock_search.expect_get_from_date().never();
-        mock_search.expect_get_until_date().never();
+        mock_queries.expect_get_from_date().never();
+        mock_queries.expect_get_until_date().never();
+
+        let result =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await;
+
+        assert_matches!(result, Err(ApplicationError::PostNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_get_post_by_id_draft() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let now = Utc::now();
+        let post_id = PostId(629);
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(post_id))
+            .returning(move |_| {
+                let mut post = Post::new(post_id, "test title", "test body", now, now);
+                post.status = PostStatus::Draft;
+                Ok(Some(post))
+            });
+        mock_queries
+            .expect_get_from_date()
+            .with(eq(now), eq(1), eq(1), eq(Audience::Admin))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
+            .expect_get_until_date()
+            .with(eq(now), eq(0), eq(1), eq(Audience::Admin))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
+
+        let result =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await;
+        assert_matches!(result, Err(ApplicationError::PostNotFound));
+
+        let page =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Admin)
+                .await
+                .unwrap();
+        assert_eq!(page.post().unwrap().id, post_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_post_by_id_scheduled() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let now = Utc::now();
+        let published_at = now + Duration::hours(8);
+        let post_id = PostId(629);
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(post_id))
+            .returning(move |_| {
+                let mut post = Post::new(post_id, "test title", "test body", now, now);
+                post.published_at = published_at;
+                Ok(Some(post))
+            });
+        mock_queries
+            .expect_get_from_date()
+            .with(eq(now), eq(1), eq(1), eq(Audience::Admin))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
+            .expect_get_until_date()
+            .with(eq(now), eq(0), eq(1), eq(Audience::Admin))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
+
+        let result =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await;
+        assert_matches!(result, Err(ApplicationError::PostNotFound));
+
+        let page =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Admin)
+                .await
+                .unwrap();
+        assert_eq!(page.post().unwrap().id, post_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_post_by_id_trashed() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mock_queries = MockPostQueryRepository::new();
+        let now = Utc::now();
+        let post_id = PostId(629);
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(post_id))
+            .returning(move |_| {
+                let mut post = Post::new(post_id, "test title", "test body", now, now);
+                post.deleted_at = Some(now);
+                Ok(Some(post))
+            });
+
+        // ゴミ箱に入れた記事は管理者にも表示しない
+        let result =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Admin)
+                .await;
+        assert_matches!(result, Err(ApplicationError::PostNotFound));
+    }
 
-        let result = GetPostByIdUseCase::execute(&mock_posts, &mock_search, &post_id).await;
+    #[tokio::test]
+    async fn test_get_post_by_id_private() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let now = Utc::now();
+        let post_id = PostId(629);
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(post_id))
+            .returning(move |_| {
+                let mut post = Post::new(post_id, "test title", "test body", now, now);
+                post.visibility = PostVisibility::Private;
+                Ok(Some(post))
+            });
+        mock_queries
+            .expect_get_from_date()
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
+            .expect_get_until_date()
+            .returning(|_, _, _, _| Ok(vec![]));
 
+        let result =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await;
         assert_matches!(result, Err(ApplicationError::PostNotFound));
+
+        let page =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Admin)
+                .await
+                .unwrap();
+        assert_eq!(page.post().unwrap().id, post_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_post_by_id_unlisted() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let now = Utc::now();
+        let post_id = PostId(629);
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(post_id))
+            .returning(move |_| {
+                let mut post = Post::new(post_id, "test title", "test body", now, now);
+                post.visibility = PostVisibility::Unlisted;
+                Ok(Some(post))
+            });
+        mock_queries
+            .expect_get_from_date()
+            .with(eq(now), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
+            .expect_get_until_date()
+            .with(eq(now), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
+
+        // 限定公開の記事はURLを知っていれば誰でも読める
+        let page =
+            GetPostByIdUseCase::execute(&mock_posts, &mock_queries, &post_id, Audience::Public)
+                .await
+                .unwrap();
+        assert_eq!(page.post().unwrap().id, post_id);
     }
 }
@@ -0,0 +1,20 @@
+use domain::entities::{PostId, Slug};
+
+use crate::{adapters::PostsRepository, errors::ApplicationError, ApplicationResult};
+
+pub struct GetPostIdBySlugUseCase;
+
+impl GetPostIdBySlugUseCase {
+    /// スラッグに対応するPostのIDを返します。ゴミ箱に入っているものは見つからない扱いにします
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        slug: &Slug,
+    ) -> ApplicationResult<PostId> {
+        posts
+            .get_by_slug(slug)
+            .await?
+            .filter(|post| !post.is_deleted())
+            .map(|post| post.id)
+            .ok_or(ApplicationError::PostNotFound)
+    }
+}
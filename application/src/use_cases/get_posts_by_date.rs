@@ -2,32 +2,34 @@ use anyhow::Context as _;
 use chrono::{Local, NaiveDate, Utc};
 
 use crate::{
-    adapters::{PostsRepository, SearchClient},
-    models::{AdjacentPageInfo, Page, PageNumber},
+    adapters::{PostQueryRepository, PostsRepository},
+    models::{AdjacentPageInfo, Audience, Page, PageNumber},
 };
 
 pub struct GetPostsByDateUseCase;
 
 impl GetPostsByDateUseCase {
     pub async fn execute<'a>(
-        posts: &impl PostsRepository,
-        search_client: &impl SearchClient,
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
         date: &'a NaiveDate,
         page_index: PageNumber,
+        per_page: usize,
+        audience: Audience,
     ) -> anyhow::Result<Page<'a, NaiveDate, PageNumber>> {
-        let result = search_client
-            .find_by_date(date, (page_index.0 - 1) * 10, 10)
+        let result = post_queries
+            .find_by_date(date, (page_index.0 - 1) * per_page, per_page, audience)
             .await?;
         let result_posts = if result.post_ids.is_empty() {
             vec![]
         } else {
             posts.get_by_ids(&result.post_ids).await?
         };
-        let next_page = if page_index.0 * 10 < result.total_count {
+        let next_page = if page_index.0 * per_page < result.total_count {
             Some(AdjacentPageInfo::PageIndex(page_index.next()))
         } else {
             let next_post_ids = if result.post_ids.is_empty() {
-                search_client
+                post_queries
                     .get_from_date(
                         date.and_hms_opt(0, 0, 0)
                             .unwrap()
@@ -36,12 +38,13 @@ impl GetPostsByDateUseCase {
                             .with_timezone(&Utc),
                         0,
                         1,
+                        audience,
                     )
                     .await?
             } else {
                 let last_post = result_posts.last().unwrap();
-                search_client
-                    .get_from_date(last_post.created_at, 1, 1)
+                post_queries
+                    .get_from_date(last_post.created_at, 1, 1, audience)
                     .await?
             };
             if let Some(next_post_id) = next_post_ids.first() {
@@ -57,13 +60,13 @@ impl GetPostsByDateUseCase {
             }
         };
         let prev_page = if page_index.0 > 1 && result.total_count > 0 {
-            let max_page_index = (result.total_count + 9) / 10;
+            let max_page_index = result.total_count.div_ceil(per_page);
             Some(AdjacentPageInfo::PageIndex(
                 PageNumber::new(max_page_index.min(page_index.0 - 1)).expect("page_index > 1"),
             ))
         } else {
             let prev_post_ids = if result.post_ids.is_empty() {
-                search_client
+                post_queries
                     .get_until_date(
                         date.and_hms_opt(0, 0, 0)
                             .unwrap()
@@ -72,12 +75,13 @@ impl GetPostsByDateUseCase {
                             .with_timezone(&Utc),
                         0,
                         1,
+                        audience,
                     )
                     .await?
             } else {
                 let first_post = result_posts.first().unwrap();
-                search_client
-                    .get_until_date(first_post.created_at, 0, 1)
+                post_queries
+                    .get_until_date(first_post.created_at, 0, 1, audience)
                     .await?
             };
             if let Some(prev_post_id) = prev_post_ids.first() {
@@ -115,7 +119,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -146,10 +150,10 @@ mod tests {
         );
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &0 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len(),
                     post_ids: post_ids_clone.clone(),
@@ -159,14 +163,14 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -178,9 +182,11 @@ mod tests {
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -206,7 +212,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date_in_first_day() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -229,10 +235,10 @@ mod tests {
         );
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &0 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len(),
                     post_ids: post_ids_clone.clone(),
@@ -242,14 +248,14 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -257,9 +263,11 @@ mod tests {
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -280,7 +288,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date_in_last_day() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -303,10 +311,10 @@ mod tests {
         );
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &0 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len(),
                     post_ids: post_ids_clone.clone(),
@@ -316,14 +324,14 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
-        mock_search
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(prev_post.id))
@@ -331,9 +339,11 @@ mod tests {
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -354,7 +364,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date_with_many_pages() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -376,7 +386,7 @@ mod tests {
             Post::new(PostId(637), "test title2", "test body2", date2, date2),
             Post::new(PostId(638), "test title2", "test body2", date2, date2),
         ];
-        let posts_in_next_page = vec![
+        let posts_in_next_page = [
             Post::new(PostId(639), "test title2", "test body2", date2, date2),
             Post::new(PostId(640), "test title2", "test body2", date2, date2),
             Post::new(PostId(641), "test title2", "test body2", date2, date2),
@@ -390,10 +400,10 @@ mod tests {
         );
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &0 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len() + posts_in_next_page.len(),
                     post_ids: post_ids_clone.clone(),
@@ -403,10 +413,10 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(prev_post.id))
@@ -414,9 +424,11 @@ mod tests {
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -441,7 +453,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date_with_many_pages_in_final_page() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -477,10 +489,10 @@ mod tests {
         );
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &10 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &10 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len() + posts_in_prev_page.len(),
                     post_ids: post_ids_clone.clone(),
@@ -490,10 +502,10 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(642)]));
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(642)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(PostId(642)))
@@ -501,9 +513,11 @@ mod tests {
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(2).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -528,7 +542,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date_on_empty_day_with_page() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -552,23 +566,23 @@ mod tests {
             prev_date,
             prev_date,
         );
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &10 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &10 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: 0,
                     post_ids: vec![],
                 })
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -580,9 +594,11 @@ mod tests {
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(2).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -606,7 +622,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date_on_empty_day() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -630,23 +646,23 @@ mod tests {
             prev_date,
             prev_date,
         );
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &0 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: 0,
                     post_ids: vec![],
                 })
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -658,9 +674,11 @@ mod tests {
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -684,7 +702,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date_in_sole_day() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -699,10 +717,10 @@ mod tests {
         ];
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &0 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len(),
                     post_ids: post_ids_clone.clone(),
@@ -712,20 +730,22 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
-        mock_search
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -741,7 +761,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_date_in_empty_page() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
         let date1 = date
             .and_hms_opt(0, 0, 0)
@@ -765,23 +785,23 @@ mod tests {
             prev_date,
             prev_date,
         );
-        mock_search
+        mock_queries
             .expect_find_by_date()
-            .withf(move |d, o, l| d == &date && o == &20 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |d, o, l, a| d == &date && o == &20 && l == &10 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: 2,
                     post_ids: vec![],
                 })
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -793,9 +813,11 @@ mod tests {
 
         let page = GetPostsByDateUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &date,
             PageNumber::new(3).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -813,4 +835,58 @@ mod tests {
             Some(AdjacentPageInfo::PageIndex(PageNumber::new(1).unwrap()))
         );
     }
+
+    #[tokio::test]
+    async fn test_get_posts_by_date_with_page_size() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let date = NaiveDate::from_ymd_opt(1989, 9, 1).unwrap();
+        let date1 = date
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+            .with_timezone(&Utc);
+        let posts = vec![
+            Post::new(PostId(632), "test title", "test body", date1, date1),
+            Post::new(PostId(633), "test title", "test body", date1, date1),
+            Post::new(PostId(634), "test title", "test body", date1, date1),
+        ];
+        let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
+        let post_ids_clone = post_ids.clone();
+        mock_queries
+            .expect_find_by_date()
+            .withf(move |d, o, l, a| d == &date && o == &3 && l == &3 && a == &Audience::Public)
+            .returning(move |_, _, _, _| {
+                Ok(SearchResult {
+                    total_count: 7,
+                    post_ids: post_ids_clone.clone(),
+                })
+            });
+        mock_posts
+            .expect_get_by_ids()
+            .withf(move |ids| ids == post_ids.clone())
+            .returning(move |_| Ok(posts.clone()));
+
+        let page = GetPostsByDateUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &date,
+            PageNumber::new(2).unwrap(),
+            3,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.index.0, 2);
+        assert_eq!(page.posts.len(), 3);
+        assert_eq!(
+            page.next_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber::new(3).unwrap()))
+        );
+        assert_eq!(
+            page.prev_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber::new(1).unwrap()))
+        );
+    }
 }
@@ -0,0 +1,154 @@
+use domain::entities::Tag;
+
+use crate::{
+    adapters::{PostQueryRepository, PostsRepository},
+    models::{AdjacentPageInfo, Audience, Page, PageNumber},
+    ApplicationResult,
+};
+
+pub struct GetPostsByTagUseCase;
+
+impl GetPostsByTagUseCase {
+    pub async fn execute<'a>(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        tag: &'a Tag,
+        page_index: PageNumber,
+        per_page: usize,
+        audience: Audience,
+    ) -> ApplicationResult<Page<'a, Tag, PageNumber>> {
+        let result = post_queries
+            .find_by_tag(tag, (page_index.0 - 1) * per_page, per_page, audience)
+            .await?;
+
+        let next_page = if page_index.0 * per_page < result.total_count {
+            Some(AdjacentPageInfo::PageIndex(page_index.next()))
+        } else {
+            None
+        };
+        let prev_page = if page_index.0 > 1 && result.total_count > 0 {
+            let max_page_index = result.total_count.div_ceil(per_page);
+            Some(AdjacentPageInfo::PageIndex(
+                PageNumber::new(max_page_index.min(page_index.0 - 1)).expect("page_index > 1"),
+            ))
+        } else {
+            None
+        };
+
+        Ok(Page {
+            condition: tag,
+            index: page_index,
+            posts: if result.post_ids.is_empty() {
+                vec![]
+            } else {
+                posts.get_by_ids(&result.post_ids).await?
+            },
+            next_page,
+            prev_page,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{adapters::*, models::SearchResult};
+    use assert_matches::assert_matches;
+    use chrono::Utc;
+    use domain::entities::{Post, PostId};
+    use mockall::predicate::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn get_posts_by_tag() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let tag = Tag::new("rust").unwrap();
+        let now = Utc::now();
+        let post_ids = (1..=10).map(PostId).collect::<Vec<_>>();
+        let post_ids_clone = post_ids.clone();
+
+        mock_queries
+            .expect_find_by_tag()
+            .with(eq(tag.clone()), eq(10), eq(10), eq(Audience::Public))
+            .returning(move |_, _, _, _| {
+                Ok(SearchResult {
+                    post_ids: post_ids_clone.clone(),
+                    total_count: 25,
+                })
+            });
+        mock_posts
+            .expect_get_by_ids()
+            .withf(move |ids| ids == post_ids)
+            .returning(move |ids| {
+                Ok(ids
+                    .iter()
+                    .map(|id| Post::new(*id, "title", "body", now, now))
+                    .collect())
+            });
+
+        let page = GetPostsByTagUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &tag,
+            PageNumber::new(2).unwrap(),
+            10,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(page.condition, &tag);
+        assert_eq!(page.posts.len(), 10);
+        assert_matches!(
+            page.next_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber(3)))
+        );
+        assert_matches!(
+            page.prev_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber(1)))
+        );
+    }
+
+    #[tokio::test]
+    async fn get_posts_by_tag_last_page() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let tag = Tag::new("rust").unwrap();
+        let now = Utc::now();
+
+        mock_queries
+            .expect_find_by_tag()
+            .with(eq(tag.clone()), eq(20), eq(10), eq(Audience::Public))
+            .returning(move |_, _, _, _| {
+                Ok(SearchResult {
+                    post_ids: vec![PostId(21), PostId(22)],
+                    total_count: 22,
+                })
+            });
+        mock_posts.expect_get_by_ids().returning(move |ids| {
+            Ok(ids
+                .iter()
+                .map(|id| Post::new(*id, "title", "body", now, now))
+                .collect())
+        });
+
+        let page = GetPostsByTagUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &tag,
+            PageNumber::new(3).unwrap(),
+            10,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(page.posts.len(), 2);
+        assert!(page.next_page.is_none());
+        assert_matches!(
+            page.prev_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber(2)))
+        );
+    }
+}
@@ -2,8 +2,8 @@ use anyhow::Context as _;
 use chrono::{DateTime, Utc};
 
 use crate::{
-    adapters::{PostsRepository, SearchClient},
-    models::{AdjacentPageInfo, Page, PageNumber, YearMonth},
+    adapters::{PostQueryRepository, PostsRepository},
+    models::{AdjacentPageInfo, Audience, Page, PageNumber, YearMonth},
     ApplicationResult,
 };
 
@@ -11,13 +11,20 @@ pub struct GetPostsByYearMonthUseCase;
 
 impl GetPostsByYearMonthUseCase {
     pub async fn execute<'a>(
-        posts: &impl PostsRepository,
-        search_client: &impl SearchClient,
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
         year_month: &'a YearMonth,
         page_index: PageNumber,
+        per_page: usize,
+        audience: Audience,
     ) -> ApplicationResult<Page<'a, YearMonth, PageNumber>> {
-        let result = search_client
-            .find_by_year_month(year_month, (page_index.0 - 1) * 10, 10) // TODO: per_page
+        let result = post_queries
+            .find_by_year_month(
+                year_month,
+                (page_index.0 - 1) * per_page,
+                per_page,
+                audience,
+            )
             .await?;
         let result_posts = if result.post_ids.is_empty() {
             vec![]
@@ -25,17 +32,17 @@ impl GetPostsByYearMonthUseCase {
             posts.get_by_ids(&result.post_ids).await?
         };
 
-        let next_page = if page_index.0 * 10 < result.total_count {
+        let next_page = if page_index.0 * per_page < result.total_count {
             Some(AdjacentPageInfo::PageIndex(page_index.next()))
         } else {
             let next_post_ids = if result.post_ids.is_empty() {
-                search_client
-                    .get_from_date(DateTime::<Utc>::from(*year_month), 0, 1)
+                post_queries
+                    .get_from_date(DateTime::<Utc>::from(*year_month), 0, 1, audience)
                     .await?
             } else {
                 let last_post = result_posts.last().unwrap();
-                search_client
-                    .get_from_date(last_post.created_at, 1, 1)
+                post_queries
+                    .get_from_date(last_post.created_at, 1, 1, audience)
                     .await?
             };
             if let Some(next_post_id) = next_post_ids.first() {
@@ -51,19 +58,19 @@ impl GetPostsByYearMonthUseCase {
             }
         };
         let prev_page = if page_index.0 > 1 && result.total_count > 0 {
-            let max_page_index = (result.total_count + 9) / 10;
+            let max_page_index = result.total_count.div_ceil(per_page);
             Some(AdjacentPageInfo::PageIndex(
                 PageNumber::new(max_page_index.min(page_index.0 - 1)).expect("page_index > 1"),
             ))
         } else {
             let prev_post_ids = if result.post_ids.is_empty() {
-                search_client
-                    .get_until_date(DateTime::<Utc>::from(*year_month), 0, 1)
+                post_queries
+                    .get_until_date(DateTime::<Utc>::from(*year_month), 0, 1, audience)
                     .await?
             } else {
                 let first_post = result_posts.first().unwrap();
-                search_client
-                    .get_until_date(first_post.created_at, 0, 1)
+                post_queries
+                    .get_until_date(first_post.created_at, 0, 1, audience)
                     .await?
             };
             if let Some(prev_post_id) = prev_post_ids.first() {
@@ -101,7 +108,7 @@ mod tests {
     #[tokio::test]
     async fn get_posts_by_year_month() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
 
         let date1 = DateTime::<Utc>::from(year_month);
@@ -129,10 +136,12 @@ mod tests {
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &0 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len(),
                     post_ids: post_ids_clone.clone(),
@@ -142,14 +151,14 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -161,9 +170,11 @@ mod tests {
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -189,7 +200,7 @@ mod tests {
     #[tokio::test]
     async fn get_posts_by_year_month_in_first_year_month() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
         let date1 = DateTime::<Utc>::from(year_month);
         let date2 = date1 + Duration::hours(12);
@@ -208,10 +219,12 @@ mod tests {
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &0 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len(),
                     post_ids: post_ids_clone.clone(),
@@ -221,14 +234,14 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -236,9 +249,11 @@ mod tests {
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -260,7 +275,7 @@ mod tests {
     #[tokio::test]
     async fn get_posts_by_year_month_in_last_year_month() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
         let date1 = DateTime::<Utc>::from(year_month);
         let date2 = date1 + Duration::hours(12);
@@ -279,10 +294,12 @@ mod tests {
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &0 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len(),
                     post_ids: post_ids_clone.clone(),
@@ -292,14 +309,14 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
-        mock_search
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(prev_post.id))
@@ -307,9 +324,11 @@ mod tests {
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -331,7 +350,7 @@ mod tests {
     #[tokio::test]
     async fn get_posts_by_year_month_with_many_pages() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
         let date1 = DateTime::<Utc>::from(year_month);
         let date2 = date1 + Duration::hours(12);
@@ -348,7 +367,7 @@ mod tests {
             Post::new(PostId(637), "test title2", "test body2", date2, date2),
             Post::new(PostId(638), "test title2", "test body2", date2, date2),
         ];
-        let posts_in_next_page = vec![
+        let posts_in_next_page = [
             Post::new(PostId(639), "test title2", "test body2", date2, date2),
             Post::new(PostId(640), "test title2", "test body2", date2, date2),
             Post::new(PostId(641), "test title2", "test body2", date2, date2),
@@ -363,10 +382,12 @@ mod tests {
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &0 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len() + posts_in_next_page.len(),
                     post_ids: post_ids_clone.clone(),
@@ -376,10 +397,10 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(prev_post.id))
@@ -387,9 +408,11 @@ mod tests {
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -422,7 +445,7 @@ mod tests {
     #[tokio::test]
     async fn get_posts_by_year_month_with_many_pages_in_last_page() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
         let date1 = DateTime::<Utc>::from(year_month);
         let date2 = date1 + Duration::hours(12);
@@ -454,10 +477,12 @@ mod tests {
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &10 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &10 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len() + posts_in_prev_page.len(),
                     post_ids: post_ids_clone.clone(),
@@ -467,10 +492,10 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(642)]));
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(642)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(PostId(642)))
@@ -478,9 +503,11 @@ mod tests {
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(2).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -506,7 +533,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_year_month_on_empty_month() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
         let date1 = DateTime::<Utc>::from(year_month);
         let next_date = date1 + Duration::days(80);
@@ -526,10 +553,12 @@ mod tests {
             prev_date,
         );
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &0 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: 0,
                     post_ids: vec![],
@@ -539,14 +568,14 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == vec![])
             .returning(move |_| Ok(vec![]));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -558,9 +587,11 @@ mod tests {
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -585,7 +616,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_year_month_on_empty_month_with_page() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
         let date1 = DateTime::<Utc>::from(year_month);
         let next_date = date1 + Duration::days(80);
@@ -605,23 +636,25 @@ mod tests {
             prev_date,
         );
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &10 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &10 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: 0,
                     post_ids: vec![],
                 })
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -633,9 +666,11 @@ mod tests {
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(2).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -660,7 +695,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_year_month_on_sole_month() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
         let date1 = DateTime::<Utc>::from(year_month);
         let date2 = date1 + Duration::hours(12);
@@ -671,10 +706,12 @@ mod tests {
         let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
         let post_ids_clone = post_ids.clone();
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &0 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &0 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: post_ids_clone.len(),
                     post_ids: post_ids_clone.clone(),
@@ -684,20 +721,22 @@ mod tests {
             .expect_get_by_ids()
             .withf(move |ids| ids == post_ids.clone())
             .returning(move |_| Ok(posts.clone()));
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date2), eq(1), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
-        mock_search
+            .with(eq(date2), eq(1), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![]));
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -714,7 +753,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_posts_by_year_month_with_empty_page() {
         let mut mock_posts = MockPostsRepository::new();
-        let mut mock_search = MockSearchClient::new();
+        let mut mock_queries = MockPostQueryRepository::new();
         let year_month = YearMonth::new(1989, 9).unwrap();
 
         let date1 = DateTime::<Utc>::from(year_month);
@@ -735,23 +774,25 @@ mod tests {
             prev_date,
         );
 
-        mock_search
+        mock_queries
             .expect_find_by_year_month()
-            .withf(move |ym, o, l| ym == &year_month && o == &20 && l == &10)
-            .returning(move |_, _, _| {
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &20 && l == &10 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
                 Ok(SearchResult {
                     total_count: 2,
                     post_ids: vec![],
                 })
             });
-        mock_search
+        mock_queries
             .expect_get_from_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(631)]));
-        mock_search
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(631)]));
+        mock_queries
             .expect_get_until_date()
-            .with(eq(date1), eq(0), eq(1))
-            .returning(|_, _, _| Ok(vec![PostId(628)]));
+            .with(eq(date1), eq(0), eq(1), eq(Audience::Public))
+            .returning(|_, _, _, _| Ok(vec![PostId(628)]));
         mock_posts
             .expect_get_by_id()
             .with(eq(next_post.id))
@@ -763,9 +804,11 @@ mod tests {
 
         let page = GetPostsByYearMonthUseCase::execute(
             &mock_posts,
-            &mock_search,
+            &mock_queries,
             &year_month,
             PageNumber::new(3).unwrap(),
+            10,
+            Audience::Public,
         )
         .await
         .unwrap();
@@ -784,4 +827,56 @@ mod tests {
             Some(AdjacentPageInfo::PageIndex(PageNumber::new(1).unwrap()))
         );
     }
+
+    #[tokio::test]
+    async fn get_posts_by_year_month_with_page_size() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let year_month = YearMonth::new(1989, 9).unwrap();
+        let date1 = DateTime::<Utc>::from(year_month);
+        let posts = vec![
+            Post::new(PostId(632), "test title", "test body", date1, date1),
+            Post::new(PostId(633), "test title", "test body", date1, date1),
+            Post::new(PostId(634), "test title", "test body", date1, date1),
+        ];
+        let post_ids = posts.iter().map(|p| p.id).collect::<Vec<_>>();
+        let post_ids_clone = post_ids.clone();
+        mock_queries
+            .expect_find_by_year_month()
+            .withf(move |ym, o, l, a| {
+                ym == &year_month && o == &3 && l == &3 && a == &Audience::Public
+            })
+            .returning(move |_, _, _, _| {
+                Ok(SearchResult {
+                    total_count: 7,
+                    post_ids: post_ids_clone.clone(),
+                })
+            });
+        mock_posts
+            .expect_get_by_ids()
+            .withf(move |ids| ids == post_ids.clone())
+            .returning(move |_| Ok(posts.clone()));
+
+        let page = GetPostsByYearMonthUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &year_month,
+            PageNumber::new(2).unwrap(),
+            3,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(page.index.0, 2);
+        assert_eq!(page.posts.len(), 3);
+        assert_eq!(
+            page.next_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber::new(3).unwrap()))
+        );
+        assert_eq!(
+            page.prev_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber::new(1).unwrap()))
+        );
+    }
 }
@@ -0,0 +1,101 @@
+use crate::{
+    adapters::{PostQueryRepository, PostsRepository},
+    models::{AdjacentPageInfo, Audience, MonthDay, Page, PageNumber},
+};
+
+pub struct GetPostsOnThisDayUseCase;
+
+impl GetPostsOnThisDayUseCase {
+    /// 年によらず`month_day`の日に書かれたPostを新しい順に返します
+    ///
+    /// 最後のページでは次の日を、最初のページでは前の日を隣のページとします
+    pub async fn execute<'a>(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        month_day: &'a MonthDay,
+        page_index: PageNumber,
+        per_page: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Page<'a, MonthDay, PageNumber>> {
+        let result = post_queries
+            .find_by_month_day(month_day, (page_index.0 - 1) * per_page, per_page, audience)
+            .await?;
+        let result_posts = if result.post_ids.is_empty() {
+            vec![]
+        } else {
+            posts.get_by_ids(&result.post_ids).await?
+        };
+
+        let next_page = if page_index.0 * per_page < result.total_count {
+            AdjacentPageInfo::PageIndex(page_index.next())
+        } else {
+            AdjacentPageInfo::Condition(month_day.next())
+        };
+        let prev_page = if page_index.0 > 1 {
+            AdjacentPageInfo::PageIndex(page_index.prev()?)
+        } else {
+            AdjacentPageInfo::Condition(month_day.prev())
+        };
+
+        Ok(Page {
+            condition: month_day,
+            index: page_index,
+            posts: result_posts,
+            next_page: Some(next_page),
+            prev_page: Some(prev_page),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{adapters::*, models::SearchResult};
+    use chrono::Utc;
+    use domain::entities::{Post, PostId};
+    use mockall::predicate::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn get_posts_on_this_day() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let month_day = MonthDay::new(10, 18).unwrap();
+        mock_queries
+            .expect_find_by_month_day()
+            .with(eq(month_day), eq(10), eq(10), eq(Audience::Public))
+            .returning(|_, _, _, _| {
+                Ok(SearchResult {
+                    post_ids: vec![PostId(2), PostId(1)],
+                    total_count: 12,
+                })
+            });
+        mock_posts.expect_get_by_ids().returning(|ids| {
+            let now = Utc::now();
+            Ok(ids
+                .iter()
+                .map(|id| Post::new(*id, "title", "body", now, now))
+                .collect())
+        });
+
+        let page = GetPostsOnThisDayUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &month_day,
+            PageNumber(2),
+            10,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.posts.len(), 2);
+        assert_eq!(
+            page.next_page,
+            Some(AdjacentPageInfo::Condition(MonthDay::new(10, 19).unwrap()))
+        );
+        assert_eq!(
+            page.prev_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber(1)))
+        );
+    }
+}
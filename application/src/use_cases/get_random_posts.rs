@@ -0,0 +1,58 @@
+use domain::entities::Post;
+
+use crate::{
+    adapters::{PostQueryRepository, PostsRepository},
+    models::Audience,
+    ApplicationResult,
+};
+
+pub struct GetRandomPostsUseCase;
+
+impl GetRandomPostsUseCase {
+    /// 見ることのできるPostから無作為に最大`count`件を返します
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        count: usize,
+        audience: Audience,
+    ) -> ApplicationResult<Vec<Post>> {
+        let post_ids = post_queries.get_random(count, audience).await?;
+        if post_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(posts.get_by_ids(&post_ids).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use chrono::Utc;
+    use domain::entities::PostId;
+    use mockall::predicate::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn get_random_posts() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        mock_queries
+            .expect_get_random()
+            .with(eq(2), eq(Audience::Public))
+            .returning(|_, _| Ok(vec![PostId(8), PostId(3)]));
+        mock_posts.expect_get_by_ids().returning(|ids| {
+            let now = Utc::now();
+            Ok(ids
+                .iter()
+                .map(|id| Post::new(*id, "title", "body", now, now))
+                .collect())
+        });
+
+        let posts = GetRandomPostsUseCase::execute(&mock_posts, &mock_queries, 2, Audience::Public)
+            .await
+            .unwrap();
+        let post_ids = posts.iter().map(|post| post.id).collect::<Vec<_>>();
+        assert_eq!(post_ids, vec![PostId(8), PostId(3)]);
+    }
+}
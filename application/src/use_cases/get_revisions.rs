@@ -0,0 +1,29 @@
+use domain::entities::{PostId, Revision, RevisionId};
+
+use crate::{adapters::PostsRepository, errors::ApplicationError, ApplicationResult};
+
+pub struct GetRevisionsUseCase;
+
+impl GetRevisionsUseCase {
+    /// Postのリビジョンを新しい順ですべて返します
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        id: &PostId,
+    ) -> ApplicationResult<Vec<Revision>> {
+        Ok(posts.get_revisions(id).await?)
+    }
+}
+
+pub struct GetRevisionUseCase;
+
+impl GetRevisionUseCase {
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        id: &RevisionId,
+    ) -> ApplicationResult<Revision> {
+        posts
+            .get_revision(id)
+            .await?
+            .ok_or(ApplicationError::RevisionNotFound)
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{Days, NaiveDate};
+
+use crate::{
+    adapters::{PostQueryRepository, PostsRepository},
+    models::{Audience, Statistics, Streak},
+    ApplicationResult,
+};
+
+pub struct GetStatisticsUseCase;
+
+impl GetStatisticsUseCase {
+    /// 文字数やリビジョンの多い記事を表示する件数
+    const RANKING_SIZE: usize = 5;
+
+    /// 管理ページに表示する統計を集計します。下書きや限定公開の記事も含みます
+    ///
+    /// `today`は連続して記事を書いた期間が今も続いているかどうかの判定に使います
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        today: NaiveDate,
+    ) -> ApplicationResult<Statistics> {
+        let audience = Audience::Admin;
+        let monthly = post_queries.get_monthly_stats(audience).await?;
+
+        let streaks = Streak::from_dates(&post_queries.get_dates(audience).await?);
+        // 同じ長さなら新しいほうを選ぶ
+        let longest_streak = streaks.iter().max_by_key(|streak| streak.days()).copied();
+        // 今日まだ書いていなくても、昨日まで続いていれば途切れていないとみなす
+        let yesterday = today.checked_sub_days(Days::new(1));
+        let current_streak = streaks
+            .last()
+            .filter(|streak| streak.until == today || Some(streak.until) == yesterday)
+            .copied();
+
+        let longest_ids = post_queries
+            .get_longest(Self::RANKING_SIZE, audience)
+            .await?;
+        let shortest_ids = post_queries
+            .get_shortest(Self::RANKING_SIZE, audience)
+            .await?;
+        let most_revised = post_queries
+            .get_most_revised(Self::RANKING_SIZE, audience)
+            .await?;
+        let most_revised_ids = most_revised.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        let most_revised_posts = posts
+            .get_by_ids(&most_revised_ids)
+            .await?
+            .into_iter()
+            .filter_map(|post| {
+                let (_, count) = most_revised.iter().find(|(id, _)| *id == post.id)?;
+                Some((post, *count))
+            })
+            .collect();
+
+        Ok(Statistics {
+            monthly,
+            longest_streak,
+            current_streak,
+            longest_posts: posts.get_by_ids(&longest_ids).await?,
+            shortest_posts: posts.get_by_ids(&shortest_ids).await?,
+            most_revised_posts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use chrono::Utc;
+    use domain::entities::{Post, PostId};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn streaks() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        mock_queries
+            .expect_get_monthly_stats()
+            .returning(|_| Ok(vec![]));
+        mock_queries.expect_get_dates().returning(|_| {
+            Ok(vec![
+                date(1),
+                date(2),
+                date(3),
+                date(10),
+                date(11),
+                date(12),
+            ])
+        });
+        mock_queries
+            .expect_get_longest()
+            .returning(|_, _| Ok(vec![]));
+        mock_queries
+            .expect_get_shortest()
+            .returning(|_, _| Ok(vec![]));
+        mock_queries
+            .expect_get_most_revised()
+            .returning(|_, _| Ok(vec![(PostId(2), 5), (PostId(1), 3)]));
+        mock_posts.expect_get_by_ids().returning(|ids| {
+            let now = Utc::now();
+            Ok(ids
+                .iter()
+                .map(|id| Post::new(*id, "title", "body", now, now))
+                .collect())
+        });
+
+        let statistics = GetStatisticsUseCase::execute(&mock_posts, &mock_queries, date(13))
+            .await
+            .unwrap();
+        let longest_streak = statistics.longest_streak.unwrap();
+        assert_eq!(longest_streak.from, date(10));
+        assert_eq!(longest_streak.days(), 3);
+        assert_eq!(statistics.current_streak, Some(longest_streak));
+        assert_eq!(statistics.most_revised_posts[0].0.id, PostId(2));
+        assert_eq!(statistics.most_revised_posts[0].1, 5);
+
+        // 2日以上あいたら途切れている
+        let statistics = GetStatisticsUseCase::execute(&mock_posts, &mock_queries, date(14))
+            .await
+            .unwrap();
+        assert_eq!(statistics.current_streak, None);
+    }
+}
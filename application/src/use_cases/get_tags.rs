@@ -0,0 +1,16 @@
+use crate::{
+    adapters::PostQueryRepository,
+    models::{Audience, TagCount},
+    ApplicationResult,
+};
+
+pub struct GetTagsUseCase;
+
+impl GetTagsUseCase {
+    pub async fn execute(
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        audience: Audience,
+    ) -> ApplicationResult<Vec<TagCount>> {
+        Ok(post_queries.get_tags(audience).await?)
+    }
+}
@@ -1,9 +1,16 @@
-use crate::{adapters::SearchClient, models::YearMonth, ApplicationResult};
+use crate::{
+    adapters::PostQueryRepository,
+    models::{Audience, YearMonthCount},
+    ApplicationResult,
+};
 
 pub struct GetYearMonthsUseCase;
 
 impl GetYearMonthsUseCase {
-    pub async fn execute(search_client: &impl SearchClient) -> ApplicationResult<Vec<YearMonth>> {
-        Ok(search_client.get_year_months().await?)
+    pub async fn execute(
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        audience: Audience,
+    ) -> ApplicationResult<Vec<YearMonthCount>> {
+        Ok(post_queries.get_year_months(audience).await?)
     }
 }
@@ -0,0 +1,317 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{Media, MediaId, MediaVariant, NewMedia};
+
+use crate::{
+    adapters::{ImageProcessor, MediaRepository, MediaRepositoryError, MediaStorage},
+    errors::ApplicationError,
+    models::UploadedFile,
+    ApplicationResult,
+};
+
+pub struct GetAllMediaUseCase;
+
+impl GetAllMediaUseCase {
+    pub async fn execute(media: &(impl MediaRepository + ?Sized)) -> ApplicationResult<Vec<Media>> {
+        Ok(media.get_all().await?)
+    }
+}
+
+pub struct UploadMediaUseCase;
+
+impl UploadMediaUseCase {
+    /// ファイルを保存します。画像ならサムネイルなどの縮小版も作ります
+    ///
+    /// ファイル名は`created_at`から作り、同時にアップロードしたファイルには番号をつけて重ならないようにします
+    pub async fn execute(
+        media: &(impl MediaRepository + ?Sized),
+        storage: &impl MediaStorage,
+        image_processor: &impl ImageProcessor,
+        files: &[UploadedFile<'_>],
+        created_at: DateTime<Utc>,
+    ) -> ApplicationResult<Vec<Media>> {
+        let mut uploaded = Vec::with_capacity(files.len());
+        for (index, file) in files.iter().enumerate() {
+            let new_media = Self::new_media(image_processor, file, created_at).numbered(index);
+            uploaded
+                .push(Self::upload(media, storage, image_processor, new_media, file.data).await?);
+        }
+        Ok(uploaded)
+    }
+
+    fn new_media(
+        image_processor: &impl ImageProcessor,
+        file: &UploadedFile<'_>,
+        created_at: DateTime<Utc>,
+    ) -> NewMedia {
+        let dimensions = if file.content_type.starts_with("image/") {
+            image_processor.dimensions(file.data)
+        } else {
+            None
+        };
+        NewMedia::new(
+            file.name,
+            file.content_type,
+            file.data.len() as i64,
+            dimensions,
+            created_at,
+        )
+    }
+
+    async fn upload(
+        media: &(impl MediaRepository + ?Sized),
+        storage: &impl MediaStorage,
+        image_processor: &impl ImageProcessor,
+        new_media: NewMedia,
+        data: &[u8],
+    ) -> ApplicationResult<Media> {
+        let uploaded = media
+            .add(new_media)
+            .await
+            .map_err(|e| match e.downcast_ref() {
+                Some(MediaRepositoryError::DuplicateName) => ApplicationError::DuplicateMediaName,
+                _ => e.into(),
+            })?;
+        // ファイルを保存できなければ、記録も残さない
+        if let Err(e) = Self::store(storage, image_processor, &uploaded, data).await {
+            media.remove(&uploaded.id).await?;
+            return Err(e.into());
+        }
+        Ok(uploaded)
+    }
+
+    async fn store(
+        storage: &impl MediaStorage,
+        image_processor: &impl ImageProcessor,
+        media: &Media,
+        data: &[u8],
+    ) -> anyhow::Result<()> {
+        storage.put(&media.name, data).await?;
+        for variant in MediaVariant::ALL {
+            let Some((width, height)) = media.variant_dimensions(variant) else {
+                continue;
+            };
+            let resized = image_processor.resize(data, width, height).await?;
+            storage.put(&media.variant_name(variant), &resized).await?;
+        }
+        Ok(())
+    }
+}
+
+pub struct DeleteMediaUseCase;
+
+impl DeleteMediaUseCase {
+    /// Mediaを削除します。本文から参照されていても削除します
+    pub async fn execute(
+        media: &(impl MediaRepository + ?Sized),
+        storage: &impl MediaStorage,
+        id: &MediaId,
+    ) -> ApplicationResult<()> {
+        let target = media
+            .get(id)
+            .await?
+            .ok_or(ApplicationError::MediaNotFound)?;
+        media.remove(id).await?;
+        let mut names = vec![target.name.clone()];
+        if target.is_image() {
+            names.extend(MediaVariant::ALL.map(|variant| target.variant_name(variant)));
+        }
+        for name in names {
+            if let Err(e) = storage.delete(&name).await {
+                log::warn!("failed to delete media file {name}: {e}");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use assert_matches::assert_matches;
+    use mockall::{predicate::*, Sequence};
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn test_upload_image() {
+        let mut mock_media = MockMediaRepository::new();
+        let mut mock_storage = MockMediaStorage::new();
+        let mut mock_processor = MockImageProcessor::new();
+        let now = Utc::now();
+        mock_processor
+            .expect_dimensions()
+            .returning(|_| Some((2560, 1440)));
+        mock_media
+            .expect_add()
+            .times(1)
+            .returning(|new_media| Ok(Media::new(MediaId(1), new_media)));
+        mock_processor
+            .expect_resize()
+            .with(always(), eq(320), eq(180))
+            .times(1)
+            .returning(|_, _, _| Ok(vec![1]));
+        mock_processor
+            .expect_resize()
+            .with(always(), eq(1280), eq(720))
+            .times(1)
+            .returning(|_, _, _| Ok(vec![2]));
+        mock_storage.expect_put().times(3).returning(|_, _| Ok(()));
+        mock_media.expect_remove().never();
+
+        let media = UploadMediaUseCase::execute(
+            &mock_media,
+            &mock_storage,
+            &mock_processor,
+            &[UploadedFile {
+                name: "photo.jpg",
+                content_type: "image/jpeg",
+                data: &[0],
+            }],
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(media[0].dimensions, Some((2560, 1440)));
+    }
+
+    #[tokio::test]
+    async fn test_upload_file() {
+        let mut mock_media = MockMediaRepository::new();
+        let mut mock_storage = MockMediaStorage::new();
+        let mut mock_processor = MockImageProcessor::new();
+        let now = Utc::now();
+        // 画像でなければ縮小版は作らない
+        mock_processor.expect_dimensions().never();
+        mock_processor.expect_resize().never();
+        mock_media
+            .expect_add()
+            .returning(|new_media| Ok(Media::new(MediaId(1), new_media)));
+        mock_storage.expect_put().times(1).returning(|_, _| Ok(()));
+
+        let media = UploadMediaUseCase::execute(
+            &mock_media,
+            &mock_storage,
+            &mock_processor,
+            &[UploadedFile {
+                name: "memo.pdf",
+                content_type: "application/pdf",
+                data: &[0],
+            }],
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert!(!media[0].is_image());
+    }
+
+    #[tokio::test]
+    async fn test_upload_failed() {
+        let mut mock_media = MockMediaRepository::new();
+        let mut mock_storage = MockMediaStorage::new();
+        let mock_processor = MockImageProcessor::new();
+        let now = Utc::now();
+        mock_media
+            .expect_add()
+            .returning(|new_media| Ok(Media::new(MediaId(1), new_media)));
+        mock_storage
+            .expect_put()
+            .returning(|_, _| Err(anyhow::anyhow!("disk full")));
+        mock_media
+            .expect_remove()
+            .with(eq(MediaId(1)))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let result = UploadMediaUseCase::execute(
+            &mock_media,
+            &mock_storage,
+            &mock_processor,
+            &[UploadedFile {
+                name: "memo.txt",
+                content_type: "text/plain",
+                data: &[0],
+            }],
+            now,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_upload_multiple_files() {
+        let mut mock_media = MockMediaRepository::new();
+        let mut mock_storage = MockMediaStorage::new();
+        let mock_processor = MockImageProcessor::new();
+        let now = Utc::now();
+        let mut seq = Sequence::new();
+        // 同時にアップロードしても名前は重ならない
+        let names = [
+            format!("{}.txt", now.format("%Y%m%d%H%M%S%3f")),
+            format!("{}-1.txt", now.format("%Y%m%d%H%M%S%3f")),
+        ];
+        for (id, name) in names.into_iter().enumerate() {
+            mock_media
+                .expect_add()
+                .withf(move |new_media| new_media.name == name)
+                .times(1)
+                .in_sequence(&mut seq)
+                .returning(move |new_media| Ok(Media::new(MediaId(id as i32 + 1), new_media)));
+        }
+        mock_storage.expect_put().times(2).returning(|_, _| Ok(()));
+
+        let media = UploadMediaUseCase::execute(
+            &mock_media,
+            &mock_storage,
+            &mock_processor,
+            &[
+                UploadedFile {
+                    name: "a.txt",
+                    content_type: "text/plain",
+                    data: &[0],
+                },
+                UploadedFile {
+                    name: "b.txt",
+                    content_type: "text/plain",
+                    data: &[1],
+                },
+            ],
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(media.len(), 2);
+        assert_ne!(media[0].name, media[1].name);
+    }
+
+    #[tokio::test]
+    async fn test_upload_duplicate_name() {
+        let mut mock_media = MockMediaRepository::new();
+        let mut mock_storage = MockMediaStorage::new();
+        let mock_processor = MockImageProcessor::new();
+        let now = Utc::now();
+        // 別のリクエストで同じ日時にアップロードされていた
+        mock_media
+            .expect_add()
+            .returning(|_| Err(MediaRepositoryError::DuplicateName.into()));
+        mock_storage.expect_put().never();
+
+        let result = UploadMediaUseCase::execute(
+            &mock_media,
+            &mock_storage,
+            &mock_processor,
+            &[UploadedFile {
+                name: "memo.txt",
+                content_type: "text/plain",
+                data: &[0],
+            }],
+            now,
+        )
+        .await;
+
+        assert_matches!(result, Err(ApplicationError::DuplicateMediaName));
+    }
+}
@@ -0,0 +1,79 @@
+use domain::entities::{Page, PageName};
+
+use crate::{adapters::PagesRepository, errors::ApplicationError, ApplicationResult};
+
+pub struct GetPageUseCase;
+
+impl GetPageUseCase {
+    pub async fn execute(
+        pages: &(impl PagesRepository + ?Sized),
+        name: &PageName,
+    ) -> ApplicationResult<Page> {
+        pages.get(name).await?.ok_or(ApplicationError::PageNotFound)
+    }
+}
+
+pub struct GetPagesUseCase;
+
+impl GetPagesUseCase {
+    pub async fn execute(pages: &(impl PagesRepository + ?Sized)) -> ApplicationResult<Vec<Page>> {
+        Ok(pages.get_all().await?)
+    }
+}
+
+pub struct SavePageUseCase;
+
+impl SavePageUseCase {
+    /// Pageを作成するか、同じ名前のPageを上書きします
+    pub async fn execute(
+        pages: &(impl PagesRepository + ?Sized),
+        page: &Page,
+    ) -> ApplicationResult<Page> {
+        Ok(pages.save(page).await?)
+    }
+}
+
+pub struct DeletePageUseCase;
+
+impl DeletePageUseCase {
+    pub async fn execute(
+        pages: &(impl PagesRepository + ?Sized),
+        name: &PageName,
+    ) -> ApplicationResult<()> {
+        Ok(pages.remove(name).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use assert_matches::assert_matches;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_get_page() {
+        let mut mock_pages = MockPagesRepository::new();
+        mock_pages
+            .expect_get()
+            .withf(|name| name.as_str() == "about")
+            .returning(|name| Ok(Some(Page::new(name.clone(), "about", "body", Utc::now()))));
+
+        let page = GetPageUseCase::execute(&mock_pages, &PageName::new("about").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(page.title, "about");
+    }
+
+    #[tokio::test]
+    async fn test_get_page_not_found() {
+        let mut mock_pages = MockPagesRepository::new();
+        mock_pages.expect_get().returning(|_| Ok(None));
+
+        let result =
+            GetPageUseCase::execute(&mock_pages, &PageName::new("colophon").unwrap()).await;
+
+        assert_matches!(result, Err(ApplicationError::PageNotFound));
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use domain::entities::PostId;
+
+use crate::{adapters::PostsRepository, errors::ApplicationError, ApplicationResult};
+
+pub struct PinPostUseCase;
+
+impl PinPostUseCase {
+    /// Postをトップページの先頭に固定します。あとから固定したものほど上に表示されます
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        id: &PostId,
+        now: DateTime<Utc>,
+    ) -> ApplicationResult<()> {
+        match posts.get_by_id(id).await? {
+            Some(post) if !post.is_deleted() => {
+                posts.set_pinned_at(id, Some(now)).await?;
+                Ok(())
+            }
+            _ => Err(ApplicationError::PostNotFound),
+        }
+    }
+}
+
+pub struct UnpinPostUseCase;
+
+impl UnpinPostUseCase {
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        id: &PostId,
+    ) -> ApplicationResult<()> {
+        posts.set_pinned_at(id, None).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use assert_matches::assert_matches;
+    use domain::entities::Post;
+    use mockall::predicate::*;
+
+    #[tokio::test]
+    async fn trashed_post_cannot_be_pinned() {
+        let mut mock_posts = MockPostsRepository::new();
+        let now = Utc::now();
+        mock_posts.expect_get_by_id().returning(move |id| {
+            let mut post = Post::new(*id, "title", "body", now, now);
+            post.deleted_at = Some(now);
+            Ok(Some(post))
+        });
+        mock_posts.expect_set_pinned_at().never();
+
+        let result = PinPostUseCase::execute(&mock_posts, &PostId(1), now).await;
+        assert_matches!(result, Err(ApplicationError::PostNotFound));
+    }
+
+    #[tokio::test]
+    async fn pin_post() {
+        let mut mock_posts = MockPostsRepository::new();
+        let now = Utc::now();
+        mock_posts
+            .expect_get_by_id()
+            .returning(move |id| Ok(Some(Post::new(*id, "title", "body", now, now))));
+        mock_posts
+            .expect_set_pinned_at()
+            .with(eq(PostId(1)), eq(Some(now)))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        PinPostUseCase::execute(&mock_posts, &PostId(1), now)
+            .await
+            .unwrap();
+    }
+}
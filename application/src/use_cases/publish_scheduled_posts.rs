@@ -0,0 +1,187 @@
+use chrono::{DateTime, Utc};
+use domain::entities::Post;
+
+use crate::{
+    adapters::{PostQueryRepository, PostsRepository, SearchIndex},
+    ApplicationResult,
+};
+
+pub struct PublishScheduledPostsUseCase;
+
+impl PublishScheduledPostsUseCase {
+    /// `from`より後、`until`以前に公開日時を迎えたPostを検索インデックスに反映します
+    ///
+    /// 予約投稿は`updated_at`を公開日時にして保存してあるので、記事そのものは変えません
+    /// 記事は`BATCH_SIZE`件ずつ読み込みます
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        search_index: &(impl SearchIndex + ?Sized),
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> ApplicationResult<Vec<Post>> {
+        const BATCH_SIZE: usize = 100;
+        let post_ids = post_queries.get_published_between(from, until).await?;
+        let mut published_posts = vec![];
+        for ids in post_ids.chunks(BATCH_SIZE) {
+            for post in posts.get_by_ids(ids).await? {
+                // 読み込むまでに公開日時が変えられた記事は、この範囲では公開しない
+                if post.published_at <= from || post.published_at > until {
+                    continue;
+                }
+                if let Err(e) = search_index.save(&post).await {
+                    log::warn!("failed to update search index: {e}");
+                }
+                published_posts.push(post);
+            }
+        }
+        Ok(published_posts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use chrono::Duration;
+    use domain::entities::PostId;
+    use mockall::predicate::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn test_publish_scheduled_posts() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let from = now - Duration::minutes(1);
+        let published_at = now - Duration::seconds(30);
+        mock_queries
+            .expect_get_published_between()
+            .with(eq(from), eq(now))
+            .returning(|_, _| Ok(vec![PostId(1)]));
+        mock_posts
+            .expect_get_by_ids()
+            .withf(|ids| ids == [PostId(1)])
+            .returning(move |_| {
+                let post = Post::new(PostId(1), "title", "body", published_at, published_at);
+                Ok(vec![post])
+            });
+        mock_posts.expect_save().never();
+        mock_search
+            .expect_save()
+            .withf(move |post| post.id == PostId(1))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let posts = PublishScheduledPostsUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &mock_search,
+            from,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].id, PostId(1));
+    }
+
+    #[tokio::test]
+    async fn test_publish_scheduled_posts_none() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let from = now - Duration::minutes(1);
+        mock_queries
+            .expect_get_published_between()
+            .with(eq(from), eq(now))
+            .returning(|_, _| Ok(vec![]));
+        mock_posts.expect_get_by_ids().never();
+        mock_posts.expect_save().never();
+        mock_search.expect_save().never();
+
+        let posts = PublishScheduledPostsUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &mock_search,
+            from,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert!(posts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_publish_scheduled_posts_in_batches() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let from = now - Duration::minutes(1);
+        let published_at = now - Duration::seconds(30);
+        mock_queries
+            .expect_get_published_between()
+            .with(eq(from), eq(now))
+            .returning(|_, _| Ok((1..=150).map(PostId).collect()));
+        mock_posts
+            .expect_get_by_ids()
+            .withf(|ids| ids.len() <= 100)
+            .times(2)
+            .returning(move |ids| {
+                Ok(ids
+                    .iter()
+                    .map(|id| Post::new(*id, "title", "body", published_at, published_at))
+                    .collect())
+            });
+        mock_search.expect_save().times(150).returning(|_| Ok(()));
+
+        let posts = PublishScheduledPostsUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &mock_search,
+            from,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(posts.len(), 150);
+    }
+
+    #[tokio::test]
+    async fn test_publish_scheduled_posts_skips_rescheduled() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let from = now - Duration::minutes(1);
+        let published_at = now + Duration::hours(1);
+        mock_queries
+            .expect_get_published_between()
+            .with(eq(from), eq(now))
+            .returning(|_, _| Ok(vec![PostId(1)]));
+        // 読み込むまでに公開日時が先に延ばされた
+        mock_posts.expect_get_by_ids().returning(move |_| {
+            let post = Post::new(PostId(1), "title", "body", published_at, published_at);
+            Ok(vec![post])
+        });
+        mock_search.expect_save().never();
+
+        let posts = PublishScheduledPostsUseCase::execute(
+            &mock_posts,
+            &mock_queries,
+            &mock_search,
+            from,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert!(posts.is_empty());
+    }
+}
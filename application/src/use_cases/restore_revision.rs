@@ -0,0 +1,119 @@
+use domain::entities::{Post, RevisionId};
+
+use crate::{
+    adapters::{BodyAnalyzer, PostsRepository, SearchIndex},
+    errors::ApplicationError,
+    ApplicationResult,
+};
+
+use super::UpdatePostUseCase;
+
+pub struct RestoreRevisionUseCase;
+
+impl RestoreRevisionUseCase {
+    /// リビジョンのタイトルと本文でPostを更新します
+    ///
+    /// 古いリビジョンを書き換えるのではなく、新しいリビジョンとして記録されます
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        search_index: &(impl SearchIndex + ?Sized),
+        body_analyzer: &impl BodyAnalyzer,
+        id: &RevisionId,
+    ) -> ApplicationResult<Post> {
+        let revision = posts
+            .get_revision(id)
+            .await?
+            .ok_or(ApplicationError::RevisionNotFound)?;
+        let mut post = posts
+            .get_by_id(&revision.post_id)
+            .await?
+            .ok_or(ApplicationError::PostNotFound)?;
+        post.title = revision.title;
+        post.body = revision.body;
+        UpdatePostUseCase::execute(posts, search_index, body_analyzer, &post).await?;
+        Ok(post)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use assert_matches::assert_matches;
+    use chrono::{Duration, Utc};
+    use domain::entities::{PostId, PostStats, Revision};
+    use mockall::predicate::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn restore_revision() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let post_id = PostId(629);
+        let revision_id = RevisionId(3);
+        mock_posts
+            .expect_get_revision()
+            .with(eq(revision_id))
+            .returning(move |_| {
+                Ok(Some(Revision::new(
+                    revision_id,
+                    post_id,
+                    "old title",
+                    "old body",
+                    now - Duration::days(1),
+                )))
+            });
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(post_id))
+            .returning(move |_| Ok(Some(Post::new(post_id, "title", "body", now, now))));
+        mock_posts
+            .expect_save()
+            .withf(|post| post.title == "old title" && post.body == "old body")
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+        let mut mock_analyzer = MockBodyAnalyzer::new();
+        mock_analyzer
+            .expect_stats()
+            .with(eq("old body"))
+            .returning(|body| PostStats::new(body.chars().count()));
+        mock_search
+            .expect_save()
+            .withf(|post| post.title == "old title")
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let post = RestoreRevisionUseCase::execute(
+            &mock_posts,
+            &mock_search,
+            &mock_analyzer,
+            &revision_id,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(post.id, post_id);
+        assert_eq!(post.title, "old title");
+        assert_eq!(post.body, "old body");
+    }
+
+    #[tokio::test]
+    async fn restore_revision_not_found() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        mock_posts.expect_get_revision().returning(|_| Ok(None));
+        mock_posts.expect_save().never();
+        mock_search.expect_save().never();
+
+        let result = RestoreRevisionUseCase::execute(
+            &mock_posts,
+            &mock_search,
+            &MockBodyAnalyzer::new(),
+            &RevisionId(3),
+        )
+        .await;
+
+        assert_matches!(result, Err(ApplicationError::RevisionNotFound));
+    }
+}
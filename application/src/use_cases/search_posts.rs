@@ -1,29 +1,56 @@
+use domain::entities::Tag;
+
 use crate::{
-    adapters::{PostsRepository, SearchClient},
-    models::{AdjacentPageInfo, Page, PageNumber},
+    adapters::{PostQueryRepository, PostsRepository, SearchIndex},
+    models::{AdjacentPageInfo, Audience, Page, PageNumber},
     ApplicationResult,
 };
 
 pub struct SearchPostsUseCase;
 
 impl SearchPostsUseCase {
-    pub async fn execute<'a, S: SearchClient>(
-        search_client: &S,
-        posts: &impl PostsRepository,
+    /// `tag:名前`の形式のキーワードはタグでの絞り込みとして扱います
+    ///
+    /// キーワードにマッチするPostを`search_index`で探し、見せてよいものを`post_queries`で絞り込んで並べます
+    pub async fn execute<'a>(
+        search_index: &(impl SearchIndex + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        posts: &(impl PostsRepository + ?Sized),
         keywords: &'a Vec<&'a str>,
         page_index: PageNumber,
+        per_page: usize,
+        audience: Audience,
     ) -> ApplicationResult<Page<'a, Vec<&'a str>, PageNumber>> {
-        let result = search_client
-            .find_by_keywords(keywords, (page_index.0 - 1) * 10, 10) // TODO: per_page
+        let mut tags = vec![];
+        let mut text_keywords = vec![];
+        for keyword in keywords {
+            match keyword.strip_prefix("tag:").map(Tag::new) {
+                Some(Ok(tag)) => tags.push(tag),
+                _ => text_keywords.push(*keyword),
+            }
+        }
+        let ids = if text_keywords.is_empty() {
+            None
+        } else {
+            Some(search_index.search(&text_keywords).await?)
+        };
+        let result = post_queries
+            .find_by_ids_and_tags(
+                ids.as_deref(),
+                &tags,
+                (page_index.0 - 1) * per_page,
+                per_page,
+                audience,
+            )
             .await?;
 
-        let next_page = if page_index.0 * 10 < result.total_count {
+        let next_page = if page_index.0 * per_page < result.total_count {
             Some(AdjacentPageInfo::PageIndex(page_index.next()))
         } else {
             None
         };
         let prev_page = if page_index.0 > 1 {
-            let max_page_index = (result.total_count + 9) / 10;
+            let max_page_index = result.total_count.div_ceil(per_page);
             Some(AdjacentPageInfo::PageIndex(
                 PageNumber::new(max_page_index.min(page_index.0 - 1)).expect("page_index > 1"),
             ))
@@ -44,3 +71,102 @@ impl SearchPostsUseCase {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{adapters::*, models::SearchResult};
+    use assert_matches::assert_matches;
+    use chrono::Utc;
+    use domain::entities::{Post, PostId};
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn search_posts() {
+        let mut mock_search = MockSearchIndex::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let mut mock_posts = MockPostsRepository::new();
+        let now = Utc::now();
+        mock_search
+            .expect_search()
+            .withf(|keywords| keywords == ["hello", "world"])
+            .returning(|_| Ok(vec![PostId(3), PostId(1), PostId(2)]));
+        mock_queries
+            .expect_find_by_ids_and_tags()
+            .withf(|ids, tags, offset, limit, audience| {
+                ids == &Some([PostId(3), PostId(1), PostId(2)].as_slice())
+                    && tags == [Tag::new("rust").unwrap()]
+                    && *offset == 2
+                    && *limit == 2
+                    && *audience == Audience::Public
+            })
+            .returning(|_, _, _, _, _| {
+                Ok(SearchResult {
+                    post_ids: vec![PostId(1)],
+                    total_count: 3,
+                })
+            });
+        mock_posts
+            .expect_get_by_ids()
+            .withf(|ids| ids == [PostId(1)])
+            .returning(move |ids| {
+                Ok(ids
+                    .iter()
+                    .map(|id| Post::new(*id, "title", "body", now, now))
+                    .collect())
+            });
+
+        let keywords = vec!["hello", "tag:rust", "world"];
+        let page = SearchPostsUseCase::execute(
+            &mock_search,
+            &mock_queries,
+            &mock_posts,
+            &keywords,
+            PageNumber::new(2).unwrap(),
+            2,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(page.posts.len(), 1);
+        assert_matches!(page.next_page, None);
+        assert_matches!(
+            page.prev_page,
+            Some(AdjacentPageInfo::PageIndex(PageNumber(1)))
+        );
+    }
+
+    #[tokio::test]
+    async fn search_posts_by_tags_only() {
+        let mut mock_search = MockSearchIndex::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let mock_posts = MockPostsRepository::new();
+        // キーワードがなければ索引は使わない
+        mock_search.expect_search().never();
+        mock_queries
+            .expect_find_by_ids_and_tags()
+            .withf(|ids, tags, _, _, _| ids.is_none() && tags == [Tag::new("rust").unwrap()])
+            .returning(|_, _, _, _, _| {
+                Ok(SearchResult {
+                    post_ids: vec![],
+                    total_count: 0,
+                })
+            });
+
+        let keywords = vec!["tag:rust"];
+        let page = SearchPostsUseCase::execute(
+            &mock_search,
+            &mock_queries,
+            &mock_posts,
+            &keywords,
+            PageNumber::new(1).unwrap(),
+            10,
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+
+        assert!(page.posts.is_empty());
+    }
+}
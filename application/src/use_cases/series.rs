@@ -0,0 +1,277 @@
+use domain::entities::{Post, PostId, Series, SeriesId};
+
+use crate::{
+    adapters::{PostsRepository, SeriesRepository, SeriesRepositoryError},
+    errors::ApplicationError,
+    models::{Audience, SeriesNavigation, SeriesPosts},
+    ApplicationResult,
+};
+
+/// Seriesに入っている記事のうち、一覧に載せてよいものを並び順で取得します
+async fn get_readable_posts(
+    posts: &(impl PostsRepository + ?Sized),
+    series: &Series,
+    audience: Audience,
+) -> ApplicationResult<Vec<Post>> {
+    if series.post_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut found = posts.get_by_ids(&series.post_ids).await?;
+    Ok(series
+        .post_ids
+        .iter()
+        .filter_map(|id| {
+            let index = found.iter().position(|post| post.id == *id)?;
+            Some(found.swap_remove(index))
+        })
+        .filter(|post| audience.can_list(post))
+        .collect())
+}
+
+pub struct GetSeriesUseCase;
+
+impl GetSeriesUseCase {
+    pub async fn execute(
+        series_repository: &(impl SeriesRepository + ?Sized),
+        posts: &(impl PostsRepository + ?Sized),
+        id: &SeriesId,
+        audience: Audience,
+    ) -> ApplicationResult<SeriesPosts> {
+        let series = series_repository
+            .get(id)
+            .await?
+            .ok_or(ApplicationError::SeriesNotFound)?;
+        let posts = get_readable_posts(posts, &series, audience).await?;
+        Ok(SeriesPosts { series, posts })
+    }
+}
+
+pub struct GetAllSeriesUseCase;
+
+impl GetAllSeriesUseCase {
+    pub async fn execute(
+        series_repository: &(impl SeriesRepository + ?Sized),
+    ) -> ApplicationResult<Vec<Series>> {
+        Ok(series_repository.get_all().await?)
+    }
+}
+
+pub struct GetSeriesNavigationUseCase;
+
+impl GetSeriesNavigationUseCase {
+    /// 記事が入っているSeriesの中での前後の記事を返します。Seriesに入っていなければ`None`を返します
+    pub async fn execute(
+        series_repository: &(impl SeriesRepository + ?Sized),
+        posts: &(impl PostsRepository + ?Sized),
+        post_id: &PostId,
+        audience: Audience,
+    ) -> ApplicationResult<Option<SeriesNavigation>> {
+        let Some(series) = series_repository.get_by_post_id(post_id).await? else {
+            return Ok(None);
+        };
+        let mut posts = get_readable_posts(posts, &series, audience).await?;
+        let Some(index) = posts.iter().position(|post| post.id == *post_id) else {
+            return Ok(None);
+        };
+        let count = posts.len();
+        let next = (index + 1 < count).then(|| posts.remove(index + 1));
+        let prev = (index > 0).then(|| posts.remove(index - 1));
+        Ok(Some(SeriesNavigation {
+            series,
+            position: index + 1,
+            count,
+            prev,
+            next,
+        }))
+    }
+}
+
+pub struct CreateSeriesUseCase;
+
+impl CreateSeriesUseCase {
+    pub async fn execute(
+        series_repository: &(impl SeriesRepository + ?Sized),
+        title: &str,
+    ) -> ApplicationResult<Series> {
+        Ok(series_repository.add(title).await?)
+    }
+}
+
+pub struct AddPostToSeriesUseCase;
+
+impl AddPostToSeriesUseCase {
+    /// 記事をSeriesの最後に追加します。ほかのSeriesに入っている記事は追加できません
+    pub async fn execute(
+        series_repository: &(impl SeriesRepository + ?Sized),
+        posts: &(impl PostsRepository + ?Sized),
+        id: &SeriesId,
+        post_id: &PostId,
+    ) -> ApplicationResult<Series> {
+        let mut series = series_repository
+            .get(id)
+            .await?
+            .ok_or(ApplicationError::SeriesNotFound)?;
+        if posts.get_by_id(post_id).await?.is_none() {
+            return Err(ApplicationError::PostNotFound);
+        }
+        if let Some(other) = series_repository.get_by_post_id(post_id).await? {
+            if other.id != series.id {
+                return Err(ApplicationError::PostAlreadyInSeries);
+            }
+        }
+        series.add_post(*post_id);
+        // 確かめてから保存するまでに同じ記事がほかのSeriesに追加されることもある
+        series_repository
+            .save(&series)
+            .await
+            .map_err(|e| match e.downcast_ref() {
+                Some(SeriesRepositoryError::PostAlreadyInSeries) => {
+                    ApplicationError::PostAlreadyInSeries
+                }
+                _ => e.into(),
+            })
+    }
+}
+
+pub struct UpdateSeriesUseCase;
+
+impl UpdateSeriesUseCase {
+    /// タイトルと記事の並び順を保存します
+    pub async fn execute(
+        series_repository: &(impl SeriesRepository + ?Sized),
+        series: &Series,
+    ) -> ApplicationResult<Series> {
+        Ok(series_repository.save(series).await?)
+    }
+}
+
+pub struct DeleteSeriesUseCase;
+
+impl DeleteSeriesUseCase {
+    /// Seriesを削除します。入っていた記事は削除しません
+    pub async fn execute(
+        series_repository: &(impl SeriesRepository + ?Sized),
+        id: &SeriesId,
+    ) -> ApplicationResult<()> {
+        Ok(series_repository.remove(id).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use assert_matches::assert_matches;
+    use chrono::{Duration, Utc};
+    use domain::entities::PostStatus;
+    use mockall::predicate::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn test_get_series_navigation() {
+        let mut mock_series = MockSeriesRepository::new();
+        let mut mock_posts = MockPostsRepository::new();
+        let now = Utc::now();
+        mock_series
+            .expect_get_by_post_id()
+            .with(eq(PostId(3)))
+            .returning(|_| {
+                let post_ids = vec![PostId(5), PostId(2), PostId(3), PostId(4)];
+                Ok(Some(Series::new(SeriesId(1), "series", post_ids)))
+            });
+        mock_posts.expect_get_by_ids().returning(move |ids| {
+            // 日付順で返ってきても並び順で並べ直す
+            let mut posts = ids
+                .iter()
+                .map(|id| Post::new(*id, "title", "body", now, now))
+                .collect::<Vec<_>>();
+            posts.sort_by_key(|post| post.id);
+            // 2は下書きなので一般の閲覧者には見えない
+            posts[0].status = PostStatus::Draft;
+            Ok(posts)
+        });
+
+        let navigation = GetSeriesNavigationUseCase::execute(
+            &mock_series,
+            &mock_posts,
+            &PostId(3),
+            Audience::Public,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(navigation.position, 2);
+        assert_eq!(navigation.count, 3);
+        assert_eq!(navigation.prev.unwrap().id, PostId(5));
+        assert_eq!(navigation.next.unwrap().id, PostId(4));
+    }
+
+    #[tokio::test]
+    async fn test_get_series_navigation_not_in_series() {
+        let mut mock_series = MockSeriesRepository::new();
+        let mut mock_posts = MockPostsRepository::new();
+        mock_series.expect_get_by_post_id().returning(|_| Ok(None));
+        mock_posts.expect_get_by_ids().never();
+
+        let navigation = GetSeriesNavigationUseCase::execute(
+            &mock_series,
+            &mock_posts,
+            &PostId(3),
+            Audience::Public,
+        )
+        .await
+        .unwrap();
+
+        assert!(navigation.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_add_post_in_other_series() {
+        let mut mock_series = MockSeriesRepository::new();
+        let mut mock_posts = MockPostsRepository::new();
+        let now = Utc::now() - Duration::days(1);
+        mock_series
+            .expect_get()
+            .with(eq(SeriesId(1)))
+            .returning(|id| Ok(Some(Series::new(*id, "series", vec![]))));
+        mock_posts
+            .expect_get_by_id()
+            .returning(move |id| Ok(Some(Post::new(*id, "title", "body", now, now))));
+        mock_series
+            .expect_get_by_post_id()
+            .returning(|post_id| Ok(Some(Series::new(SeriesId(2), "other", vec![*post_id]))));
+        mock_series.expect_save().never();
+
+        let result =
+            AddPostToSeriesUseCase::execute(&mock_series, &mock_posts, &SeriesId(1), &PostId(3))
+                .await;
+
+        assert_matches!(result, Err(ApplicationError::PostAlreadyInSeries));
+    }
+
+    #[tokio::test]
+    async fn test_add_post_added_to_other_series_concurrently() {
+        let mut mock_series = MockSeriesRepository::new();
+        let mut mock_posts = MockPostsRepository::new();
+        let now = Utc::now() - Duration::days(1);
+        mock_series
+            .expect_get()
+            .with(eq(SeriesId(1)))
+            .returning(|id| Ok(Some(Series::new(*id, "series", vec![]))));
+        mock_posts
+            .expect_get_by_id()
+            .returning(move |id| Ok(Some(Post::new(*id, "title", "body", now, now))));
+        mock_series.expect_get_by_post_id().returning(|_| Ok(None));
+        // 確かめたあとでほかのSeriesに追加された
+        mock_series
+            .expect_save()
+            .returning(|_| Err(SeriesRepositoryError::PostAlreadyInSeries.into()));
+
+        let result =
+            AddPostToSeriesUseCase::execute(&mock_series, &mock_posts, &SeriesId(1), &PostId(3))
+                .await;
+
+        assert_matches!(result, Err(ApplicationError::PostAlreadyInSeries));
+    }
+}
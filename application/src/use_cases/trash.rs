@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{Post, PostId};
+
+use crate::{
+    adapters::{PostQueryRepository, PostsRepository, SearchIndex},
+    errors::ApplicationError,
+    ApplicationResult,
+};
+
+pub struct GetTrashedPostsUseCase;
+
+impl GetTrashedPostsUseCase {
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+    ) -> ApplicationResult<Vec<Post>> {
+        let post_ids = post_queries.get_trashed().await?;
+        if post_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(posts.get_by_ids(&post_ids).await?)
+    }
+}
+
+pub struct RestorePostUseCase;
+
+impl RestorePostUseCase {
+    /// ゴミ箱からPostを戻して、検索インデックスにも登録し直します
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        search_index: &(impl SearchIndex + ?Sized),
+        id: &PostId,
+    ) -> ApplicationResult<Post> {
+        let post = posts.restore_from_trash(id).await?;
+        if let Err(e) = search_index.save(&post).await {
+            log::warn!("failed to create search index: {e}");
+        }
+        Ok(post)
+    }
+}
+
+pub struct PurgePostUseCase;
+
+impl PurgePostUseCase {
+    /// ゴミ箱に入っているPostを完全に削除します
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        id: &PostId,
+    ) -> ApplicationResult<()> {
+        match posts.get_by_id(id).await? {
+            Some(post) if post.is_deleted() => {
+                posts.remove(id).await?;
+                Ok(())
+            }
+            _ => Err(ApplicationError::PostNotFound),
+        }
+    }
+}
+
+pub struct PurgeExpiredPostsUseCase;
+
+impl PurgeExpiredPostsUseCase {
+    /// `until`より前にゴミ箱に入れられたPostを完全に削除し、削除したPostのIDを返します
+    pub async fn execute(
+        posts: &(impl PostsRepository + ?Sized),
+        post_queries: &(impl PostQueryRepository + ?Sized),
+        until: DateTime<Utc>,
+    ) -> ApplicationResult<Vec<PostId>> {
+        let post_ids = post_queries.get_trashed().await?;
+        if post_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut purged_ids = vec![];
+        for post in posts.get_by_ids(&post_ids).await? {
+            if post.deleted_at.is_some_and(|deleted_at| deleted_at < until) {
+                posts.remove(&post.id).await?;
+                purged_ids.push(post.id);
+            }
+        }
+        Ok(purged_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use assert_matches::assert_matches;
+    use chrono::Duration;
+    use mockall::predicate::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn restore_post() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        mock_posts
+            .expect_restore_from_trash()
+            .with(eq(PostId(629)))
+            .returning(move |id| Ok(Post::new(*id, "title", "body", now, now)));
+        mock_search
+            .expect_save()
+            .withf(|post| post.id == PostId(629))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let post = RestorePostUseCase::execute(&mock_posts, &mock_search, &PostId(629))
+            .await
+            .unwrap();
+
+        assert_eq!(post.id, PostId(629));
+    }
+
+    #[tokio::test]
+    async fn purge_post_not_in_trash() {
+        let mut mock_posts = MockPostsRepository::new();
+        let now = Utc::now();
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(PostId(629)))
+            .returning(move |id| Ok(Some(Post::new(*id, "title", "body", now, now))));
+        mock_posts.expect_remove().never();
+
+        let result = PurgePostUseCase::execute(&mock_posts, &PostId(629)).await;
+
+        assert_matches!(result, Err(ApplicationError::PostNotFound));
+    }
+
+    #[tokio::test]
+    async fn purge_expired_posts() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_queries = MockPostQueryRepository::new();
+        let now = Utc::now();
+        let until = now - Duration::days(30);
+        mock_queries
+            .expect_get_trashed()
+            .returning(|| Ok(vec![PostId(1), PostId(2)]));
+        mock_posts.expect_get_by_ids().returning(move |ids| {
+            Ok(ids
+                .iter()
+                .map(|id| {
+                    let mut post = Post::new(*id, "title", "body", now, now);
+                    // 1は31日前、2は1日前にゴミ箱に入れた
+                    let days = if *id == PostId(1) { 31 } else { 1 };
+                    post.deleted_at = Some(now - Duration::days(days));
+                    post
+                })
+                .collect())
+        });
+        mock_posts
+            .expect_remove()
+            .with(eq(PostId(1)))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let purged_ids = PurgeExpiredPostsUseCase::execute(&mock_posts, &mock_queries, until)
+            .await
+            .unwrap();
+
+        assert_eq!(purged_ids, vec![PostId(1)]);
+    }
+}
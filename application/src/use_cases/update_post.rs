@@ -1,22 +1,216 @@
 use domain::entities::Post;
 
 use crate::{
-    adapters::{PostsRepository, SearchClient},
+    adapters::{BodyAnalyzer, PostsRepository, PostsRepositoryError, SearchIndex},
+    errors::ApplicationError,
     ApplicationResult,
 };
 
 pub struct UpdatePostUseCase;
 
 impl UpdatePostUseCase {
+    /// 本文の統計を数え直してPostを保存します
+    ///
+    /// `post.version`が保存されているバージョンと異なるときは`ApplicationError::PostConflict`を返します
     pub async fn execute(
-        posts: &impl PostsRepository,
-        search_client: &impl SearchClient,
+        posts: &(impl PostsRepository + ?Sized),
+        search_index: &(impl SearchIndex + ?Sized),
+        body_analyzer: &impl BodyAnalyzer,
         post: &Post,
     ) -> ApplicationResult<()> {
-        _ = posts.save(post).await?;
-        if let Err(e) = search_client.save(post).await {
+        let Some(current) = posts.get_by_id(&post.id).await? else {
+            return Err(ApplicationError::PostNotFound);
+        };
+        if current.version != post.version {
+            return Err(ApplicationError::PostConflict(Box::new(current)));
+        }
+        if let Some(slug) = post
+            .slug
+            .as_ref()
+            .filter(|slug| current.slug.as_ref() != Some(slug))
+        {
+            if posts.get_by_slug(slug).await?.is_some() {
+                return Err(ApplicationError::DuplicateSlug);
+            }
+        }
+        let mut post = post.clone();
+        post.stats = Some(body_analyzer.stats(&post.body));
+        if let Err(e) = posts.save(&post).await {
+            // 読み込んでから保存するまでの間に別の保存が割り込んだ
+            return Err(match e.downcast_ref() {
+                Some(PostsRepositoryError::VersionConflict(id)) => {
+                    let current = posts
+                        .get_by_id(id)
+                        .await?
+                        .ok_or(ApplicationError::PostNotFound)?;
+                    ApplicationError::PostConflict(Box::new(current))
+                }
+                Some(PostsRepositoryError::DuplicateSlug) => ApplicationError::DuplicateSlug,
+                None => e.into(),
+            });
+        }
+        if let Err(e) = search_index.save(&post).await {
             log::warn!("failed to update search index: {e}");
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::*;
+    use assert_matches::assert_matches;
+    use chrono::Utc;
+    use domain::entities::{PostId, PostStats, Slug};
+    use mockall::{predicate::*, Sequence};
+
+    #[tokio::test]
+    async fn test_update_post() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let mut post = Post::new(PostId(629), "title", "body", now, now);
+        post.version = 2;
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(PostId(629)))
+            .returning(move |id| {
+                let mut current = Post::new(*id, "title", "old body", now, now);
+                current.version = 2;
+                Ok(Some(current))
+            });
+        let mut mock_analyzer = MockBodyAnalyzer::new();
+        mock_analyzer
+            .expect_stats()
+            .with(eq("body"))
+            .returning(|body| PostStats::new(body.chars().count()));
+        mock_posts
+            .expect_save()
+            .withf(|post| post.body == "body" && post.stats == Some(PostStats::new(4)))
+            .times(1)
+            .returning(|post| Ok(post.clone()));
+        mock_search.expect_save().times(1).returning(|_| Ok(()));
+
+        UpdatePostUseCase::execute(&mock_posts, &mock_search, &mock_analyzer, &post)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_post_conflict() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let post = Post::new(PostId(629), "title", "body", now, now);
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(PostId(629)))
+            .returning(move |id| {
+                // 別のタブで保存されてバージョンが進んでいる
+                let mut current = Post::new(*id, "title", "other body", now, now);
+                current.version = 2;
+                Ok(Some(current))
+            });
+        mock_posts.expect_save().never();
+        mock_search.expect_save().never();
+
+        let result =
+            UpdatePostUseCase::execute(&mock_posts, &mock_search, &MockBodyAnalyzer::new(), &post)
+                .await;
+
+        assert_matches!(result, Err(ApplicationError::PostConflict(current)) if current.body == "other body");
+    }
+
+    #[tokio::test]
+    async fn test_update_post_conflict_while_saving() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let post = Post::new(PostId(629), "title", "body", now, now);
+        // 読み込んだときはバージョンが同じだったが、保存するまでに別の保存が割り込んだ
+        let mut seq = Sequence::new();
+        mock_posts
+            .expect_get_by_id()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |id| Ok(Some(Post::new(*id, "title", "old body", now, now))));
+        mock_posts
+            .expect_save()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|post| Err(PostsRepositoryError::VersionConflict(post.id).into()));
+        mock_posts
+            .expect_get_by_id()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |id| {
+                let mut current = Post::new(*id, "title", "other body", now, now);
+                current.version = 2;
+                Ok(Some(current))
+            });
+        let mut mock_analyzer = MockBodyAnalyzer::new();
+        mock_analyzer
+            .expect_stats()
+            .returning(|body| PostStats::new(body.chars().count()));
+        mock_search.expect_save().never();
+
+        let result =
+            UpdatePostUseCase::execute(&mock_posts, &mock_search, &mock_analyzer, &post).await;
+
+        assert_matches!(result, Err(ApplicationError::PostConflict(current)) if current.body == "other body");
+    }
+
+    #[tokio::test]
+    async fn test_update_post_duplicate_slug_while_saving() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let mut post = Post::new(PostId(629), "title", "body", now, now);
+        post.slug = Some(Slug::new("hello").unwrap());
+        mock_posts
+            .expect_get_by_id()
+            .returning(move |id| Ok(Some(Post::new(*id, "title", "body", now, now))));
+        // 確かめたときはなかったが、保存するまでに同じスラッグで追加された
+        mock_posts.expect_get_by_slug().returning(|_| Ok(None));
+        mock_posts
+            .expect_save()
+            .times(1)
+            .returning(|_| Err(PostsRepositoryError::DuplicateSlug.into()));
+        let mut mock_analyzer = MockBodyAnalyzer::new();
+        mock_analyzer
+            .expect_stats()
+            .returning(|body| PostStats::new(body.chars().count()));
+        mock_search.expect_save().never();
+
+        let result =
+            UpdatePostUseCase::execute(&mock_posts, &mock_search, &mock_analyzer, &post).await;
+
+        assert_matches!(result, Err(ApplicationError::DuplicateSlug));
+    }
+
+    #[tokio::test]
+    async fn test_update_post_duplicate_slug() {
+        let mut mock_posts = MockPostsRepository::new();
+        let mut mock_search = MockSearchIndex::new();
+        let now = Utc::now();
+        let mut post = Post::new(PostId(629), "title", "body", now, now);
+        post.slug = Some(Slug::new("hello").unwrap());
+        mock_posts
+            .expect_get_by_id()
+            .with(eq(PostId(629)))
+            .returning(move |id| Ok(Some(Post::new(*id, "title", "body", now, now))));
+        mock_posts
+            .expect_get_by_slug()
+            .withf(|slug| slug.as_str() == "hello")
+            .returning(move |_| Ok(Some(Post::new(PostId(1), "other", "body", now, now))));
+        mock_posts.expect_save().never();
+        mock_search.expect_save().never();
+
+        let result =
+            UpdatePostUseCase::execute(&mock_posts, &mock_search, &MockBodyAnalyzer::new(), &post)
+                .await;
+
+        assert_matches!(result, Err(ApplicationError::DuplicateSlug));
+    }
+}
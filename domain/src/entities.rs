@@ -1,2 +1,14 @@
+mod media;
+mod page;
 mod post;
+mod revision;
+mod series;
+mod slug;
+mod tag;
+pub use media::*;
+pub use page::*;
 pub use post::*;
+pub use revision::*;
+pub use series::*;
+pub use slug::*;
+pub use tag::*;
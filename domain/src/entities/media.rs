@@ -0,0 +1,156 @@
+use core::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, Default)]
+pub struct MediaId(pub i32);
+
+impl fmt::Display for MediaId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// 画像から作る縮小版
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaVariant {
+    /// メディアライブラリの一覧に使う
+    Thumbnail,
+    /// 本文に表示するのに使う
+    Large,
+}
+
+impl MediaVariant {
+    pub const ALL: [MediaVariant; 2] = [MediaVariant::Thumbnail, MediaVariant::Large];
+
+    /// 長辺の最大ピクセル数
+    pub fn max_size(&self) -> u32 {
+        match self {
+            Self::Thumbnail => 320,
+            Self::Large => 1280,
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::Thumbnail => "thumb",
+            Self::Large => "large",
+        }
+    }
+}
+
+/// アップロードされた画像やファイル
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Media {
+    pub id: MediaId,
+    /// 保存したファイル名。URLのパスに使う
+    pub name: String,
+    /// アップロードされたときのファイル名
+    pub original_name: String,
+    pub content_type: String,
+    /// バイト数
+    pub size: i64,
+    /// 画像なら幅と高さ
+    pub dimensions: Option<(u32, u32)>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Media {
+    pub fn new(id: MediaId, new_media: NewMedia) -> Media {
+        Media {
+            id,
+            name: new_media.name,
+            original_name: new_media.original_name,
+            content_type: new_media.content_type,
+            size: new_media.size,
+            dimensions: new_media.dimensions,
+            created_at: new_media.created_at,
+        }
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.dimensions.is_some()
+    }
+
+    /// 縮小版のファイル名を返します。`photo.jpg`なら`photo.thumb.jpg`のようになります
+    pub fn variant_name(&self, variant: MediaVariant) -> String {
+        match self.name.rsplit_once('.') {
+            Some((stem, ext)) => format!("{stem}.{}.{ext}", variant.suffix()),
+            None => format!("{}.{}", self.name, variant.suffix()),
+        }
+    }
+
+    /// 縮小版の幅と高さを返します。画像でなければ`None`を返します
+    pub fn variant_dimensions(&self, variant: MediaVariant) -> Option<(u32, u32)> {
+        let (width, height) = self.dimensions?;
+        Some(fit_within(width, height, variant.max_size()))
+    }
+}
+
+/// 縦横比を保ったまま、長辺が`max_size`以下になる大きさを返します。拡大はしません
+fn fit_within(width: u32, height: u32, max_size: u32) -> (u32, u32) {
+    if width <= max_size && height <= max_size {
+        return (width, height);
+    }
+    let scale = max_size as f64 / width.max(height) as f64;
+    let width = ((width as f64 * scale).round() as u32).max(1);
+    let height = ((height as f64 * scale).round() as u32).max(1);
+    (width, height)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct NewMedia {
+    pub name: String,
+    pub original_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub dimensions: Option<(u32, u32)>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl NewMedia {
+    /// 保存するファイル名はアップロードされた日時と元のファイルの拡張子から作ります
+    pub fn new(
+        original_name: impl Into<String>,
+        content_type: impl Into<String>,
+        size: i64,
+        dimensions: Option<(u32, u32)>,
+        created_at: DateTime<Utc>,
+    ) -> NewMedia {
+        let original_name = original_name.into();
+        let timestamp = created_at.format("%Y%m%d%H%M%S%3f");
+        // URLに使えない拡張子は捨てる
+        let name = match original_name.rsplit_once('.') {
+            Some((_, ext))
+                if !ext.is_empty()
+                    && ext.len() <= 5
+                    && ext.chars().all(|c| c.is_ascii_alphanumeric()) =>
+            {
+                format!("{timestamp}.{}", ext.to_ascii_lowercase())
+            }
+            _ => timestamp.to_string(),
+        };
+        NewMedia {
+            name,
+            original_name,
+            content_type: content_type.into(),
+            size,
+            dimensions,
+            created_at,
+        }
+    }
+
+    /// 同じ日時にアップロードした`index`番目のファイルとして、名前に番号をつけます。0番目の名前はそのままです
+    pub fn numbered(mut self, index: usize) -> NewMedia {
+        if index > 0 {
+            self.name = match self.name.split_once('.') {
+                Some((stem, ext)) => format!("{stem}-{index}.{ext}"),
+                None => format!("{}-{index}", self.name),
+            };
+        }
+        self
+    }
+}
@@ -0,0 +1,90 @@
+use core::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// 固定ページの名前。URLのパスに使う
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PageName(String);
+
+impl PageName {
+    /// 英小文字・数字・`-`・`_`以外を含むものや空のもの、記事IDと区別できない数字だけのもの、
+    /// `2021-05`や`2021-05-01`のように年月や日付と区別できないものはエラーになります
+    pub fn new(name: impl AsRef<str>) -> Result<PageName> {
+        let name = name.as_ref().trim();
+        if name.is_empty()
+            || name.chars().all(|c| c.is_ascii_digit())
+            || is_date_like(name)
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        {
+            return Err(Error::InvalidPageName);
+        }
+        Ok(PageName(name.to_owned()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// `{4桁}-{2桁}`か`{4桁}-{2桁}-{2桁}`の形かどうかを返します
+fn is_date_like(name: &str) -> bool {
+    let digits =
+        |part: &str, len: usize| part.len() == len && part.chars().all(|c| c.is_ascii_digit());
+    match name.split('-').collect::<Vec<_>>().as_slice() {
+        [year, month] => digits(year, 4) && digits(month, 2),
+        [year, month, day] => digits(year, 4) && digits(month, 2) && digits(day, 2),
+        _ => false,
+    }
+}
+
+impl fmt::Display for PageName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl TryFrom<String> for PageName {
+    type Error = Error;
+
+    fn try_from(name: String) -> Result<PageName> {
+        PageName::new(name)
+    }
+}
+
+impl From<PageName> for String {
+    fn from(name: PageName) -> String {
+        name.0
+    }
+}
+
+/// aboutなど、日記の記事とは別に置く固定ページ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Page {
+    pub name: PageName,
+    pub title: String,
+    pub body: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Page {
+    pub fn new(
+        name: PageName,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        updated_at: DateTime<Utc>,
+    ) -> Page {
+        Page {
+            name,
+            title: title.into(),
+            body: body.into().replace("\r\n", "\n").replace('\r', "\n"),
+            updated_at,
+        }
+    }
+}
@@ -3,6 +3,8 @@ use core::fmt;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::{Slug, Tag};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, Default)]
 pub struct PostId(pub i32);
 
@@ -12,14 +14,86 @@ impl fmt::Display for PostId {
     }
 }
 
+/// 記事の公開状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PostStatus {
+    /// 下書き。管理者以外には表示されない
+    Draft,
+    /// 公開済み
+    #[default]
+    Published,
+}
+
+/// 記事を誰に見せるか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PostVisibility {
+    /// 誰にでも見せ、一覧やフィードにも載せる
+    #[default]
+    Public,
+    /// URLを知っていれば誰でも読めるが、一覧やフィード、検索には載せない
+    Unlisted,
+    /// 管理者にだけ見せる
+    Private,
+}
+
+impl PostVisibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Unlisted => "unlisted",
+            Self::Private => "private",
+        }
+    }
+}
+
+/// 本文の文字数と、読むのにかかる時間の目安
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PostStats {
+    /// 数式やURLを除いた、空白以外の文字数
+    pub char_count: usize,
+    /// 読むのにかかる分数。1分未満でも1分とする
+    pub reading_minutes: usize,
+}
+
+impl PostStats {
+    /// 1分間に読める文字数の目安
+    pub const CHARS_PER_MINUTE: usize = 500;
+
+    pub fn new(char_count: usize) -> PostStats {
+        PostStats {
+            char_count,
+            reading_minutes: char_count.div_ceil(Self::CHARS_PER_MINUTE).max(1),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Post {
     pub id: PostId,
     pub title: String,
     pub body: String,
+    pub status: PostStatus,
+    pub visibility: PostVisibility,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// 公開日時。未来の日時であれば予約投稿として扱う
+    pub published_at: DateTime<Utc>,
+    pub tags: Vec<Tag>,
+    /// URLに使う識別子。なければIDを使う
+    pub slug: Option<Slug>,
+    /// 著者が書いた要約
+    pub summary: Option<String>,
+    /// ゴミ箱に入れられた日時
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// トップページの先頭に固定した日時
+    pub pinned_at: Option<DateTime<Utc>>,
+    /// 保存したときに数えた本文の統計。まだ数えていなければNone
+    pub stats: Option<PostStats>,
+    /// 保存するたびに増えるバージョン。同時に編集されたことを検出するために使う
+    pub version: i32,
 }
 
 impl Post {
@@ -34,10 +108,64 @@ impl Post {
             id,
             title: title.into(),
             body: body.into().replace("\r\n", "\n").replace('\r', "\n"),
+            status: PostStatus::default(),
+            visibility: PostVisibility::default(),
             created_at,
             updated_at,
+            published_at: created_at,
+            tags: vec![],
+            slug: None,
+            summary: None,
+            deleted_at: None,
+            pinned_at: None,
+            stats: None,
+            version: 1,
         }
     }
+
+    pub fn is_draft(&self) -> bool {
+        self.status == PostStatus::Draft
+    }
+
+    /// 一覧やフィードに載せてよい記事かどうかを返します
+    pub fn is_listed(&self) -> bool {
+        self.visibility == PostVisibility::Public
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.visibility == PostVisibility::Private
+    }
+
+    /// ゴミ箱に入っているかどうかを返します
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// 公開日時がまだ来ていない予約投稿かどうかを返します
+    pub fn is_scheduled(&self) -> bool {
+        self.published_at > Utc::now()
+    }
+
+    /// トップページの先頭に固定されているかどうかを返します
+    pub fn is_pinned(&self) -> bool {
+        self.pinned_at.is_some()
+    }
+
+    /// 編集で指定された公開日時を反映し、記事の日時を公開日時にそろえます
+    ///
+    /// `published_at`が`None`のとき、予約投稿か、`status`で公開する下書きはすぐに公開します
+    pub fn set_published_at(&mut self, published_at: Option<DateTime<Utc>>, status: PostStatus) {
+        let published_at = match published_at {
+            Some(published_at) => published_at,
+            None if self.is_scheduled() || (self.is_draft() && status == PostStatus::Published) => {
+                Utc::now()
+            }
+            None => return,
+        };
+        self.created_at = published_at;
+        self.updated_at = published_at;
+        self.published_at = published_at;
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,7 +173,18 @@ impl Post {
 pub struct NewPost {
     pub title: String,
     pub body: String,
+    pub status: PostStatus,
+    pub visibility: PostVisibility,
     pub timestamp: DateTime<Utc>,
+    /// 公開日時。未来の日時であれば予約投稿として扱う
+    pub published_at: DateTime<Utc>,
+    pub tags: Vec<Tag>,
+    /// URLに使う識別子。なければIDを使う
+    pub slug: Option<Slug>,
+    /// 著者が書いた要約
+    pub summary: Option<String>,
+    /// 本文の統計
+    pub stats: Option<PostStats>,
 }
 
 impl NewPost {
@@ -57,7 +196,19 @@ impl NewPost {
         NewPost {
             title: title.into(),
             body: body.into().replace("\r\n", "\n").replace('\r', "\n"),
+            status: PostStatus::default(),
+            visibility: PostVisibility::default(),
             timestamp,
+            published_at: timestamp,
+            tags: vec![],
+            slug: None,
+            summary: None,
+            stats: None,
         }
     }
+
+    /// 公開日時がまだ来ていない予約投稿かどうかを返します
+    pub fn is_scheduled(&self) -> bool {
+        self.published_at > Utc::now()
+    }
 }
@@ -0,0 +1,45 @@
+use core::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::PostId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, Default)]
+pub struct RevisionId(pub i32);
+
+impl fmt::Display for RevisionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// 保存されたときの記事のタイトルと本文
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Revision {
+    pub id: RevisionId,
+    pub post_id: PostId,
+    pub title: String,
+    pub body: String,
+    /// このリビジョンが保存された日時
+    pub created_at: DateTime<Utc>,
+}
+
+impl Revision {
+    pub fn new(
+        id: RevisionId,
+        post_id: PostId,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        created_at: DateTime<Utc>,
+    ) -> Revision {
+        Revision {
+            id,
+            post_id,
+            title: title.into(),
+            body: body.into(),
+            created_at,
+        }
+    }
+}
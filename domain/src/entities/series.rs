@@ -0,0 +1,57 @@
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::PostId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, Default)]
+pub struct SeriesId(pub i32);
+
+impl fmt::Display for SeriesId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// 日付をまたいで続く記事を順番に並べたまとまり
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Series {
+    pub id: SeriesId,
+    pub title: String,
+    /// 読む順に並べた記事のID
+    pub post_ids: Vec<PostId>,
+}
+
+impl Series {
+    pub fn new(id: SeriesId, title: impl Into<String>, post_ids: Vec<PostId>) -> Series {
+        Series {
+            id,
+            title: title.into(),
+            post_ids,
+        }
+    }
+
+    /// 記事を最後に追加します。すでに含まれていれば何もしません
+    pub fn add_post(&mut self, post_id: PostId) {
+        if !self.post_ids.contains(&post_id) {
+            self.post_ids.push(post_id);
+        }
+    }
+
+    pub fn remove_post(&mut self, post_id: PostId) {
+        self.post_ids.retain(|id| *id != post_id);
+    }
+
+    /// 記事を`offset`だけ後ろに移動します。先頭より前や末尾より後ろには移動しません
+    pub fn move_post(&mut self, post_id: PostId, offset: isize) {
+        let Some(from) = self.post_ids.iter().position(|id| *id == post_id) else {
+            return;
+        };
+        let to = from
+            .saturating_add_signed(offset)
+            .min(self.post_ids.len() - 1);
+        let post_id = self.post_ids.remove(from);
+        self.post_ids.insert(to, post_id);
+    }
+}
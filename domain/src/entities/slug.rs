@@ -0,0 +1,49 @@
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// 記事のURLに使う識別子
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Slug(String);
+
+impl Slug {
+    /// 前後の空白は取り除きます。英小文字・数字・`-`・`_`以外を含むものや空のものはエラーになります
+    pub fn new(value: impl AsRef<str>) -> Result<Slug> {
+        let value = value.as_ref().trim();
+        if value.is_empty()
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        {
+            return Err(Error::InvalidSlug);
+        }
+        Ok(Slug(value.to_owned()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Slug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl TryFrom<String> for Slug {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Slug> {
+        Slug::new(value)
+    }
+}
+
+impl From<Slug> for String {
+    fn from(slug: Slug) -> String {
+        slug.0
+    }
+}
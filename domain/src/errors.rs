@@ -2,6 +2,12 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum Error {
+    #[error("Invalid Tag")]
+    InvalidTag,
+    #[error("Invalid Slug")]
+    InvalidSlug,
+    #[error("Invalid PageName")]
+    InvalidPageName,
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -1,4 +1,4 @@
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use domain::entities::*;
 
 #[test]
@@ -22,3 +22,161 @@ fn new_post_replace_linebreaks() {
     );
     assert_eq!(new_post.body, "LINE\nLINE\nLINE\nLINE\n\nLINE\n\nLINE");
 }
+
+#[test]
+fn post_is_published_by_default() {
+    let post = Post::new(PostId(1), "TITLE", "BODY", Utc::now(), Utc::now());
+    assert_eq!(post.status, PostStatus::Published);
+    assert!(!post.is_draft());
+    let new_post = NewPost::new("TITLE", "BODY", Utc::now());
+    assert_eq!(new_post.status, PostStatus::Published);
+}
+
+#[test]
+fn post_is_scheduled_if_published_at_is_in_future() {
+    let now = Utc::now();
+    let mut post = Post::new(PostId(1), "TITLE", "BODY", now, now);
+    assert_eq!(post.published_at, now);
+    assert!(!post.is_scheduled());
+    post.published_at = now + Duration::hours(1);
+    assert!(post.is_scheduled());
+}
+
+#[test]
+fn post_sets_published_at() {
+    let written_at = Utc::now() - Duration::days(1);
+    let scheduled_at = Utc::now() + Duration::hours(1);
+
+    // 指定された公開日時の記事にする
+    let mut post = Post::new(PostId(1), "TITLE", "BODY", written_at, written_at);
+    post.set_published_at(Some(scheduled_at), PostStatus::Published);
+    assert_eq!(post.created_at, scheduled_at);
+    assert_eq!(post.updated_at, scheduled_at);
+    assert!(post.is_scheduled());
+
+    // 空欄なら予約投稿はすぐに公開する
+    post.set_published_at(None, PostStatus::Published);
+    assert!(!post.is_scheduled());
+    assert!(post.created_at < scheduled_at);
+    assert_eq!(post.updated_at, post.published_at);
+
+    // 公開済みの記事の日時は変えない
+    let mut post = Post::new(PostId(1), "TITLE", "BODY", written_at, written_at);
+    post.set_published_at(None, PostStatus::Published);
+    assert_eq!(post.published_at, written_at);
+
+    // 下書きは公開するときだけ公開した日時の記事にする
+    post.status = PostStatus::Draft;
+    post.set_published_at(None, PostStatus::Draft);
+    assert_eq!(post.published_at, written_at);
+    post.set_published_at(None, PostStatus::Published);
+    assert!(post.published_at > written_at);
+}
+
+#[test]
+fn tag_trims_name() {
+    let tag = Tag::new(" rust ").unwrap();
+    assert_eq!(tag.name(), "rust");
+}
+
+#[test]
+fn tag_rejects_invalid_name() {
+    assert!(Tag::new("").is_err());
+    assert!(Tag::new("   ").is_err());
+    assert!(Tag::new("two words").is_err());
+    assert!(Tag::new("a/b").is_err());
+}
+
+#[test]
+fn slug_rejects_invalid_value() {
+    assert_eq!(
+        Slug::new(" my-first_post2 ").unwrap().as_str(),
+        "my-first_post2"
+    );
+    assert!(Slug::new("").is_err());
+    assert!(Slug::new("Upper").is_err());
+    assert!(Slug::new("a/b").is_err());
+    assert!(Slug::new("日本語").is_err());
+}
+
+#[test]
+fn page_name_rejects_invalid_value() {
+    assert_eq!(PageName::new("about").unwrap().as_str(), "about");
+    assert!(PageName::new("").is_err());
+    assert!(PageName::new("629").is_err());
+    assert!(PageName::new("2021-05").is_err());
+    assert!(PageName::new("2021-05-01").is_err());
+    assert_eq!(
+        PageName::new("2021-review").unwrap().as_str(),
+        "2021-review"
+    );
+    assert_eq!(
+        PageName::new("2021-05-01-02").unwrap().as_str(),
+        "2021-05-01-02"
+    );
+    assert!(PageName::new("About").is_err());
+    assert!(PageName::new("a/b").is_err());
+}
+
+#[test]
+fn series_moves_post() {
+    let mut series = Series::new(SeriesId(1), "series", vec![PostId(1), PostId(2), PostId(3)]);
+    series.move_post(PostId(3), -1);
+    assert_eq!(series.post_ids, vec![PostId(1), PostId(3), PostId(2)]);
+    series.move_post(PostId(1), -1);
+    assert_eq!(series.post_ids, vec![PostId(1), PostId(3), PostId(2)]);
+    series.move_post(PostId(1), 5);
+    assert_eq!(series.post_ids, vec![PostId(3), PostId(2), PostId(1)]);
+    series.add_post(PostId(4));
+    series.add_post(PostId(2));
+    series.remove_post(PostId(3));
+    assert_eq!(series.post_ids, vec![PostId(2), PostId(1), PostId(4)]);
+}
+
+#[test]
+fn new_media_is_named_after_timestamp() {
+    let created_at = chrono::DateTime::parse_from_rfc3339("2026-10-18T12:34:56.789Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let new_media = NewMedia::new(
+        "写真.JPG",
+        "image/jpeg",
+        100,
+        Some((4000, 3000)),
+        created_at,
+    );
+    assert_eq!(new_media.name, "20261018123456789.jpg");
+    let new_media = NewMedia::new("memo", "text/plain", 100, None, created_at);
+    assert_eq!(new_media.name, "20261018123456789");
+    // 同時にアップロードしたファイルには番号をつける
+    let new_media = NewMedia::new("a.png", "image/png", 100, None, created_at);
+    assert_eq!(new_media.numbered(0).name, "20261018123456789.png");
+    let new_media = NewMedia::new("a.png", "image/png", 100, None, created_at);
+    assert_eq!(new_media.numbered(2).name, "20261018123456789-2.png");
+    let new_media = NewMedia::new("memo", "text/plain", 100, None, created_at);
+    assert_eq!(new_media.numbered(1).name, "20261018123456789-1");
+
+    let media = Media::new(
+        MediaId(1),
+        NewMedia::new("a.png", "image/png", 100, Some((4000, 3000)), created_at),
+    );
+    assert_eq!(
+        media.variant_name(MediaVariant::Thumbnail),
+        "20261018123456789.thumb.png"
+    );
+    assert_eq!(
+        media.variant_dimensions(MediaVariant::Thumbnail),
+        Some((320, 240))
+    );
+    assert_eq!(
+        media.variant_dimensions(MediaVariant::Large),
+        Some((1280, 960))
+    );
+}
+
+#[test]
+fn post_stats_rounds_up_reading_minutes() {
+    assert_eq!(PostStats::new(0).reading_minutes, 1);
+    assert_eq!(PostStats::new(500).reading_minutes, 1);
+    assert_eq!(PostStats::new(501).reading_minutes, 2);
+}
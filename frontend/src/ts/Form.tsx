@@ -2,16 +2,30 @@ import { useCallback, useRef, useState } from "react";
 import { useSessionStorage } from "./storage";
 
 type Props = {
+    // "page"なら固定ページを編集する
+    kind?: string;
+    // 固定ページの名前
+    name?: string;
     title?: string;
     body?: string;
     id?: string;
+    version?: string;
+    status?: string;
+    visibility?: string;
+    publishedAt?: string;
+    tags?: string;
+    slug?: string;
+    summary?: string;
 };
 
 export function Form(props: Props) {
     const formRef = useRef<HTMLFormElement>(null);
     const deleteFormRef = useRef<HTMLFormElement>(null);
-    const [sessionStorageTitle, setSessionStorageTitle] = useSessionStorage<string | undefined>(`post-title${props.id ?? ""}`, undefined);
-    const [sessionStorageBody, setSessionStorageBody] = useSessionStorage<string | undefined>(`post-body${props.id ?? ""}`, undefined);
+    const statusRef = useRef<HTMLInputElement>(null);
+    const isPage = props.kind === "page";
+    const storageKeySuffix = isPage ? `page${props.name ?? ""}` : (props.id ?? "");
+    const [sessionStorageTitle, setSessionStorageTitle] = useSessionStorage<string | undefined>(`post-title${storageKeySuffix}`, undefined);
+    const [sessionStorageBody, setSessionStorageBody] = useSessionStorage<string | undefined>(`post-body${storageKeySuffix}`, undefined);
     const [title, rawSetTitle] = useState(sessionStorageTitle ?? props.title ?? "");
     const [body, rawSetBody] = useState(sessionStorageBody ?? props.body ?? "");
 
@@ -30,19 +44,78 @@ export function Form(props: Props) {
         [setSessionStorageBody],
     );
 
-    const submit = useCallback(() => {
-        if (formRef.current?.reportValidity()) {
-            formRef.current?.submit();
-            setSessionStorageTitle(undefined);
-            setSessionStorageBody(undefined);
-        }
-    }, [setSessionStorageTitle, setSessionStorageBody]);
+    const submit = useCallback(
+        (status: "published" | "draft") => {
+            if (formRef.current?.reportValidity()) {
+                if (statusRef.current) {
+                    statusRef.current.value = status;
+                }
+                formRef.current?.submit();
+                setSessionStorageTitle(undefined);
+                setSessionStorageBody(undefined);
+            }
+        },
+        [setSessionStorageTitle, setSessionStorageBody],
+    );
     const submitDelete = useCallback(() => {
         deleteFormRef.current?.submit();
         setSessionStorageTitle(undefined);
         setSessionStorageBody(undefined);
     }, [setSessionStorageTitle, setSessionStorageBody]);
 
+    if (isPage) {
+        return (
+            <>
+                <form id="form-page" method="POST" action="/admin/pages/save" ref={formRef}>
+                    <fieldset>
+                        <h4>
+                            <label htmlFor="page-form-name">ページ名（URLのパス。英小文字・数字・-・_）</label>
+                        </h4>
+                        <p>
+                            <input
+                                type="text"
+                                name="name"
+                                id="page-form-name"
+                                defaultValue={props.name}
+                                readOnly={props.name !== undefined}
+                                pattern="[a-z0-9_\-]+"
+                                required
+                            />
+                        </p>
+                        <h4>
+                            <label htmlFor="post-form-title">タイトル</label>
+                        </h4>
+                        <p>
+                            <input type="text" name="title" id="post-form-title" value={title} onChange={(e) => setTitle(e.target.value)} required />
+                            <input type="text" id="dummy-to-prevent-submit" style={{ display: "none" }} />
+                        </p>
+                        <h4>
+                            <label htmlFor="post-form-body">本文</label>
+                        </h4>
+                        <p>
+                            <textarea name="body" id="post-form-body" value={body} onChange={(e) => setBody(e.target.value)} required />
+                        </p>
+                    </fieldset>
+                    <p>
+                        <button type="button" onClick={() => submit("published")}>
+                            保存
+                        </button>
+                    </p>
+                </form>
+                {props.name && (
+                    <form id="form-delete" method="POST" action="/admin/pages/delete" ref={deleteFormRef}>
+                        <p>
+                            <input type="hidden" name="name" value={props.name} />
+                            <button type="button" onClick={submitDelete}>
+                                削除
+                            </button>
+                        </p>
+                    </form>
+                )}
+            </>
+        );
+    }
+
     return (
         <>
             <form id={props.id ? "form-update" : "form-create"} method="POST" action={props.id ? "/admin/update" : "/admin/create"} ref={formRef}>
@@ -60,16 +133,67 @@ export function Form(props: Props) {
                     <p>
                         <textarea name="body" id="post-form-body" value={body} onChange={(e) => setBody(e.target.value)} required />
                     </p>
+                    <h4>
+                        <label htmlFor="post-form-summary">要約（空欄なら本文から抜粋）</label>
+                    </h4>
+                    <p>
+                        <textarea name="summary" id="post-form-summary" className="summary" defaultValue={props.summary} />
+                    </p>
+                    <h4>
+                        <label htmlFor="post-form-tags">タグ（空白区切り）</label>
+                    </h4>
+                    <p>
+                        <input type="text" name="tags" id="post-form-tags" defaultValue={props.tags} />
+                    </p>
+                    <h4>
+                        <label htmlFor="post-form-slug">スラッグ（英小文字・数字・-・_、空欄なら記事ID）</label>
+                    </h4>
+                    <p>
+                        <input type="text" name="slug" id="post-form-slug" defaultValue={props.slug} pattern="[a-z0-9_\-]+" />
+                    </p>
+                    <h4>
+                        <label htmlFor="post-form-visibility">公開範囲</label>
+                    </h4>
+                    <p>
+                        <select name="visibility" id="post-form-visibility" defaultValue={props.visibility ?? "public"}>
+                            <option value="public">公開</option>
+                            <option value="unlisted">限定公開（URLを知っている人だけ）</option>
+                            <option value="private">非公開（自分だけ）</option>
+                        </select>
+                    </p>
+                    {(!props.id || props.status === "draft" || props.publishedAt) && (
+                        <>
+                            <h4>
+                                <label htmlFor="post-form-published-at">公開日時（空欄ならすぐに公開）</label>
+                            </h4>
+                            <p>
+                                <input type="datetime-local" name="published_at" id="post-form-published-at" defaultValue={props.publishedAt} />
+                            </p>
+                        </>
+                    )}
                 </fieldset>
                 <p>
                     {props.id && <input type="hidden" name="id" id="post-form-id" value={props.id} />}
-                    <button type="button" onClick={submit}>
-                        送信
+                    {props.version && <input type="hidden" name="version" value={props.version} />}
+                    <input type="hidden" name="status" defaultValue={props.status ?? "published"} ref={statusRef} />
+                    <button type="button" onClick={() => submit("published")}>
+                        {props.status === "draft" ? "公開" : "送信"}
                     </button>
+                    {(!props.id || props.status === "draft") && (
+                        <button type="button" onClick={() => submit("draft")}>
+                            下書き
+                        </button>
+                    )}
                 </p>
             </form>
             {props.id && (
-                <form id="form-delete" method="POST" action="/admin/delete" ref={deleteFormRef}>
+                <form
+                    id="form-delete"
+                    className={props.status === "draft" ? "draft" : undefined}
+                    method="POST"
+                    action="/admin/delete"
+                    ref={deleteFormRef}
+                >
                     <p>
                         <input type="hidden" name="id" id="post-form-id" value={props.id} />
                         <button type="button" onClick={submitDelete}>
@@ -0,0 +1,46 @@
+import useAxios from "axios-hooks";
+import dayjs from "dayjs";
+
+const API_HOST = import.meta.env.MODE === "production" ? "" : "http://localhost:4000";
+
+type Post = {
+    id: number;
+    title: string;
+    slug: string | null;
+    created_at: string;
+};
+
+// スラッグがあれば日付とスラッグの、なければIDのパス
+function postPath({ id, slug, created_at }: Post) {
+    return slug ? `/${dayjs(created_at).format("YYYY-MM-DD")}/${slug}` : `/${id}`;
+}
+
+export function FromTheArchive() {
+    // 過去の記事から無作為に選ぶ
+    const [
+        {
+            data: { posts } = { posts: [] },
+        },
+    ] = useAxios<{ posts: Post[] }>({
+        url: `${API_HOST}/api/random?count=5`,
+    });
+    if (posts.length === 0) {
+        return null;
+    }
+
+    return (
+        <section className="from-the-archive">
+            <h4>from the archive</h4>
+            <ul>
+                {posts.map((post) => (
+                    <li key={post.id}>
+                        <a href={postPath(post)}>{post.title}</a>
+                    </li>
+                ))}
+            </ul>
+            <a href="/random" rel="nofollow">
+                surprise me
+            </a>
+        </section>
+    );
+}
@@ -1,4 +1,5 @@
 import { Calendar } from "./Calendar";
+import { FromTheArchive } from "./FromTheArchive";
 import { Months } from "./Months";
 
 export function Nav() {
@@ -6,6 +7,7 @@ export function Nav() {
         <div id="side-nav-content">
             <Calendar />
             <Months />
+            <FromTheArchive />
         </div>
     );
 }
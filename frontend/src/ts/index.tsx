@@ -22,7 +22,22 @@ for (const el of logout) {
 
 const diaryForm = document.getElementById("diary-form-slot");
 if (diaryForm) {
-    createRoot(diaryForm).render(<Form id={diaryForm.dataset.id} title={diaryForm.dataset.title} body={diaryForm.dataset.body} />);
+    createRoot(diaryForm).render(
+        <Form
+            kind={diaryForm.dataset.kind}
+            name={diaryForm.dataset.name}
+            id={diaryForm.dataset.id}
+            version={diaryForm.dataset.version}
+            title={diaryForm.dataset.title}
+            body={diaryForm.dataset.body}
+            status={diaryForm.dataset.status}
+            visibility={diaryForm.dataset.visibility}
+            publishedAt={diaryForm.dataset.publishedAt}
+            tags={diaryForm.dataset.tags}
+            slug={diaryForm.dataset.slug}
+            summary={diaryForm.dataset.summary}
+        />,
+    );
 }
 
 const search = document.getElementById("search-button");
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX IF EXISTS posts_is_draft_created_at_idx;
+ALTER TABLE posts DROP COLUMN is_draft;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+
+ALTER TABLE posts ADD COLUMN is_draft BOOLEAN NOT NULL DEFAULT FALSE;
+CREATE INDEX posts_is_draft_created_at_idx ON posts (is_draft, created_at);
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+
+ALTER TABLE posts ADD COLUMN published_at TIMESTAMP WITH TIME ZONE;
+UPDATE posts SET published_at = created_at;
+ALTER TABLE posts ALTER COLUMN published_at SET NOT NULL;
+CREATE INDEX posts_published_at_idx ON posts (published_at);
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+
+CREATE TABLE post_revisions (
+    id SERIAL PRIMARY KEY,
+    post_id INTEGER NOT NULL REFERENCES posts (id) ON DELETE CASCADE,
+    title VARCHAR NOT NULL,
+    body TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX post_revisions_post_id_created_at_idx ON post_revisions (post_id, created_at);
+
+-- 既存の記事は現在の内容を最初のリビジョンとする
+INSERT INTO post_revisions (post_id, title, body, created_at)
+    SELECT id, title, body, updated_at FROM posts;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+
+ALTER TABLE posts ADD COLUMN deleted_at TIMESTAMP WITH TIME ZONE;
+CREATE INDEX posts_deleted_at_idx ON posts (deleted_at);
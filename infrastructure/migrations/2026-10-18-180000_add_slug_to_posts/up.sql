@@ -0,0 +1,3 @@
+-- Your SQL goes here
+
+ALTER TABLE posts ADD COLUMN slug TEXT UNIQUE;
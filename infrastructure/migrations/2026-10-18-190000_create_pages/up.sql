@@ -0,0 +1,15 @@
+-- Your SQL goes here
+
+CREATE TABLE pages (
+    name TEXT PRIMARY KEY,
+    title VARCHAR NOT NULL,
+    body TEXT NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- これまでconfig.tomlに書いていたaboutの本文を移す
+INSERT INTO pages (name, title, body) VALUES (
+    'about',
+    'このサイトのこと',
+    E'物理学・数学・哲学に関心がある（けれどもそれほど勉強しているわけではない）ソフトウェアエンジニアの日記です。このサイト自身はRustで書かれています。\n\n\n“andante”という名前は、「歩くような速さで」とよく訳される演奏記号からとっています。日記とは日々を記すものであり、それがどんな日々であるか考えたとき、「歩くような速さで」という言葉が気に入ったのでこの名前にしています。\n\nあまり多くの人が読んでいないような気持ちで書いています。コメント欄はありませんが、何か連絡したいことがあればTwitterなどを利用してください。'
+);
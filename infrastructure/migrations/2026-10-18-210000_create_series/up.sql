@@ -0,0 +1,14 @@
+-- Your SQL goes here
+
+CREATE TABLE series (
+    id SERIAL PRIMARY KEY,
+    title VARCHAR NOT NULL
+);
+
+-- ひとつの記事は複数のシリーズには入らない
+CREATE TABLE series_posts (
+    series_id INTEGER NOT NULL REFERENCES series (id) ON DELETE CASCADE,
+    post_id INTEGER NOT NULL UNIQUE REFERENCES posts (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (series_id, post_id)
+);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+
+ALTER TABLE posts ADD COLUMN visibility VARCHAR NOT NULL DEFAULT 'public' CHECK (visibility IN ('public', 'unlisted', 'private'));
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE posts DROP COLUMN reading_minutes;
+ALTER TABLE posts DROP COLUMN char_count;
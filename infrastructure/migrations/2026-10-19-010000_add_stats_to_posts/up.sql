@@ -0,0 +1,4 @@
+-- Your SQL goes here
+
+ALTER TABLE posts ADD COLUMN char_count INTEGER;
+ALTER TABLE posts ADD COLUMN reading_minutes INTEGER;
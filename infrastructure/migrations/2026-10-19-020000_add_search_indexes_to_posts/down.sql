@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+
+DROP INDEX posts_body_search_idx;
+DROP INDEX posts_title_search_idx;
+DROP FUNCTION search_pattern(TEXT);
+DROP FUNCTION search_normalize(TEXT);
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+
+-- Elasticsearchのbigram_analyzerと同じく、NFKCで正規化して小文字にし、ひらがなをカタカナにそろえる
+-- normalizeを使うのでデータベースのエンコーディングはUTF8である必要がある
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE FUNCTION search_normalize(value TEXT) RETURNS TEXT AS $$
+    SELECT translate(
+        lower(normalize(value, NFKC)),
+        'ぁあぃいぅうぇえぉおかがきぎくぐけげこごさざしじすずせぜそぞただちぢっつづてでとどなにぬねのはばぱひびぴふぶぷへべぺほぼぽまみむめもゃやゅゆょよらりるれろゎわゐゑをんゔゕゖゝゞ',
+        'ァアィイゥウェエォオカガキギクグケゲコゴサザシジスズセゼソゾタダチヂッツヅテデトドナニヌネノハバパヒビピフブプヘベペホボポマミムメモャヤュユョヨラリルレロヮワヰヱヲンヴヵヶヽヾ'
+    )
+$$ LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE;
+
+-- キーワードを正規化し、LIKEの特殊文字をエスケープした部分一致のパターンにする
+CREATE FUNCTION search_pattern(keyword TEXT) RETURNS TEXT AS $$
+    SELECT '%' || replace(replace(replace(search_normalize(keyword), '\', '\\'), '%', '\%'), '_', '\_') || '%'
+$$ LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE;
+
+CREATE INDEX posts_title_search_idx ON posts USING gin (search_normalize(title) gin_trgm_ops);
+CREATE INDEX posts_body_search_idx ON posts USING gin (search_normalize(body) gin_trgm_ops);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE media;
+DROP TABLE series_posts;
+DROP TABLE series;
+DROP TABLE pages;
+DROP TABLE post_revisions;
+DROP TABLE posts_tags;
+DROP TABLE tags;
+DROP TABLE posts;
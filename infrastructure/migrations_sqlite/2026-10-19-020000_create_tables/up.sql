@@ -0,0 +1,79 @@
+-- Your SQL goes here
+
+-- 日時はDieselがUTCに直して`%F %T%.f%:z`の書式（例: `2024-01-02 03:04:05.678+00:00`）の文字列で保存する。
+-- 小数秒は0、3、6、9桁のいずれかで桁数が変わるが、`.`と数字はどれも`+`より後ろに並ぶので、文字列のまま比べても日時の順になる
+CREATE TABLE posts (
+    id INTEGER PRIMARY KEY,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    is_draft BOOLEAN NOT NULL DEFAULT FALSE,
+    published_at TEXT NOT NULL,
+    deleted_at TEXT,
+    version INTEGER NOT NULL DEFAULT 1,
+    slug TEXT UNIQUE,
+    summary TEXT,
+    visibility TEXT NOT NULL DEFAULT 'public' CHECK (visibility IN ('public', 'unlisted', 'private')),
+    pinned_at TEXT,
+    char_count INTEGER,
+    reading_minutes INTEGER
+);
+
+CREATE INDEX posts_is_draft_created_at_idx ON posts (is_draft, created_at);
+CREATE INDEX posts_published_at_idx ON posts (published_at);
+CREATE INDEX posts_deleted_at_idx ON posts (deleted_at);
+
+CREATE TABLE tags (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE posts_tags (
+    post_id INTEGER NOT NULL REFERENCES posts (id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags (id) ON DELETE CASCADE,
+    PRIMARY KEY (post_id, tag_id)
+);
+
+CREATE INDEX posts_tags_tag_id_idx ON posts_tags (tag_id);
+
+CREATE TABLE post_revisions (
+    id INTEGER PRIMARY KEY,
+    post_id INTEGER NOT NULL REFERENCES posts (id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX post_revisions_post_id_created_at_idx ON post_revisions (post_id, created_at);
+
+CREATE TABLE pages (
+    name TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE series (
+    id INTEGER PRIMARY KEY,
+    title TEXT NOT NULL
+);
+
+-- ひとつの記事は複数のシリーズには入らない
+CREATE TABLE series_posts (
+    series_id INTEGER NOT NULL REFERENCES series (id) ON DELETE CASCADE,
+    post_id INTEGER NOT NULL UNIQUE REFERENCES posts (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (series_id, post_id)
+);
+
+CREATE TABLE media (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    original_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size BIGINT NOT NULL,
+    width INTEGER,
+    height INTEGER,
+    created_at TEXT NOT NULL
+);
@@ -6,6 +6,20 @@ use std::env;
 fn main() -> Result<()> {
     env_logger::init();
     dotenv().ok();
-    let pg_url = url::Url::parse(&env::var("DATABASE_URL").context("DATABASE_URL was not found")?)?;
+    let database_url = env::var("DATABASE_URL").context("DATABASE_URL was not found")?;
+    if let Some(path) = database_url.strip_prefix("sqlite://") {
+        return migrate_sqlite(path);
+    }
+    let pg_url = url::Url::parse(&database_url)?;
     migrate(&pg_url)
 }
+
+#[cfg(feature = "sqlite")]
+fn migrate_sqlite(path: &str) -> Result<()> {
+    infrastructure::sqlite::migrate(path)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn migrate_sqlite(_path: &str) -> Result<()> {
+    anyhow::bail!("SQLite is not supported. Build with the `sqlite` feature.")
+}
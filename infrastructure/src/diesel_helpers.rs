@@ -1,13 +1,14 @@
+use anyhow::Context as _;
+use application::adapters::{MediaRepositoryError, PostsRepositoryError, SeriesRepositoryError};
 use chrono::FixedOffset;
 use diesel::{
-    backend::Backend,
-    expression::{is_aggregate, AppearsOnTable, AsExpression, SelectableExpression, ValidGrouping},
-    query_builder::{AstPass, QueryFragment},
+    r2d2::{ConnectionManager, R2D2Connection},
+    result::{DatabaseErrorKind, Error as DieselError},
     sql_query,
     sql_types::*,
-    Connection, Expression, PgConnection, QueryResult, RunQueryDsl,
+    PgConnection, RunQueryDsl,
 };
-use r2d2::CustomizeConnection;
+use r2d2::{CustomizeConnection, Pool, PooledConnection};
 
 /// 各コネクションのタイムゾーンを設定するためのCustomizer
 #[derive(Debug, Clone)]
@@ -27,57 +28,111 @@ impl CustomizeConnection<PgConnection, diesel::r2d2::Error> for TimezoneCustomiz
     }
 }
 
-#[derive(Debug, Clone, Copy, QueryId)]
+/// プールからコネクションを取り出します
+pub(crate) fn get_conn<C: R2D2Connection + 'static>(
+    pool: &Pool<ConnectionManager<C>>,
+) -> anyhow::Result<PooledConnection<ConnectionManager<C>>> {
+    pool.get().context("Failed to get connection")
+}
+
+/// `table`の`column`の一意制約に違反したエラーなら`error`に置き換えます
+///
+/// PostgreSQLは制約名を返しますが、SQLiteはメッセージに列名を含めるだけなので両方を見ます
+fn map_unique_violation(
+    e: DieselError,
+    table: &str,
+    column: &str,
+    error: impl Into<anyhow::Error>,
+) -> anyhow::Error {
+    match &e {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+            if info.constraint_name() == Some(format!("{table}_{column}_key").as_str())
+                || info.message().ends_with(&format!("{table}.{column}")) =>
+        {
+            error.into()
+        }
+        _ => e.into(),
+    }
+}
+
+/// `posts.slug`の一意制約に違反したエラーを`PostsRepositoryError::DuplicateSlug`に置き換えます
+pub(crate) fn map_duplicate_slug(e: DieselError) -> anyhow::Error {
+    map_unique_violation(e, "posts", "slug", PostsRepositoryError::DuplicateSlug)
+}
+
+/// `media.name`の一意制約に違反したエラーを`MediaRepositoryError::DuplicateName`に置き換えます
+pub(crate) fn map_duplicate_media_name(e: DieselError) -> anyhow::Error {
+    map_unique_violation(e, "media", "name", MediaRepositoryError::DuplicateName)
+}
+
+/// `series_posts.post_id`の一意制約に違反したエラーを`SeriesRepositoryError::PostAlreadyInSeries`に置き換えます
+pub(crate) fn map_post_already_in_series(e: DieselError) -> anyhow::Error {
+    map_unique_violation(
+        e,
+        "series_posts",
+        "post_id",
+        SeriesRepositoryError::PostAlreadyInSeries,
+    )
+}
+
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum DatePart {
     Year,
     Month,
     Day,
 }
 
-impl<DB: Backend> QueryFragment<DB> for DatePart {
-    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
-        use DatePart::*;
+impl DatePart {
+    /// PostgreSQLの`EXTRACT`で取り出すフィールド
+    fn field(self) -> &'static str {
         match self {
-            Year => out.push_sql("YEAR"),
-            Month => out.push_sql("MONTH"),
-            Day => out.push_sql("DAY"),
+            DatePart::Year => "YEAR",
+            DatePart::Month => "MONTH",
+            DatePart::Day => "DAY",
         }
-        Ok(())
     }
-}
 
-pub(crate) fn extract<TS: AsExpression<Timestamptz>>(
-    part: DatePart,
-    from: TS,
-) -> Extracted<TS::Expression> {
-    Extracted {
-        timestamp: from.as_expression(),
-        part,
+    /// SQLiteの`strftime`での書式
+    #[cfg(feature = "sqlite")]
+    fn strftime_format(self) -> &'static str {
+        match self {
+            DatePart::Year => "%Y",
+            DatePart::Month => "%m",
+            DatePart::Day => "%d",
+        }
     }
 }
-#[derive(Debug, Clone, Copy, QueryId)]
-pub(crate) struct Extracted<TS> {
-    timestamp: TS,
-    part: DatePart,
+
+/// PostgreSQLで`column`の年、月、日のいずれかをセッションのタイムゾーンで取り出します
+pub(crate) fn extract(part: DatePart, column: &str) -> diesel::expression::SqlLiteral<Integer> {
+    diesel::dsl::sql(&format!(
+        "CAST(EXTRACT({} FROM {}) AS INTEGER)",
+        part.field(),
+        column
+    ))
 }
 
-impl<TS> Expression for Extracted<TS> {
-    type SqlType = Integer;
+/// SQLiteで`column`の年、月、日のいずれかをローカルのタイムゾーンで取り出します
+///
+/// SQLiteには`EXTRACT`がないので、`extract`の代わりに使います
+#[cfg(feature = "sqlite")]
+pub(crate) fn local_date_part(
+    part: DatePart,
+    column: &str,
+) -> diesel::expression::SqlLiteral<Integer> {
+    diesel::dsl::sql(&format!(
+        "CAST(strftime('{}', {}, 'localtime') AS INTEGER)",
+        part.strftime_format(),
+        column
+    ))
 }
 
-impl<DB: Backend, TS: QueryFragment<DB>> QueryFragment<DB> for Extracted<TS> {
-    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
-        out.push_sql("CAST(EXTRACT(");
-        QueryFragment::walk_ast(&self.part, out.reborrow())?;
-        out.push_sql(" FROM ");
-        QueryFragment::walk_ast(&self.timestamp, out.reborrow())?;
-        out.push_sql(") AS INTEGER)");
-        Ok(())
-    }
+sql_function! {
+    /// 検索のためにNFKCで正規化し、小文字とカタカナにそろえます。マイグレーションで定義しています
+    fn search_normalize(value: Text) -> Text;
 }
-impl<TS, C: Connection> RunQueryDsl<C> for Extracted<TS> {}
-impl<QS, TS: SelectableExpression<QS>> SelectableExpression<QS> for Extracted<TS> {}
-impl<QS, TS: AppearsOnTable<QS>> AppearsOnTable<QS> for Extracted<TS> {}
-impl<TS: ValidGrouping<()>> ValidGrouping<()> for Extracted<TS> {
-    type IsAggregate = is_aggregate::No;
+
+sql_function! {
+    /// `keyword`を正規化して、`LIKE`で部分一致を探すためのパターンにします。マイグレーションで定義しています
+    fn search_pattern(keyword: Text) -> Text;
 }
@@ -0,0 +1,1056 @@
+//! PostgreSQLとSQLiteで共通のリポジトリの実装
+//!
+//! 2つのスキーマは日時の型が異なり、Dieselのテーブルとしては別の型になるので、
+//! ジェネリクスではなくマクロでそれぞれのバックエンド向けに同じ実装を展開します
+
+/// `$repository`に`PostQueryRepository`を実装します
+///
+/// `$repository`には`conn_pool`と、`created_at`をローカルのタイムゾーンで扱う
+/// `date_part(DatePart) -> SqlLiteral<Integer>`と`local_date() -> SqlLiteral<Date>`が必要です。
+/// バックエンドごとに異なるのはこの2つだけです
+macro_rules! impl_post_query_repository {
+    ($repository:ty, $backend:ty, $schema:path) => {
+        const _: () = {
+            use anyhow::Context as _;
+            use application::adapters::PostQueryRepository;
+            use application::models::{
+                Audience, Cursor, MonthDay, MonthlyStats, PostHeadline, SearchResult, TagCount,
+                YearMonth, YearMonthCount,
+            };
+            use chrono::{DateTime, Local, NaiveDate, TimeZone as _, Utc};
+            use diesel::{prelude::*, sql_types::Integer};
+            use domain::entities::{PostId, PostVisibility, Slug, Tag};
+            use schema::{posts, posts_tags, tags};
+            use $crate::diesel_helpers::{get_conn, DatePart};
+            use $crate::models::Post as PostModel;
+            use $schema as schema;
+
+            impl $repository {
+                /// `audience`に見せてよい記事だけに絞り込んだクエリを返します
+                fn visible_posts<'a>(audience: Audience) -> posts::BoxedQuery<'a, $backend> {
+                    use schema::posts::dsl::{
+                        deleted_at, is_draft, posts, published_at, visibility,
+                    };
+                    // ゴミ箱に入っている記事は誰にも見せない
+                    let query = posts.filter(deleted_at.is_null()).into_boxed();
+                    match audience {
+                        // 下書きと公開日時を迎えていない予約投稿、一覧に載せない記事は見せない
+                        Audience::Public => query
+                            .filter(is_draft.eq(false))
+                            .filter(published_at.le(Utc::now()))
+                            .filter(visibility.eq(PostVisibility::Public.as_str())),
+                        Audience::Admin => query,
+                    }
+                }
+
+                /// `tag`のついたPostのIDを返すサブクエリ
+                fn tagged_post_ids(tag: &Tag) -> posts_tags::BoxedQuery<'_, $backend, Integer> {
+                    let tag_ids = tags::table
+                        .filter(tags::name.eq(tag.name()))
+                        .select(tags::id);
+                    posts_tags::table
+                        .filter(posts_tags::tag_id.eq_any(tag_ids))
+                        .select(posts_tags::post_id)
+                        .into_boxed()
+                }
+
+                /// `audience`に見せてよい記事のうち、`tags`がすべてついた記事に絞り込んだクエリを返します
+                fn tagged_posts<'a>(
+                    tags: &'a [Tag],
+                    audience: Audience,
+                ) -> posts::BoxedQuery<'a, $backend> {
+                    use schema::posts::dsl::id;
+                    let mut query = Self::visible_posts(audience);
+                    for tag in tags {
+                        query = query.filter(id.eq_any(Self::tagged_post_ids(tag)));
+                    }
+                    query
+                }
+
+                /// `query`の記事を新しい順に並べた`SearchResult`を返します
+                fn search_result<'a>(
+                    &self,
+                    query: impl Fn() -> posts::BoxedQuery<'a, $backend>,
+                    offset: usize,
+                    limit: usize,
+                ) -> anyhow::Result<SearchResult> {
+                    use schema::posts::dsl::{created_at, id};
+                    let post_ids = query()
+                        .order_by((created_at.desc(), id.desc()))
+                        .offset(offset as i64)
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    let total_count = query()
+                        .count()
+                        .get_result::<i64>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get total count")?
+                        as usize;
+                    Ok(SearchResult {
+                        post_ids,
+                        total_count,
+                    })
+                }
+            }
+
+            #[async_trait::async_trait]
+            impl PostQueryRepository for $repository {
+                async fn find_by_ids_and_tags<'a>(
+                    &self,
+                    ids: Option<&'a [PostId]>,
+                    tags: &'a [Tag],
+                    offset: usize,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<SearchResult> {
+                    use schema::posts::dsl::id;
+                    let ids =
+                        ids.map(|ids| ids.iter().map(|post_id| post_id.0).collect::<Vec<_>>());
+                    self.search_result(
+                        || {
+                            let query = Self::tagged_posts(tags, audience);
+                            match &ids {
+                                Some(ids) => query.filter(id.eq_any(ids.clone())),
+                                None => query,
+                            }
+                        },
+                        offset,
+                        limit,
+                    )
+                }
+
+                async fn find_by_year_month(
+                    &self,
+                    year_month: &YearMonth,
+                    offset: usize,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<SearchResult> {
+                    use schema::posts::dsl::{created_at, id};
+                    let (next_year, next_month) = if year_month.month == 12 {
+                        (year_month.year + 1, 1)
+                    } else {
+                        (year_month.year, year_month.month + 1)
+                    };
+                    let created_after = Local
+                        .with_ymd_and_hms(
+                            year_month.year as i32,
+                            year_month.month as u32,
+                            1,
+                            0,
+                            0,
+                            0,
+                        )
+                        .unwrap();
+                    let created_before = Local
+                        .with_ymd_and_hms(next_year as i32, next_month as u32, 1, 0, 0, 0)
+                        .unwrap();
+                    let post_ids = Self::visible_posts(audience)
+                        .filter(created_at.ge(created_after))
+                        .filter(created_at.lt(created_before))
+                        .order_by(created_at.asc())
+                        .offset(offset as i64)
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    let total_count = Self::visible_posts(audience)
+                        .filter(created_at.ge(created_after))
+                        .filter(created_at.lt(created_before))
+                        .count()
+                        .get_result::<i64>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get total count")?
+                        as usize;
+                    Ok(SearchResult {
+                        post_ids,
+                        total_count,
+                    })
+                }
+
+                async fn find_by_date(
+                    &self,
+                    date: &NaiveDate,
+                    offset: usize,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<SearchResult> {
+                    use schema::posts::dsl::{created_at, id};
+                    let created_after = Local
+                        .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+                        .unwrap();
+                    let created_before = Local
+                        .from_local_datetime(
+                            &date.succ_opt().unwrap().and_hms_opt(0, 0, 0).unwrap(),
+                        )
+                        .unwrap();
+
+                    let post_ids = Self::visible_posts(audience)
+                        .filter(created_at.ge(created_after))
+                        .filter(created_at.lt(created_before))
+                        .order_by(created_at.asc())
+                        .offset(offset as i64)
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    let total_count = Self::visible_posts(audience)
+                        .filter(created_at.ge(created_after))
+                        .filter(created_at.lt(created_before))
+                        .count()
+                        .get_result::<i64>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get total count")?
+                        as usize;
+                    Ok(SearchResult {
+                        post_ids,
+                        total_count,
+                    })
+                }
+
+                async fn find_by_month_day(
+                    &self,
+                    MonthDay { month, day, .. }: &MonthDay,
+                    offset: usize,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<SearchResult> {
+                    use schema::posts::dsl::{created_at, id};
+                    // ローカルのタイムゾーンでの月日で比べる
+                    let post_ids = Self::visible_posts(audience)
+                        .filter(Self::date_part(DatePart::Month).eq(*month as i32))
+                        .filter(Self::date_part(DatePart::Day).eq(*day as i32))
+                        .order_by(created_at.desc())
+                        .offset(offset as i64)
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    let total_count = Self::visible_posts(audience)
+                        .filter(Self::date_part(DatePart::Month).eq(*month as i32))
+                        .filter(Self::date_part(DatePart::Day).eq(*day as i32))
+                        .count()
+                        .get_result::<i64>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get total count")?
+                        as usize;
+                    Ok(SearchResult {
+                        post_ids,
+                        total_count,
+                    })
+                }
+
+                async fn find_by_tag(
+                    &self,
+                    tag: &Tag,
+                    offset: usize,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<SearchResult> {
+                    use schema::posts::dsl::{created_at, id};
+                    let post_ids = Self::visible_posts(audience)
+                        .filter(id.eq_any(Self::tagged_post_ids(tag)))
+                        .order_by(created_at.desc())
+                        .offset(offset as i64)
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    let total_count = Self::visible_posts(audience)
+                        .filter(id.eq_any(Self::tagged_post_ids(tag)))
+                        .count()
+                        .get_result::<i64>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get total count")?
+                        as usize;
+                    Ok(SearchResult {
+                        post_ids,
+                        total_count,
+                    })
+                }
+
+                async fn get_year_months(
+                    &self,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<YearMonthCount>> {
+                    use diesel::dsl::count_star;
+                    use schema::posts::dsl::{id, posts};
+                    let year = Self::date_part(DatePart::Year);
+                    let month = Self::date_part(DatePart::Month);
+                    let results = posts
+                        .filter(id.eq_any(Self::visible_posts(audience).select(id)))
+                        .group_by((year.clone(), month.clone()))
+                        .order_by((year.clone().asc(), month.clone().asc()))
+                        .select((year, month, count_star()))
+                        .get_results::<(i32, i32, i64)>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get results")?;
+                    results
+                        .into_iter()
+                        .map(|(year, month, count)| {
+                            Ok(YearMonthCount {
+                                year_month: YearMonth::new(year as u16, month as u8)?,
+                                count: count as usize,
+                            })
+                        })
+                        .collect()
+                }
+
+                async fn get_headlines(
+                    &self,
+                    year: Option<u16>,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<PostHeadline>> {
+                    use schema::posts::dsl::{created_at, id, slug, title};
+                    let mut query = Self::visible_posts(audience);
+                    if let Some(year) = year {
+                        // `get_year_months`と同じく、ローカルのタイムゾーンでの年で比べる
+                        query = query.filter(Self::date_part(DatePart::Year).eq(year as i32));
+                    }
+                    // 本文は読み込まない
+                    let results = query
+                        .order_by(created_at.desc())
+                        .select((id, title, slug, created_at))
+                        .get_results::<(i32, String, Option<String>, DateTime<Utc>)>(&mut get_conn(
+                            &self.conn_pool,
+                        )?)
+                        .context("Failed to get headlines")?;
+                    Ok(results
+                        .into_iter()
+                        .map(
+                            |(post_id, post_title, post_slug, post_created_at)| PostHeadline {
+                                id: PostId(post_id),
+                                title: post_title,
+                                slug: post_slug.and_then(|s| Slug::new(s).ok()),
+                                created_at: post_created_at,
+                            },
+                        )
+                        .collect())
+                }
+
+                async fn get_days_in_year_month(
+                    &self,
+                    YearMonth { year, month, .. }: &YearMonth,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<u8>> {
+                    use schema::posts::dsl::created_at;
+                    let (next_year, next_month) = if *month == 12 {
+                        (*year + 1, 1)
+                    } else {
+                        (*year, *month + 1)
+                    };
+                    let created_after = Local
+                        .with_ymd_and_hms((*year) as i32, (*month) as u32, 1, 0, 0, 0)
+                        .unwrap();
+                    let created_before = Local
+                        .with_ymd_and_hms(next_year as i32, next_month as u32, 1, 0, 0, 0)
+                        .unwrap();
+                    let mut results = Self::visible_posts(audience)
+                        .filter(created_at.ge(created_after))
+                        .filter(created_at.lt(created_before))
+                        .select(Self::date_part(DatePart::Day))
+                        .distinct()
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get results")?
+                        .into_iter()
+                        .map(|d| d as u8)
+                        .collect::<Vec<_>>();
+                    results.sort();
+                    Ok(results)
+                }
+
+                async fn get_latest_posts(
+                    &self,
+                    offset: usize,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<SearchResult> {
+                    use schema::posts::dsl::{created_at, id};
+                    let post_ids = Self::visible_posts(audience)
+                        .order_by(created_at.desc())
+                        .offset(offset as i64)
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    let total_count = Self::visible_posts(audience)
+                        .count()
+                        .get_result::<i64>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get total count")?
+                        as usize;
+                    Ok(SearchResult {
+                        post_ids,
+                        total_count,
+                    })
+                }
+
+                async fn get_before_cursor<'a>(
+                    &self,
+                    cursor: Option<&'a Cursor>,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{created_at, id};
+                    let mut query = Self::visible_posts(audience);
+                    if let Some(cursor) = cursor {
+                        query = query.filter(
+                            created_at
+                                .lt(cursor.created_at)
+                                .or(created_at.eq(cursor.created_at).and(id.lt(cursor.id.0))),
+                        );
+                    }
+                    let results = query
+                        .order_by((created_at.desc(), id.desc()))
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_after_cursor(
+                    &self,
+                    cursor: &Cursor,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{created_at, id};
+                    let results = Self::visible_posts(audience)
+                        .filter(
+                            created_at
+                                .gt(cursor.created_at)
+                                .or(created_at.eq(cursor.created_at).and(id.gt(cursor.id.0))),
+                        )
+                        .order_by((created_at.asc(), id.asc()))
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_random(
+                    &self,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<PostId>> {
+                    use diesel::dsl::sql;
+                    use diesel::sql_types::Double;
+                    use schema::posts::dsl::id;
+                    // IDをすべて読み込まずにデータベースの中で選ぶ
+                    let results = Self::visible_posts(audience)
+                        .order_by(sql::<Double>("random()"))
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get random posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_pinned(&self, audience: Audience) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{id, pinned_at};
+                    let results = Self::visible_posts(audience)
+                        .filter(pinned_at.is_not_null())
+                        .order_by(pinned_at.desc())
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get pinned posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_monthly_stats(
+                    &self,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<MonthlyStats>> {
+                    use diesel::dsl::count_star;
+                    use schema::posts::dsl::{char_count, id, posts, reading_minutes};
+                    // ローカルのタイムゾーンでの年月ごとにまとめる
+                    let year = Self::date_part(DatePart::Year);
+                    let month = Self::date_part(DatePart::Month);
+                    let results = posts
+                        .filter(id.eq_any(Self::visible_posts(audience).select(id)))
+                        .group_by((year.clone(), month.clone()))
+                        .order_by((year.clone().asc(), month.clone().asc()))
+                        .select((
+                            year,
+                            month,
+                            count_star(),
+                            diesel::dsl::sum(char_count),
+                            diesel::dsl::sum(reading_minutes),
+                        ))
+                        .get_results::<(i32, i32, i64, Option<i64>, Option<i64>)>(&mut get_conn(
+                            &self.conn_pool,
+                        )?)
+                        .context("Failed to get monthly stats")?;
+                    results
+                        .into_iter()
+                        .map(|(year, month, post_count, chars, minutes)| {
+                            Ok(MonthlyStats {
+                                year_month: YearMonth::new(year as u16, month as u8)?,
+                                post_count: post_count as usize,
+                                char_count: chars.unwrap_or_default() as usize,
+                                reading_minutes: minutes.unwrap_or_default() as usize,
+                            })
+                        })
+                        .collect()
+                }
+
+                async fn get_dates(&self, audience: Audience) -> anyhow::Result<Vec<NaiveDate>> {
+                    use schema::posts::dsl::{id, posts};
+                    // `get_days_in_year_month`と同じく、ローカルのタイムゾーンでの日付にする
+                    let date = Self::local_date();
+                    let results = posts
+                        .filter(id.eq_any(Self::visible_posts(audience).select(id)))
+                        .select(date.clone())
+                        .distinct()
+                        .order_by(date.asc())
+                        .get_results::<NaiveDate>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get dates")?;
+                    Ok(results)
+                }
+
+                async fn get_longest(
+                    &self,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{char_count, id};
+                    let results = Self::visible_posts(audience)
+                        .filter(char_count.is_not_null())
+                        .order_by((char_count.desc(), id.desc()))
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get longest posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_shortest(
+                    &self,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{char_count, id};
+                    let results = Self::visible_posts(audience)
+                        .filter(char_count.is_not_null())
+                        .order_by((char_count.asc(), id.desc()))
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get shortest posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_most_revised(
+                    &self,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<(PostId, usize)>> {
+                    use diesel::dsl::count_star;
+                    use schema::post_revisions::dsl::{post_id, post_revisions};
+                    let results = post_revisions
+                        .filter(post_id.eq_any(Self::visible_posts(audience).select(posts::id)))
+                        .group_by(post_id)
+                        .order_by((count_star().desc(), post_id.desc()))
+                        .limit(limit as i64)
+                        .select((post_id, count_star()))
+                        .get_results::<(i32, i64)>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get most revised posts")?
+                        .into_iter()
+                        .map(|(id, count)| (PostId(id), count as usize))
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_without_stats(&self) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{char_count, id, posts};
+                    let results = posts
+                        .filter(char_count.is_null())
+                        .order_by(id.asc())
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get posts without stats")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_tags(&self, audience: Audience) -> anyhow::Result<Vec<TagCount>> {
+                    use diesel::dsl::count;
+                    let results = posts_tags::table
+                        .inner_join(tags::table)
+                        .filter(
+                            posts_tags::post_id
+                                .eq_any(Self::visible_posts(audience).select(posts::id)),
+                        )
+                        .group_by(tags::name)
+                        .order_by(tags::name.asc())
+                        .select((tags::name, count(posts_tags::post_id)))
+                        .get_results::<(String, i64)>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get tags")?;
+                    results
+                        .into_iter()
+                        .map(|(name, count)| {
+                            Ok(TagCount {
+                                tag: Tag::new(name)?,
+                                count: count as usize,
+                            })
+                        })
+                        .collect()
+                }
+
+                async fn get_last_updated(&self) -> anyhow::Result<Option<DateTime<Utc>>> {
+                    use schema::posts::dsl::updated_at;
+                    let post = Self::visible_posts(Audience::Public)
+                        .order_by(updated_at.desc())
+                        .first::<PostModel>(&mut get_conn(&self.conn_pool)?)
+                        .optional()
+                        .context("failed to get result")?;
+                    Ok(post.map(|p| p.updated_at))
+                }
+
+                async fn get_drafts(&self) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{id, is_draft, updated_at};
+                    let results = Self::visible_posts(Audience::Admin)
+                        .filter(is_draft.eq(true))
+                        .order_by(updated_at.desc())
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get drafts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_trashed(&self) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{deleted_at, id, posts};
+                    let results = posts
+                        .filter(deleted_at.is_not_null())
+                        .order_by(deleted_at.desc())
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get trashed posts")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_published_between(
+                    &self,
+                    from: DateTime<Utc>,
+                    until: DateTime<Utc>,
+                ) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{id, is_draft, published_at};
+                    let results = Self::visible_posts(Audience::Admin)
+                        .filter(is_draft.eq(false))
+                        .filter(published_at.gt(from))
+                        .filter(published_at.le(until))
+                        .order_by(published_at.asc())
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get results")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_from_date(
+                    &self,
+                    from: DateTime<Utc>,
+                    offset: usize,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{created_at, id};
+                    let results = Self::visible_posts(audience)
+                        .filter(created_at.ge(from))
+                        .order_by(created_at.asc())
+                        .offset(offset as i64)
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get results")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+
+                async fn get_until_date(
+                    &self,
+                    until: DateTime<Utc>,
+                    offset: usize,
+                    limit: usize,
+                    audience: Audience,
+                ) -> anyhow::Result<Vec<PostId>> {
+                    use schema::posts::dsl::{created_at, id};
+                    let results = Self::visible_posts(audience)
+                        .filter(created_at.lt(until))
+                        .order_by(created_at.desc())
+                        .offset(offset as i64)
+                        .limit(limit as i64)
+                        .select(id)
+                        .get_results::<i32>(&mut get_conn(&self.conn_pool)?)
+                        .context("failed to get results")?
+                        .into_iter()
+                        .map(PostId)
+                        .collect();
+                    Ok(results)
+                }
+            }
+        };
+    };
+}
+pub(crate) use impl_post_query_repository;
+
+/// `$repository`に`PostsRepository`を実装します
+///
+/// `$repository`には`conn_pool`と、Postにタグをつける
+/// `insert_tags(&mut $connection, i32, &[Tag]) -> anyhow::Result<()>`が必要です。
+/// SQLiteでは`ON CONFLICT`つきで複数行をまとめて挿入できないので、これだけはバックエンドごとに実装します
+macro_rules! impl_posts_repository {
+    ($repository:ty, $connection:ty, $schema:path) => {
+        const _: () = {
+            use anyhow::{Context, Result as AnyhowResult};
+            use application::adapters::{PostsRepository, PostsRepositoryError};
+            use chrono::{DateTime, Utc};
+            use diesel::prelude::*;
+            use domain::entities::{
+                NewPost, Post, PostId, PostStats, PostStatus, Revision, RevisionId, Slug, Tag,
+            };
+            use std::collections::HashMap;
+            use $crate::diesel_helpers::{get_conn, map_duplicate_slug};
+            use $crate::models::{Post as PostModel, Revision as RevisionModel};
+            use $schema as schema;
+
+            impl $repository {
+                /// 指定したPostそれぞれについているタグを名前順で返します
+                fn get_tags(
+                    conn: &mut $connection,
+                    post_ids: &[i32],
+                ) -> AnyhowResult<HashMap<i32, Vec<Tag>>> {
+                    use schema::{posts_tags, tags};
+                    let rows = posts_tags::table
+                        .inner_join(tags::table)
+                        .filter(posts_tags::post_id.eq_any(post_ids))
+                        .order_by(tags::name.asc())
+                        .select((posts_tags::post_id, tags::name))
+                        .get_results::<(i32, String)>(conn)
+                        .context("Failed to get tags")?;
+                    let mut tags_map = HashMap::<i32, Vec<Tag>>::new();
+                    for (post_id, name) in rows {
+                        tags_map.entry(post_id).or_default().push(Tag::new(name)?);
+                    }
+                    Ok(tags_map)
+                }
+
+                /// タイトルか本文が最新のリビジョンから変わっていれば、新しいリビジョンとして記録します
+                fn add_revision(conn: &mut $connection, post: &PostModel) -> AnyhowResult<()> {
+                    use schema::post_revisions::{self, body, created_at, post_id, title};
+                    let latest = post_revisions::table
+                        .filter(post_id.eq(post.id))
+                        .order_by((created_at.desc(), post_revisions::id.desc()))
+                        .select((title, body))
+                        .first::<(String, String)>(conn)
+                        .optional()
+                        .context("Failed to get latest revision")?;
+                    if latest.is_some_and(|(t, b)| t == post.title && b == post.body) {
+                        return Ok(());
+                    }
+                    diesel::insert_into(post_revisions::table)
+                        .values((
+                            post_id.eq(post.id),
+                            title.eq(&post.title),
+                            body.eq(&post.body),
+                            created_at.eq(Utc::now()),
+                        ))
+                        .execute(conn)
+                        .context("Failed to add revision")?;
+                    Ok(())
+                }
+
+                /// Postのタグを`tags`に置き換えます
+                fn replace_tags(
+                    conn: &mut $connection,
+                    post_id: i32,
+                    tags: &[Tag],
+                ) -> AnyhowResult<()> {
+                    use schema::{posts_tags, tags as tags_table};
+                    let names = tags.iter().map(Tag::name).collect::<Vec<_>>();
+                    let kept_tag_ids = tags_table::table
+                        .filter(tags_table::name.eq_any(&names))
+                        .select(tags_table::id);
+                    diesel::delete(
+                        posts_tags::table
+                            .filter(posts_tags::post_id.eq(post_id))
+                            .filter(diesel::dsl::not(posts_tags::tag_id.eq_any(kept_tag_ids))),
+                    )
+                    .execute(conn)
+                    .context("Failed to detach tags")?;
+                    Self::insert_tags(conn, post_id, tags)
+                }
+
+                fn with_tags(conn: &mut $connection, post: PostModel) -> AnyhowResult<Post> {
+                    let mut tags_map = Self::get_tags(conn, &[post.id])?;
+                    let mut post = Post::from(post);
+                    post.tags = tags_map.remove(&post.id.0).unwrap_or_default();
+                    Ok(post)
+                }
+            }
+
+            #[async_trait::async_trait]
+            impl PostsRepository for $repository {
+                async fn get_by_id(&self, id: &PostId) -> anyhow::Result<Option<Post>> {
+                    use schema::posts::dsl::posts;
+                    let mut conn = get_conn(&self.conn_pool)?;
+                    let post = posts
+                        .find(id.0)
+                        .get_result::<PostModel>(&mut conn)
+                        .optional()
+                        .context("Failed to get result")?;
+                    post.map(|post| Self::with_tags(&mut conn, post))
+                        .transpose()
+                }
+
+                async fn get_by_slug(&self, slug: &Slug) -> anyhow::Result<Option<Post>> {
+                    use schema::posts::dsl::{posts, slug as slug_column};
+                    let mut conn = get_conn(&self.conn_pool)?;
+                    let post = posts
+                        .filter(slug_column.eq(slug.as_str()))
+                        .get_result::<PostModel>(&mut conn)
+                        .optional()
+                        .context("Failed to get result")?;
+                    post.map(|post| Self::with_tags(&mut conn, post))
+                        .transpose()
+                }
+
+                async fn get_by_ids(&self, ids: &[PostId]) -> anyhow::Result<Vec<Post>> {
+                    use schema::posts::{dsl::posts, id};
+                    let post_ids = ids.iter().map(|post_id| post_id.0).collect::<Vec<_>>();
+                    let mut conn = get_conn(&self.conn_pool)?;
+                    let mut tags_map = Self::get_tags(&mut conn, &post_ids)?;
+                    let posts_map: HashMap<_, _> = posts
+                        .filter(id.eq_any(&post_ids))
+                        .get_results::<PostModel>(&mut conn)
+                        .context("Failed to get results")?
+                        .into_iter()
+                        .map(|post| {
+                            let post_id = post.id;
+                            let mut post = Post::from(post);
+                            post.tags = tags_map.remove(&post_id).unwrap_or_default();
+                            (post_id, post)
+                        })
+                        .collect();
+
+                    Ok(post_ids
+                        .iter()
+                        .filter_map(|&post_id| posts_map.get(&post_id).cloned())
+                        .collect())
+                }
+
+                async fn add(&self, new_post: NewPost) -> anyhow::Result<Post> {
+                    use schema::posts::{
+                        self, body, char_count, created_at, is_draft, published_at,
+                        reading_minutes, slug, summary, title, updated_at, visibility,
+                    };
+                    let mut tags = new_post.tags;
+                    tags.sort();
+                    tags.dedup();
+                    let post = get_conn(&self.conn_pool)?.transaction(|conn| {
+                        let post = diesel::insert_into(posts::table)
+                            .values((
+                                title.eq(new_post.title),
+                                body.eq(new_post.body),
+                                created_at.eq(new_post.timestamp),
+                                updated_at.eq(new_post.timestamp),
+                                is_draft.eq(new_post.status == PostStatus::Draft),
+                                published_at.eq(new_post.published_at),
+                                slug.eq(new_post.slug.as_ref().map(Slug::as_str)),
+                                summary.eq(&new_post.summary),
+                                visibility.eq(new_post.visibility.as_str()),
+                                char_count.eq(new_post.stats.map(|stats| stats.char_count as i32)),
+                                reading_minutes
+                                    .eq(new_post.stats.map(|stats| stats.reading_minutes as i32)),
+                            ))
+                            .get_result::<PostModel>(conn)
+                            .map_err(map_duplicate_slug)?;
+                        Self::insert_tags(conn, post.id, &tags)?;
+                        Self::add_revision(conn, &post)?;
+                        anyhow::Ok(post)
+                    })?;
+                    let mut post = Post::from(post);
+                    post.tags = tags;
+                    Ok(post)
+                }
+
+                async fn save(&self, post: &Post) -> anyhow::Result<Post> {
+                    use schema::posts::dsl::{
+                        body, char_count, created_at, is_draft, posts, published_at,
+                        reading_minutes, slug, summary, title, updated_at, version, visibility,
+                    };
+                    let tags = &post.tags;
+                    let mut conn = get_conn(&self.conn_pool)?;
+                    let post = conn.transaction(|conn| {
+                        // 読み込んだときからバージョンが変わっていたら保存しない
+                        let post =
+                            diesel::update(posts.find(post.id.0).filter(version.eq(post.version)))
+                                .set((
+                                    title.eq(post.title.clone()),
+                                    body.eq(post.body.clone()),
+                                    is_draft.eq(post.is_draft()),
+                                    created_at.eq(post.created_at),
+                                    updated_at.eq(post.updated_at),
+                                    published_at.eq(post.published_at),
+                                    slug.eq(post.slug.as_ref().map(Slug::as_str)),
+                                    summary.eq(&post.summary),
+                                    visibility.eq(post.visibility.as_str()),
+                                    char_count.eq(post.stats.map(|stats| stats.char_count as i32)),
+                                    reading_minutes
+                                        .eq(post.stats.map(|stats| stats.reading_minutes as i32)),
+                                    version.eq(version + 1),
+                                ))
+                                .get_result::<PostModel>(conn)
+                                .optional()
+                                .map_err(map_duplicate_slug)?
+                                .ok_or(PostsRepositoryError::VersionConflict(post.id))?;
+                        Self::replace_tags(conn, post.id, tags)?;
+                        Self::add_revision(conn, &post)?;
+                        anyhow::Ok(post)
+                    })?;
+                    Self::with_tags(&mut conn, post)
+                }
+
+                async fn remove(&self, id: &PostId) -> anyhow::Result<()> {
+                    use schema::posts::dsl::posts;
+                    diesel::delete(posts.find(id.0)).execute(&mut get_conn(&self.conn_pool)?)?;
+                    Ok(())
+                }
+
+                async fn move_to_trash(&self, id: &PostId) -> anyhow::Result<()> {
+                    use schema::posts::dsl::{deleted_at, posts};
+                    diesel::update(posts.find(id.0))
+                        .set(deleted_at.eq(Some(Utc::now())))
+                        .execute(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to move post to trash")?;
+                    Ok(())
+                }
+
+                async fn set_pinned_at(
+                    &self,
+                    id: &PostId,
+                    pinned_at: Option<DateTime<Utc>>,
+                ) -> anyhow::Result<()> {
+                    use schema::posts::dsl::{self, posts};
+                    diesel::update(posts.find(id.0))
+                        .set(dsl::pinned_at.eq(pinned_at))
+                        .execute(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to pin post")?;
+                    Ok(())
+                }
+
+                async fn set_stats(&self, id: &PostId, stats: &PostStats) -> anyhow::Result<()> {
+                    use schema::posts::dsl::{char_count, posts, reading_minutes};
+                    diesel::update(posts.find(id.0))
+                        .set((
+                            char_count.eq(stats.char_count as i32),
+                            reading_minutes.eq(stats.reading_minutes as i32),
+                        ))
+                        .execute(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to save post stats")?;
+                    Ok(())
+                }
+
+                async fn restore_from_trash(&self, id: &PostId) -> anyhow::Result<Post> {
+                    use schema::posts::dsl::{deleted_at, posts};
+                    let mut conn = get_conn(&self.conn_pool)?;
+                    let post = diesel::update(posts.find(id.0))
+                        .set(deleted_at.eq(None::<DateTime<Utc>>))
+                        .get_result::<PostModel>(&mut conn)
+                        .context("Failed to restore post from trash")?;
+                    Self::with_tags(&mut conn, post)
+                }
+
+                async fn get_revisions(&self, id: &PostId) -> anyhow::Result<Vec<Revision>> {
+                    use schema::post_revisions::{self, created_at, post_id};
+                    let revisions = post_revisions::table
+                        .filter(post_id.eq(id.0))
+                        .order_by((created_at.desc(), post_revisions::id.desc()))
+                        .get_results::<RevisionModel>(&mut get_conn(&self.conn_pool)?)
+                        .context("Failed to get revisions")?;
+                    Ok(revisions.into_iter().map(Revision::from).collect())
+                }
+
+                async fn get_revision(&self, id: &RevisionId) -> anyhow::Result<Option<Revision>> {
+                    use schema::post_revisions;
+                    let revision = post_revisions::table
+                        .find(id.0)
+                        .get_result::<RevisionModel>(&mut get_conn(&self.conn_pool)?)
+                        .optional()
+                        .context("Failed to get revision")?;
+                    Ok(revision.map(Revision::from))
+                }
+
+                async fn attach_tags(&self, id: &PostId, tags: &[Tag]) -> anyhow::Result<()> {
+                    get_conn(&self.conn_pool)?
+                        .transaction(|conn| Self::insert_tags(conn, id.0, tags))
+                }
+
+                async fn detach_tags(&self, id: &PostId, tags: &[Tag]) -> anyhow::Result<()> {
+                    use schema::{posts_tags, tags};
+                    if tags.is_empty() {
+                        return Ok(());
+                    }
+                    let names = tags.iter().map(Tag::name).collect::<Vec<_>>();
+                    let tag_ids = tags::table
+                        .filter(tags::name.eq_any(&names))
+                        .select(tags::id);
+                    diesel::delete(
+                        posts_tags::table
+                            .filter(posts_tags::post_id.eq(id.0))
+                            .filter(posts_tags::tag_id.eq_any(tag_ids)),
+                    )
+                    .execute(&mut get_conn(&self.conn_pool)?)
+                    .context("Failed to detach tags")?;
+                    Ok(())
+                }
+            }
+        };
+    };
+}
+pub(crate) use impl_posts_repository;
@@ -0,0 +1,263 @@
+use anyhow::Context as _;
+use domain::entities::{Post, PostId};
+use elasticsearch::{
+    http::{
+        transport::{SingleNodeConnectionPool, TransportBuilder},
+        StatusCode,
+    },
+    indices::{IndicesCreateParts, IndicesGetParts},
+    CreateParts, DeleteParts, Elasticsearch, ExistsParts, SearchParts, UpdateParts,
+};
+use serde_json::{json, Value};
+
+#[derive(Clone)]
+pub struct ElasticsearchIndex {
+    client: Elasticsearch,
+    index_name: String,
+}
+
+impl ElasticsearchIndex {
+    const DEFAULT_INDEX_NAME: &'static str = "andante";
+
+    /// 一度の検索で返す記事の数の上限。Elasticsearchの`index.max_result_window`の初期値です
+    const MAX_RESULT_WINDOW: usize = 10000;
+
+    pub fn new(es_url: &url::Url) -> anyhow::Result<Self> {
+        Self::with_index_name(es_url, Self::DEFAULT_INDEX_NAME)
+    }
+
+    pub fn with_index_name(
+        es_url: &url::Url,
+        index_name: impl Into<String>,
+    ) -> anyhow::Result<Self> {
+        let es_conn_pool = SingleNodeConnectionPool::new(es_url.clone());
+        let transport = TransportBuilder::new(es_conn_pool)
+            .disable_proxy()
+            .build()?;
+        let client = Elasticsearch::new(transport);
+
+        Ok(Self {
+            client,
+            index_name: index_name.into(),
+        })
+    }
+
+    async fn create_index_if_needed(&self) -> anyhow::Result<bool> {
+        let response = self
+            .client
+            .indices()
+            .get(IndicesGetParts::Index(&[&self.index_name]))
+            .send()
+            .await
+            .context("Failed to check index")?;
+        if response.status_code() == StatusCode::OK {
+            return Ok(false);
+        }
+
+        self.client
+            .indices()
+            .create(IndicesCreateParts::Index(&self.index_name))
+            .body(json!({
+                "settings": {
+                    "analysis": {
+                        "char_filter": {
+                            "normalize": {
+                                "type": "icu_normalizer",
+                                "name": "nfkc",
+                                "mode": "compose",
+                            },
+                        },
+                        "tokenizer": {
+                            "bigram": {
+                                "type": "ngram",
+                                "min_gram": 1,
+                                "max_gram": 2,
+                                "token_chars": [
+                                    "letter",
+                                    "digit",
+                                ]
+                            },
+                            "kuromoji": {
+                                "mode": "search",
+                                "type": "kuromoji_tokenizer",
+                                "discard_compound_token": true,
+                            }
+                        },
+                        "filter": {
+                            "kana_filter": {
+                                "type": "icu_transform",
+                                "id": "Hiragana-Katakana",
+                            }
+                        },
+                        "analyzer": {
+                            "kuromoji_analyzer": {
+                                "type": "custom",
+                                "char_filter": ["normalize"],
+                                "tokenizer": "kuromoji",
+                                "filter": [
+                                    "kuromoji_baseform",
+                                    "kuromoji_part_of_speech",
+                                    "cjk_width",
+                                    "ja_stop",
+                                    "kuromoji_stemmer",
+                                    "lowercase",
+                                    "kana_filter",
+                                ],
+                            },
+                            "bigram_analyzer": {
+                                "type": "custom",
+                                "char_filter": ["normalize"],
+                                "tokenizer": "bigram",
+                                "filter": [
+                                    "lowercase",
+                                    "kana_filter",
+                                ],
+                            }
+                        }
+                    },
+                },
+                "mappings": {
+                    "properties": {
+                        "body": {
+                            "type": "text",
+                            "analyzer": "kuromoji_analyzer",
+                            "fields": {
+                                "bigram": {
+                                    "type": "text",
+                                    "analyzer": "bigram_analyzer",
+                                },
+                            },
+                        },
+                        "title": {
+                            "type": "text",
+                            "analyzer": "kuromoji_analyzer",
+                            "fields": {
+                                "bigram": {
+                                    "type": "text",
+                                    "analyzer": "bigram_analyzer",
+                                },
+                            },
+                        },
+                        "id": {
+                            "type": "integer"
+                        },
+                        "tags": {
+                            "type": "keyword"
+                        }
+                    }
+                }
+            }))
+            .send()
+            .await
+            .context("Failed to create index")?;
+
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl application::adapters::SearchIndex for ElasticsearchIndex {
+    async fn search<'a>(&self, keywords: &'a [&'a str]) -> anyhow::Result<Vec<PostId>> {
+        // 本文とタイトルから検索。bigramのマッチはMUST、kuromojiのマッチはSHOULD。
+        let must_queries = keywords
+            .iter()
+            .map(|keyword| {
+                json!({
+                    "multi_match": {
+                        "query": keyword,
+                        "fields": ["body.bigram", "title.bigram"],
+                        "type": "phrase",
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+        let should_queries = keywords
+            .iter()
+            .map(|keyword| {
+                json!({
+                    "multi_match": {
+                        "query": keyword,
+                        "fields": ["body", "title"],
+                        "type": "phrase",
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+        // 絞り込みと並べ替えはPostgreSQLで行うので、マッチした記事のIDだけを返す
+        let body = json!({
+            "size": Self::MAX_RESULT_WINDOW,
+            "_source": false,
+            "fields": ["id"],
+            "query": {
+                "bool": {
+                    "must": must_queries,
+                    "should": should_queries,
+                }
+            },
+        });
+
+        let response = self
+            .client
+            .search(SearchParts::Index(&[&self.index_name]))
+            .body(body)
+            .allow_no_indices(true)
+            .send()
+            .await
+            .context("Search failed")?
+            .error_for_status_code()
+            .context("Search failed")?
+            .json::<Value>()
+            .await
+            .context("Failed to parse search result")?;
+        response["hits"]["hits"]
+            .as_array()
+            .context("`hits` was not an array")?
+            .iter()
+            .map(|v| -> anyhow::Result<PostId> {
+                serde_json::from_value(v["fields"]["id"][0].clone()).context("Failed to get PostId")
+            })
+            .collect()
+    }
+
+    async fn save(&self, post: &Post) -> anyhow::Result<()> {
+        self.create_index_if_needed().await?;
+
+        let response = self
+            .client
+            .exists(ExistsParts::IndexId(&self.index_name, &post.id.to_string()))
+            .send()
+            .await
+            .context("failed to check existence of specified PostId")?;
+        if response.status_code() == StatusCode::OK {
+            // Update
+            self.client
+                .update(UpdateParts::IndexId(&self.index_name, &post.id.to_string()))
+                .body(json!({
+                    "doc": post,
+                }))
+                .send()
+                .await
+                .context("failed to update document")?;
+        } else {
+            // Insert
+            self.client
+                .create(CreateParts::IndexId(&self.index_name, &post.id.to_string()))
+                .body(post)
+                .send()
+                .await
+                .context("failed to insert document")?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &PostId) -> anyhow::Result<()> {
+        self.client
+            .delete(DeleteParts::IndexId(&self.index_name, &id.to_string()))
+            .send()
+            .await
+            .context("failed to delete document")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use application::adapters::SearchIndex;
+use domain::entities::{Post, PostId};
+
+/// `primary`で検索できなかったときに、`fallback`で検索し直す索引
+///
+/// 保存と削除は両方の索引に反映します
+#[derive(Clone)]
+pub struct FallbackSearchIndex<P, F> {
+    primary: P,
+    fallback: F,
+}
+
+impl<P, F> FallbackSearchIndex<P, F> {
+    pub fn new(primary: P, fallback: F) -> Self {
+        Self { primary, fallback }
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, F> SearchIndex for FallbackSearchIndex<P, F>
+where
+    P: SearchIndex + Send + Sync,
+    F: SearchIndex + Send + Sync,
+{
+    async fn search<'a>(&self, keywords: &'a [&'a str]) -> anyhow::Result<Vec<PostId>> {
+        match self.primary.search(keywords).await {
+            Ok(ids) => Ok(ids),
+            Err(e) => {
+                log::warn!("failed to search in the index, falling back: {e:#}");
+                self.fallback.search(keywords).await
+            }
+        }
+    }
+
+    async fn save(&self, post: &Post) -> anyhow::Result<()> {
+        // 片方に失敗しても、もう片方には反映しておく
+        let primary = self.primary.save(post).await;
+        let fallback = self.fallback.save(post).await;
+        primary.and(fallback)
+    }
+
+    async fn delete(&self, id: &PostId) -> anyhow::Result<()> {
+        let primary = self.primary.delete(id).await;
+        let fallback = self.fallback.delete(id).await;
+        primary.and(fallback)
+    }
+}
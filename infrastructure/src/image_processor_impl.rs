@@ -0,0 +1,42 @@
+use anyhow::Context as _;
+use application::adapters::ImageProcessor;
+use image::{imageops::FilterType, ImageReader};
+use std::io::Cursor;
+
+#[derive(Debug, Clone, Default)]
+pub struct ImageProcessorImpl;
+
+impl ImageProcessorImpl {
+    fn resize_blocking(data: &[u8], width: u32, height: u32) -> anyhow::Result<Vec<u8>> {
+        let reader = ImageReader::new(Cursor::new(data))
+            .with_guessed_format()
+            .context("Failed to read image")?;
+        let format = reader.format().context("Unknown image format")?;
+        let image = reader.decode().context("Failed to decode image")?;
+        let mut resized = vec![];
+        image
+            .resize_exact(width, height, FilterType::Lanczos3)
+            .write_to(&mut Cursor::new(&mut resized), format)
+            .context("Failed to encode image")?;
+        Ok(resized)
+    }
+}
+
+#[async_trait::async_trait]
+impl ImageProcessor for ImageProcessorImpl {
+    fn dimensions(&self, data: &[u8]) -> Option<(u32, u32)> {
+        ImageReader::new(Cursor::new(data))
+            .with_guessed_format()
+            .ok()?
+            .into_dimensions()
+            .ok()
+    }
+
+    async fn resize(&self, data: &[u8], width: u32, height: u32) -> anyhow::Result<Vec<u8>> {
+        // デコードと縮小はCPUを使い続けるので、ブロックしてよいスレッドで行う
+        let data = data.to_vec();
+        tokio::task::spawn_blocking(move || Self::resize_blocking(&data, width, height))
+            .await
+            .context("Failed to resize image")?
+    }
+}
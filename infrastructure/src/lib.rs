@@ -4,9 +4,24 @@ extern crate diesel;
 extern crate diesel_migrations;
 
 mod diesel_helpers;
+mod diesel_repositories;
+pub mod elasticsearch_index;
+pub mod fallback_search_index;
 pub mod google_auth_cert_repository_impl;
+pub mod image_processor_impl;
+pub mod local_media_storage;
+pub mod media_repository_impl;
+pub mod memory;
 pub mod migration;
 mod models;
+pub mod pages_repository_impl;
+pub mod post_query_repository_impl;
+pub mod postgres;
+pub mod postgres_search_index;
 pub mod posts_repository_impl;
 mod schema;
-pub mod search_client;
+pub mod series_repository_impl;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(feature = "tantivy")]
+pub mod tantivy_index;
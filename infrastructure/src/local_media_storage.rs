@@ -0,0 +1,55 @@
+use anyhow::{ensure, Context as _};
+use application::adapters::MediaStorage;
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+/// ローカルのディレクトリにファイルを保存します
+#[derive(Debug, Clone)]
+pub struct LocalMediaStorage {
+    root: PathBuf,
+}
+
+impl LocalMediaStorage {
+    /// `root`がなければ作成します
+    pub fn new(root: impl Into<PathBuf>) -> anyhow::Result<LocalMediaStorage> {
+        let root = root.into();
+        std::fs::create_dir_all(&root)
+            .with_context(|| format!("Failed to create {}", root.display()))?;
+        Ok(LocalMediaStorage { root })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// `root`の外を指す名前は使わせない
+    fn path(&self, name: &str) -> anyhow::Result<PathBuf> {
+        ensure!(
+            !name.is_empty() && !name.starts_with('.') && !name.contains(['/', '\\']),
+            "Invalid media name: {name}"
+        );
+        Ok(self.root.join(name))
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaStorage for LocalMediaStorage {
+    async fn put(&self, name: &str, data: &[u8]) -> anyhow::Result<()> {
+        let path = self.path(name)?;
+        tokio::fs::write(&path, data)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    async fn delete(&self, name: &str) -> anyhow::Result<()> {
+        let path = self.path(name)?;
+        match tokio::fs::remove_file(&path).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                Err(e).with_context(|| format!("Failed to delete {}", path.display()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::diesel_helpers::{get_conn, map_duplicate_media_name};
+use crate::models::Media as MediaModel;
+use crate::postgres::PgPool;
+use anyhow::Context;
+use application::adapters::MediaRepository;
+use diesel::prelude::*;
+use domain::entities::{Media, MediaId, NewMedia};
+
+#[derive(Clone)]
+pub struct MediaRepositoryImpl {
+    conn_pool: PgPool,
+}
+
+impl MediaRepositoryImpl {
+    pub fn new(conn_pool: PgPool) -> MediaRepositoryImpl {
+        MediaRepositoryImpl { conn_pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaRepository for MediaRepositoryImpl {
+    async fn get(&self, id: &MediaId) -> anyhow::Result<Option<Media>> {
+        use crate::schema::media::dsl::media;
+        let result = media
+            .find(id.0)
+            .get_result::<MediaModel>(&mut get_conn(&self.conn_pool)?)
+            .optional()
+            .context("Failed to get result")?;
+        Ok(result.map(Into::into))
+    }
+
+    async fn get_all(&self) -> anyhow::Result<Vec<Media>> {
+        use crate::schema::media::dsl::{created_at, id, media};
+        let results = media
+            .order_by((created_at.desc(), id.desc()))
+            .load::<MediaModel>(&mut get_conn(&self.conn_pool)?)
+            .context("Failed to get results")?;
+        Ok(results.into_iter().map(Into::into).collect())
+    }
+
+    async fn add(&self, new_media: NewMedia) -> anyhow::Result<Media> {
+        use crate::schema::media::{
+            self, content_type, created_at, height, name, original_name, size, width,
+        };
+        let (media_width, media_height) = new_media
+            .dimensions
+            .map(|(w, h)| (Some(w as i32), Some(h as i32)))
+            .unwrap_or_default();
+        let result = diesel::insert_into(media::table)
+            .values((
+                name.eq(&new_media.name),
+                original_name.eq(&new_media.original_name),
+                content_type.eq(&new_media.content_type),
+                size.eq(new_media.size),
+                width.eq(media_width),
+                height.eq(media_height),
+                created_at.eq(new_media.created_at),
+            ))
+            .get_result::<MediaModel>(&mut get_conn(&self.conn_pool)?)
+            .map_err(map_duplicate_media_name)?;
+        Ok(result.into())
+    }
+
+    async fn remove(&self, id: &MediaId) -> anyhow::Result<()> {
+        use crate::schema::media::dsl::media;
+        diesel::delete(media.find(id.0))
+            .execute(&mut get_conn(&self.conn_pool)?)
+            .context("Failed to remove media")?;
+        Ok(())
+    }
+}
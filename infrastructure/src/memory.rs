@@ -0,0 +1,197 @@
+//! 外部のサービスを使わずにメモリ上だけで動くリポジトリ
+//!
+//! デモやテンプレートの開発、HTTPのテストのために使います。プロセスが終了すると内容は失われます
+mod media_repository;
+mod pages_repository;
+mod post_query_repository;
+mod posts_repository;
+mod search_index;
+mod series_repository;
+
+pub use media_repository::MemoryMediaRepository;
+pub use pages_repository::MemoryPagesRepository;
+pub use post_query_repository::MemoryPostQueryRepository;
+pub use posts_repository::MemoryPostsRepository;
+pub use search_index::MemorySearchIndex;
+pub use series_repository::MemorySeriesRepository;
+
+use anyhow::Context as _;
+use application::adapters::PostsRepositoryError;
+use chrono::{DateTime, Utc};
+use domain::entities::{
+    Media, MediaId, Page, PageName, Post, PostId, PostStatus, PostVisibility, Revision, RevisionId,
+    Series, SeriesId, Slug, Tag,
+};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+/// メモリ上のリポジトリが共有するデータ
+///
+/// 各リポジトリはこれを複製して持つので、どのリポジトリから変更しても他のリポジトリに反映されます
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    state: Arc<RwLock<MemoryState>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    posts: BTreeMap<PostId, Post>,
+    revisions: BTreeMap<RevisionId, Revision>,
+    pages: BTreeMap<PageName, Page>,
+    series: BTreeMap<SeriesId, Series>,
+    media: BTreeMap<MediaId, Media>,
+    last_post_id: i32,
+    last_revision_id: i32,
+    last_series_id: i32,
+    last_media_id: i32,
+}
+
+impl MemoryState {
+    fn next_post_id(&mut self) -> PostId {
+        self.last_post_id += 1;
+        PostId(self.last_post_id)
+    }
+
+    fn next_series_id(&mut self) -> SeriesId {
+        self.last_series_id += 1;
+        SeriesId(self.last_series_id)
+    }
+
+    fn next_media_id(&mut self) -> MediaId {
+        self.last_media_id += 1;
+        MediaId(self.last_media_id)
+    }
+
+    /// タイトルか本文が最新のリビジョンから変わっていれば、新しいリビジョンとして記録します
+    fn add_revision(&mut self, post_id: PostId, title: &str, body: &str) {
+        let latest = self
+            .revisions
+            .values()
+            .filter(|revision| revision.post_id == post_id)
+            .max_by_key(|revision| (revision.created_at, revision.id));
+        if latest.is_some_and(|revision| revision.title == title && revision.body == body) {
+            return;
+        }
+        self.last_revision_id += 1;
+        let id = RevisionId(self.last_revision_id);
+        let revision = Revision::new(id, post_id, title, body, Utc::now());
+        self.revisions.insert(id, revision);
+    }
+
+    /// `slug`が`id`以外のPostで使われていればエラーを返します
+    fn ensure_slug_is_unused(&self, slug: Option<&Slug>, id: Option<PostId>) -> anyhow::Result<()> {
+        let Some(slug) = slug else {
+            return Ok(());
+        };
+        let used = self
+            .posts
+            .values()
+            .any(|post| post.slug.as_ref() == Some(slug) && Some(post.id) != id);
+        anyhow::ensure!(!used, PostsRepositoryError::DuplicateSlug);
+        Ok(())
+    }
+}
+
+impl MemoryStore {
+    /// JSONのシードファイルから読み込みます
+    pub fn from_seed_file(path: impl AsRef<Path>) -> anyhow::Result<MemoryStore> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read seed file {}", path.display()))?;
+        Self::from_seed(&json)
+    }
+
+    /// JSONのシードから読み込みます
+    ///
+    /// `posts`、`pages`、`series`の配列を持つオブジェクトで、どれも省略できます
+    pub fn from_seed(json: &str) -> anyhow::Result<MemoryStore> {
+        let seed = serde_json::from_str::<Seed>(json).context("Failed to parse seed")?;
+        let mut state = MemoryState::default();
+        for seed_post in seed.posts {
+            let id = match seed_post.id {
+                Some(id) => {
+                    anyhow::ensure!(!state.posts.contains_key(&id), "Post {} is duplicated", id);
+                    state.last_post_id = state.last_post_id.max(id.0);
+                    id
+                }
+                None => state.next_post_id(),
+            };
+            state.ensure_slug_is_unused(seed_post.slug.as_ref(), Some(id))?;
+            let updated_at = seed_post.updated_at.unwrap_or(seed_post.created_at);
+            let mut post = Post::new(
+                id,
+                seed_post.title,
+                seed_post.body,
+                seed_post.created_at,
+                updated_at,
+            );
+            post.status = seed_post.status;
+            post.visibility = seed_post.visibility;
+            post.published_at = seed_post.published_at.unwrap_or(seed_post.created_at);
+            post.tags = seed_post.tags;
+            post.tags.sort();
+            post.tags.dedup();
+            post.slug = seed_post.slug;
+            post.summary = seed_post.summary;
+            post.pinned_at = seed_post.pinned_at;
+            state.add_revision(id, &post.title, &post.body);
+            state.posts.insert(id, post);
+        }
+        for page in seed.pages {
+            state.pages.insert(page.name.clone(), page);
+        }
+        for series in seed.series {
+            state.last_series_id = state.last_series_id.max(series.id.0);
+            state.series.insert(series.id, series);
+        }
+        Ok(MemoryStore {
+            state: Arc::new(RwLock::new(state)),
+        })
+    }
+
+    fn read(&self) -> anyhow::Result<RwLockReadGuard<'_, MemoryState>> {
+        self.state
+            .read()
+            .map_err(|_| anyhow::anyhow!("Memory store is poisoned"))
+    }
+
+    fn write(&self) -> anyhow::Result<RwLockWriteGuard<'_, MemoryState>> {
+        self.state
+            .write()
+            .map_err(|_| anyhow::anyhow!("Memory store is poisoned"))
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Seed {
+    posts: Vec<SeedPost>,
+    pages: Vec<Page>,
+    series: Vec<Series>,
+}
+
+/// シードに書くPost。`created_at`以外は省略できます
+#[derive(Debug, Deserialize)]
+struct SeedPost {
+    id: Option<PostId>,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    body: String,
+    #[serde(default)]
+    status: PostStatus,
+    #[serde(default)]
+    visibility: PostVisibility,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+    slug: Option<Slug>,
+    summary: Option<String>,
+    pinned_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,51 @@
+use super::MemoryStore;
+use application::adapters::{MediaRepository, MediaRepositoryError};
+use domain::entities::{Media, MediaId, NewMedia};
+use std::cmp::Reverse;
+
+#[derive(Debug, Clone)]
+pub struct MemoryMediaRepository {
+    store: MemoryStore,
+}
+
+impl MemoryMediaRepository {
+    pub fn new(store: MemoryStore) -> MemoryMediaRepository {
+        MemoryMediaRepository { store }
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaRepository for MemoryMediaRepository {
+    async fn get(&self, id: &MediaId) -> anyhow::Result<Option<Media>> {
+        Ok(self.store.read()?.media.get(id).cloned())
+    }
+
+    async fn get_all(&self) -> anyhow::Result<Vec<Media>> {
+        let mut media = self
+            .store
+            .read()?
+            .media
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        media.sort_by_key(|media| Reverse((media.created_at, media.id)));
+        Ok(media)
+    }
+
+    async fn add(&self, new_media: NewMedia) -> anyhow::Result<Media> {
+        let mut state = self.store.write()?;
+        let used = state
+            .media
+            .values()
+            .any(|media| media.name == new_media.name);
+        anyhow::ensure!(!used, MediaRepositoryError::DuplicateName);
+        let media = Media::new(state.next_media_id(), new_media);
+        state.media.insert(media.id, media.clone());
+        Ok(media)
+    }
+
+    async fn remove(&self, id: &MediaId) -> anyhow::Result<()> {
+        self.store.write()?.media.remove(id);
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use super::MemoryStore;
+use application::adapters::PagesRepository;
+use domain::entities::{Page, PageName};
+
+#[derive(Debug, Clone)]
+pub struct MemoryPagesRepository {
+    store: MemoryStore,
+}
+
+impl MemoryPagesRepository {
+    pub fn new(store: MemoryStore) -> MemoryPagesRepository {
+        MemoryPagesRepository { store }
+    }
+}
+
+#[async_trait::async_trait]
+impl PagesRepository for MemoryPagesRepository {
+    async fn get(&self, name: &PageName) -> anyhow::Result<Option<Page>> {
+        Ok(self.store.read()?.pages.get(name).cloned())
+    }
+
+    async fn get_all(&self) -> anyhow::Result<Vec<Page>> {
+        Ok(self.store.read()?.pages.values().cloned().collect())
+    }
+
+    async fn save(&self, page: &Page) -> anyhow::Result<Page> {
+        let mut state = self.store.write()?;
+        state.pages.insert(page.name.clone(), page.clone());
+        Ok(page.clone())
+    }
+
+    async fn remove(&self, name: &PageName) -> anyhow::Result<()> {
+        self.store.write()?.pages.remove(name);
+        Ok(())
+    }
+}
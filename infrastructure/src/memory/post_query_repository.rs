@@ -0,0 +1,414 @@
+use super::{MemoryState, MemoryStore};
+use application::models::{
+    Audience, Cursor, MonthDay, MonthlyStats, PostHeadline, SearchResult, TagCount, YearMonth,
+    YearMonthCount,
+};
+use chrono::{DateTime, Datelike as _, Local, NaiveDate, Utc};
+use domain::entities::{Post, PostId, Tag};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    hash::BuildHasher as _,
+};
+
+/// `MemoryStore`のPostを条件で探します
+#[derive(Debug, Clone)]
+pub struct MemoryPostQueryRepository {
+    store: MemoryStore,
+}
+
+impl MemoryPostQueryRepository {
+    pub fn new(store: MemoryStore) -> MemoryPostQueryRepository {
+        MemoryPostQueryRepository { store }
+    }
+
+    /// `audience`に見せてよい記事だけを返します
+    fn visible_posts(state: &MemoryState, audience: Audience) -> impl Iterator<Item = &Post> {
+        let now = Utc::now();
+        // ゴミ箱に入っている記事は誰にも見せない
+        state.posts.values().filter(move |post| {
+            !post.is_deleted()
+                && match audience {
+                    // 下書きと公開日時を迎えていない予約投稿、一覧に載せない記事は見せない
+                    Audience::Public => {
+                        !post.is_draft() && post.published_at <= now && post.is_listed()
+                    }
+                    Audience::Admin => true,
+                }
+        })
+    }
+
+    /// `created_at`降順に並べて`offset`から`limit`件を返します
+    fn search_latest<'a>(
+        posts: impl Iterator<Item = &'a Post>,
+        offset: usize,
+        limit: usize,
+    ) -> SearchResult {
+        let mut posts = posts.collect::<Vec<_>>();
+        posts.sort_by_key(|post| Reverse((post.created_at, post.id)));
+        Self::paginate(posts, offset, limit)
+    }
+
+    /// `created_at`昇順に並べて`offset`から`limit`件を返します
+    fn search_oldest<'a>(
+        posts: impl Iterator<Item = &'a Post>,
+        offset: usize,
+        limit: usize,
+    ) -> SearchResult {
+        let mut posts = posts.collect::<Vec<_>>();
+        posts.sort_by_key(|post| (post.created_at, post.id));
+        Self::paginate(posts, offset, limit)
+    }
+
+    fn paginate(posts: Vec<&Post>, offset: usize, limit: usize) -> SearchResult {
+        SearchResult {
+            total_count: posts.len(),
+            post_ids: posts
+                .into_iter()
+                .skip(offset)
+                .take(limit)
+                .map(|post| post.id)
+                .collect(),
+        }
+    }
+
+    /// PostgreSQLのセッションと同じく、ローカルのタイムゾーンでの日付にします
+    fn local_date(datetime: DateTime<Utc>) -> NaiveDate {
+        datetime.with_timezone(&Local).date_naive()
+    }
+}
+
+#[async_trait::async_trait]
+impl application::adapters::PostQueryRepository for MemoryPostQueryRepository {
+    async fn find_by_ids_and_tags<'a>(
+        &self,
+        ids: Option<&'a [PostId]>,
+        tags: &'a [Tag],
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience).filter(|post| {
+            ids.is_none_or(|ids| ids.contains(&post.id))
+                && tags.iter().all(|tag| post.tags.contains(tag))
+        });
+        Ok(Self::search_latest(posts, offset, limit))
+    }
+
+    async fn find_by_year_month(
+        &self,
+        year_month: &YearMonth,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience)
+            .filter(|post| YearMonth::from(post.created_at) == *year_month);
+        Ok(Self::search_oldest(posts, offset, limit))
+    }
+
+    async fn find_by_date(
+        &self,
+        date: &NaiveDate,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience)
+            .filter(|post| Self::local_date(post.created_at) == *date);
+        Ok(Self::search_oldest(posts, offset, limit))
+    }
+
+    async fn find_by_month_day(
+        &self,
+        month_day: &MonthDay,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience)
+            .filter(|post| MonthDay::from(Self::local_date(post.created_at)) == *month_day);
+        Ok(Self::search_latest(posts, offset, limit))
+    }
+
+    async fn find_by_tag(
+        &self,
+        tag: &Tag,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience).filter(|post| post.tags.contains(tag));
+        Ok(Self::search_latest(posts, offset, limit))
+    }
+
+    async fn get_year_months(&self, audience: Audience) -> anyhow::Result<Vec<YearMonthCount>> {
+        let state = self.store.read()?;
+        let mut counts = BTreeMap::<YearMonth, usize>::new();
+        for post in Self::visible_posts(&state, audience) {
+            *counts.entry(YearMonth::from(post.created_at)).or_default() += 1;
+        }
+        Ok(counts
+            .into_iter()
+            .map(|(year_month, count)| YearMonthCount { year_month, count })
+            .collect())
+    }
+
+    async fn get_headlines(
+        &self,
+        year: Option<u16>,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostHeadline>> {
+        let state = self.store.read()?;
+        let mut posts = Self::visible_posts(&state, audience)
+            .filter(|post| year.is_none_or(|year| YearMonth::from(post.created_at).year == year))
+            .collect::<Vec<_>>();
+        posts.sort_by_key(|post| Reverse((post.created_at, post.id)));
+        Ok(posts
+            .into_iter()
+            .map(|post| PostHeadline {
+                id: post.id,
+                title: post.title.clone(),
+                slug: post.slug.clone(),
+                created_at: post.created_at,
+            })
+            .collect())
+    }
+
+    async fn get_days_in_year_month(
+        &self,
+        ym: &YearMonth,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<u8>> {
+        let state = self.store.read()?;
+        let days = Self::visible_posts(&state, audience)
+            .filter(|post| YearMonth::from(post.created_at) == *ym)
+            .map(|post| Self::local_date(post.created_at).day() as u8)
+            .collect::<BTreeSet<_>>();
+        Ok(days.into_iter().collect())
+    }
+
+    async fn get_latest_posts(
+        &self,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<SearchResult> {
+        let state = self.store.read()?;
+        Ok(Self::search_latest(
+            Self::visible_posts(&state, audience),
+            offset,
+            limit,
+        ))
+    }
+
+    async fn get_before_cursor<'a>(
+        &self,
+        cursor: Option<&'a Cursor>,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience).filter(|post| {
+            cursor.is_none_or(|cursor| (post.created_at, post.id) < (cursor.created_at, cursor.id))
+        });
+        Ok(Self::search_latest(posts, 0, limit).post_ids)
+    }
+
+    async fn get_after_cursor(
+        &self,
+        cursor: &Cursor,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience)
+            .filter(|post| (post.created_at, post.id) > (cursor.created_at, cursor.id));
+        Ok(Self::search_oldest(posts, 0, limit).post_ids)
+    }
+
+    async fn get_random(&self, limit: usize, audience: Audience) -> anyhow::Result<Vec<PostId>> {
+        // `RandomState`は作るたびに違う鍵を使うので、そのハッシュ値で並べ替えればシャッフルになる
+        let random_state = std::collections::hash_map::RandomState::new();
+        let state = self.store.read()?;
+        let mut post_ids = Self::visible_posts(&state, audience)
+            .map(|post| post.id)
+            .collect::<Vec<_>>();
+        post_ids.sort_by_cached_key(|id| random_state.hash_one(id.0));
+        post_ids.truncate(limit);
+        Ok(post_ids)
+    }
+
+    async fn get_pinned(&self, audience: Audience) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let mut posts = Self::visible_posts(&state, audience)
+            .filter(|post| post.pinned_at.is_some())
+            .collect::<Vec<_>>();
+        posts.sort_by_key(|post| Reverse(post.pinned_at));
+        Ok(posts.into_iter().map(|post| post.id).collect())
+    }
+
+    async fn get_monthly_stats(&self, audience: Audience) -> anyhow::Result<Vec<MonthlyStats>> {
+        let state = self.store.read()?;
+        let mut stats = BTreeMap::<YearMonth, MonthlyStats>::new();
+        for post in Self::visible_posts(&state, audience) {
+            let year_month = YearMonth::from(post.created_at);
+            let monthly = stats.entry(year_month).or_insert(MonthlyStats {
+                year_month,
+                post_count: 0,
+                char_count: 0,
+                reading_minutes: 0,
+            });
+            monthly.post_count += 1;
+            if let Some(post_stats) = post.stats {
+                monthly.char_count += post_stats.char_count;
+                monthly.reading_minutes += post_stats.reading_minutes;
+            }
+        }
+        Ok(stats.into_values().collect())
+    }
+
+    async fn get_dates(&self, audience: Audience) -> anyhow::Result<Vec<NaiveDate>> {
+        let state = self.store.read()?;
+        let dates = Self::visible_posts(&state, audience)
+            .map(|post| Self::local_date(post.created_at))
+            .collect::<BTreeSet<_>>();
+        Ok(dates.into_iter().collect())
+    }
+
+    async fn get_longest(&self, limit: usize, audience: Audience) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let mut posts = Self::visible_posts(&state, audience)
+            .filter_map(|post| Some((post.stats?.char_count, post.id)))
+            .collect::<Vec<_>>();
+        posts.sort_by_key(|&(char_count, id)| Reverse((char_count, id)));
+        Ok(posts.into_iter().take(limit).map(|(_, id)| id).collect())
+    }
+
+    async fn get_shortest(&self, limit: usize, audience: Audience) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let mut posts = Self::visible_posts(&state, audience)
+            .filter_map(|post| Some((post.stats?.char_count, post.id)))
+            .collect::<Vec<_>>();
+        posts.sort_by_key(|&(char_count, id)| (char_count, Reverse(id)));
+        Ok(posts.into_iter().take(limit).map(|(_, id)| id).collect())
+    }
+
+    async fn get_most_revised(
+        &self,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<(PostId, usize)>> {
+        let state = self.store.read()?;
+        let mut counts = Self::visible_posts(&state, audience)
+            .map(|post| {
+                let count = state
+                    .revisions
+                    .values()
+                    .filter(|revision| revision.post_id == post.id)
+                    .count();
+                (post.id, count)
+            })
+            .filter(|&(_, count)| count > 0)
+            .collect::<Vec<_>>();
+        counts.sort_by_key(|&(id, count)| Reverse((count, id)));
+        counts.truncate(limit);
+        Ok(counts)
+    }
+
+    async fn get_without_stats(&self) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        Ok(state
+            .posts
+            .values()
+            .filter(|post| post.stats.is_none())
+            .map(|post| post.id)
+            .collect())
+    }
+
+    async fn get_tags(&self, audience: Audience) -> anyhow::Result<Vec<TagCount>> {
+        let state = self.store.read()?;
+        let mut counts = BTreeMap::<&Tag, usize>::new();
+        for tag in Self::visible_posts(&state, audience).flat_map(|post| &post.tags) {
+            *counts.entry(tag).or_default() += 1;
+        }
+        Ok(counts
+            .into_iter()
+            .map(|(tag, count)| TagCount {
+                tag: tag.clone(),
+                count,
+            })
+            .collect())
+    }
+
+    async fn get_last_updated(&self) -> anyhow::Result<Option<DateTime<Utc>>> {
+        let state = self.store.read()?;
+        Ok(Self::visible_posts(&state, Audience::Public)
+            .map(|post| post.updated_at)
+            .max())
+    }
+
+    async fn get_drafts(&self) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let mut posts = Self::visible_posts(&state, Audience::Admin)
+            .filter(|post| post.is_draft())
+            .collect::<Vec<_>>();
+        posts.sort_by_key(|post| Reverse(post.updated_at));
+        Ok(posts.into_iter().map(|post| post.id).collect())
+    }
+
+    async fn get_trashed(&self) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let mut posts = state
+            .posts
+            .values()
+            .filter(|post| post.is_deleted())
+            .collect::<Vec<_>>();
+        posts.sort_by_key(|post| Reverse(post.deleted_at));
+        Ok(posts.into_iter().map(|post| post.id).collect())
+    }
+
+    async fn get_published_between(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let mut posts = Self::visible_posts(&state, Audience::Admin)
+            .filter(|post| {
+                !post.is_draft() && from < post.published_at && post.published_at <= until
+            })
+            .collect::<Vec<_>>();
+        posts.sort_by_key(|post| post.published_at);
+        Ok(posts.into_iter().map(|post| post.id).collect())
+    }
+
+    async fn get_from_date(
+        &self,
+        from: DateTime<Utc>,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience).filter(|post| post.created_at >= from);
+        Ok(Self::search_oldest(posts, offset, limit).post_ids)
+    }
+
+    async fn get_until_date(
+        &self,
+        until: DateTime<Utc>,
+        offset: usize,
+        limit: usize,
+        audience: Audience,
+    ) -> anyhow::Result<Vec<PostId>> {
+        let state = self.store.read()?;
+        let posts = Self::visible_posts(&state, audience).filter(|post| post.created_at < until);
+        Ok(Self::search_latest(posts, offset, limit).post_ids)
+    }
+}
@@ -0,0 +1,170 @@
+use super::MemoryStore;
+use anyhow::Context as _;
+use application::adapters::{PostsRepository, PostsRepositoryError};
+use chrono::{DateTime, Utc};
+use domain::entities::{NewPost, Post, PostId, PostStats, Revision, RevisionId, Slug, Tag};
+use std::cmp::Reverse;
+
+#[derive(Debug, Clone)]
+pub struct MemoryPostsRepository {
+    store: MemoryStore,
+}
+
+impl MemoryPostsRepository {
+    pub fn new(store: MemoryStore) -> MemoryPostsRepository {
+        MemoryPostsRepository { store }
+    }
+
+    /// Postを書き換えます。Postがなければエラーを返します
+    fn update(&self, id: &PostId, f: impl FnOnce(&mut Post)) -> anyhow::Result<Post> {
+        let mut state = self.store.write()?;
+        let post = state
+            .posts
+            .get_mut(id)
+            .with_context(|| format!("Post {} is not found", id))?;
+        f(post);
+        Ok(post.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl PostsRepository for MemoryPostsRepository {
+    async fn get_by_id(&self, id: &PostId) -> anyhow::Result<Option<Post>> {
+        Ok(self.store.read()?.posts.get(id).cloned())
+    }
+
+    async fn get_by_ids(&self, ids: &[PostId]) -> anyhow::Result<Vec<Post>> {
+        let state = self.store.read()?;
+        Ok(ids
+            .iter()
+            .filter_map(|id| state.posts.get(id).cloned())
+            .collect())
+    }
+
+    async fn get_by_slug(&self, slug: &Slug) -> anyhow::Result<Option<Post>> {
+        let state = self.store.read()?;
+        Ok(state
+            .posts
+            .values()
+            .find(|post| post.slug.as_ref() == Some(slug))
+            .cloned())
+    }
+
+    async fn add(&self, new_post: NewPost) -> anyhow::Result<Post> {
+        let mut state = self.store.write()?;
+        state.ensure_slug_is_unused(new_post.slug.as_ref(), None)?;
+        let id = state.next_post_id();
+        let mut post = Post::new(
+            id,
+            new_post.title,
+            new_post.body,
+            new_post.timestamp,
+            new_post.timestamp,
+        );
+        post.status = new_post.status;
+        post.visibility = new_post.visibility;
+        post.published_at = new_post.published_at;
+        post.slug = new_post.slug;
+        post.summary = new_post.summary;
+        post.stats = new_post.stats;
+        post.tags = new_post.tags;
+        post.tags.sort();
+        post.tags.dedup();
+        state.add_revision(id, &post.title, &post.body);
+        state.posts.insert(id, post.clone());
+        Ok(post)
+    }
+
+    async fn save(&self, post: &Post) -> anyhow::Result<Post> {
+        let mut state = self.store.write()?;
+        state.ensure_slug_is_unused(post.slug.as_ref(), Some(post.id))?;
+        // 読み込んだときからバージョンが変わっていたら保存しない
+        let saved = state
+            .posts
+            .get_mut(&post.id)
+            .filter(|saved| saved.version == post.version)
+            .ok_or(PostsRepositoryError::VersionConflict(post.id))?;
+        saved.title = post.title.clone();
+        saved.body = post.body.clone();
+        saved.status = post.status;
+        saved.created_at = post.created_at;
+        saved.updated_at = post.updated_at;
+        saved.published_at = post.published_at;
+        saved.slug = post.slug.clone();
+        saved.summary = post.summary.clone();
+        saved.visibility = post.visibility;
+        saved.stats = post.stats;
+        saved.tags = post.tags.clone();
+        saved.tags.sort();
+        saved.tags.dedup();
+        saved.version += 1;
+        let saved = saved.clone();
+        state.add_revision(saved.id, &saved.title, &saved.body);
+        Ok(saved)
+    }
+
+    async fn remove(&self, id: &PostId) -> anyhow::Result<()> {
+        let mut state = self.store.write()?;
+        state.posts.remove(id);
+        state
+            .revisions
+            .retain(|_, revision| revision.post_id != *id);
+        for series in state.series.values_mut() {
+            series.remove_post(*id);
+        }
+        Ok(())
+    }
+
+    async fn move_to_trash(&self, id: &PostId) -> anyhow::Result<()> {
+        self.update(id, |post| post.deleted_at = Some(Utc::now()))?;
+        Ok(())
+    }
+
+    async fn set_pinned_at(
+        &self,
+        id: &PostId,
+        pinned_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        self.update(id, |post| post.pinned_at = pinned_at)?;
+        Ok(())
+    }
+
+    async fn set_stats(&self, id: &PostId, stats: &PostStats) -> anyhow::Result<()> {
+        self.update(id, |post| post.stats = Some(*stats))?;
+        Ok(())
+    }
+
+    async fn restore_from_trash(&self, id: &PostId) -> anyhow::Result<Post> {
+        self.update(id, |post| post.deleted_at = None)
+    }
+
+    async fn attach_tags(&self, id: &PostId, tags: &[Tag]) -> anyhow::Result<()> {
+        self.update(id, |post| {
+            post.tags.extend_from_slice(tags);
+            post.tags.sort();
+            post.tags.dedup();
+        })?;
+        Ok(())
+    }
+
+    async fn detach_tags(&self, id: &PostId, tags: &[Tag]) -> anyhow::Result<()> {
+        self.update(id, |post| post.tags.retain(|tag| !tags.contains(tag)))?;
+        Ok(())
+    }
+
+    async fn get_revisions(&self, id: &PostId) -> anyhow::Result<Vec<Revision>> {
+        let state = self.store.read()?;
+        let mut revisions = state
+            .revisions
+            .values()
+            .filter(|revision| revision.post_id == *id)
+            .cloned()
+            .collect::<Vec<_>>();
+        revisions.sort_by_key(|revision| Reverse((revision.created_at, revision.id)));
+        Ok(revisions)
+    }
+
+    async fn get_revision(&self, id: &RevisionId) -> anyhow::Result<Option<Revision>> {
+        Ok(self.store.read()?.revisions.get(id).cloned())
+    }
+}
@@ -0,0 +1,50 @@
+use super::MemoryStore;
+use domain::entities::{Post, PostId};
+
+/// `MemoryStore`のPostをそのままキーワード検索します
+///
+/// 大文字と小文字を区別しない部分一致です
+#[derive(Debug, Clone)]
+pub struct MemorySearchIndex {
+    store: MemoryStore,
+}
+
+impl MemorySearchIndex {
+    pub fn new(store: MemoryStore) -> MemorySearchIndex {
+        MemorySearchIndex { store }
+    }
+}
+
+#[async_trait::async_trait]
+impl application::adapters::SearchIndex for MemorySearchIndex {
+    async fn search<'a>(&self, keywords: &'a [&'a str]) -> anyhow::Result<Vec<PostId>> {
+        let keywords = keywords
+            .iter()
+            .map(|keyword| keyword.to_lowercase())
+            .collect::<Vec<_>>();
+        let state = self.store.read()?;
+        let ids = state
+            .posts
+            .values()
+            .filter(|post| {
+                let title = post.title.to_lowercase();
+                let body = post.body.to_lowercase();
+                keywords
+                    .iter()
+                    .all(|keyword| title.contains(keyword) || body.contains(keyword))
+            })
+            .map(|post| post.id)
+            .collect();
+        Ok(ids)
+    }
+
+    /// `MemoryStore`のPostをそのまま検索するので、何もしません
+    async fn save(&self, _post: &Post) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// `MemoryStore`のPostをそのまま検索するので、何もしません
+    async fn delete(&self, _id: &PostId) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use super::MemoryStore;
+use application::adapters::{SeriesRepository, SeriesRepositoryError};
+use domain::entities::{PostId, Series, SeriesId};
+
+#[derive(Debug, Clone)]
+pub struct MemorySeriesRepository {
+    store: MemoryStore,
+}
+
+impl MemorySeriesRepository {
+    pub fn new(store: MemoryStore) -> MemorySeriesRepository {
+        MemorySeriesRepository { store }
+    }
+}
+
+#[async_trait::async_trait]
+impl SeriesRepository for MemorySeriesRepository {
+    async fn get(&self, id: &SeriesId) -> anyhow::Result<Option<Series>> {
+        Ok(self.store.read()?.series.get(id).cloned())
+    }
+
+    async fn get_all(&self) -> anyhow::Result<Vec<Series>> {
+        Ok(self.store.read()?.series.values().cloned().collect())
+    }
+
+    async fn get_by_post_id(&self, post_id: &PostId) -> anyhow::Result<Option<Series>> {
+        let state = self.store.read()?;
+        Ok(state
+            .series
+            .values()
+            .find(|series| series.post_ids.contains(post_id))
+            .cloned())
+    }
+
+    async fn add(&self, title: &str) -> anyhow::Result<Series> {
+        let mut state = self.store.write()?;
+        let series = Series::new(state.next_series_id(), title, vec![]);
+        state.series.insert(series.id, series.clone());
+        Ok(series)
+    }
+
+    async fn save(&self, series: &Series) -> anyhow::Result<Series> {
+        let mut state = self.store.write()?;
+        // ひとつのPostは複数のシリーズに入れられない
+        let conflict = state.series.values().any(|other| {
+            other.id != series.id && other.post_ids.iter().any(|id| series.post_ids.contains(id))
+        });
+        if conflict {
+            return Err(SeriesRepositoryError::PostAlreadyInSeries.into());
+        }
+        state.series.insert(series.id, series.clone());
+        Ok(series.clone())
+    }
+
+    async fn remove(&self, id: &SeriesId) -> anyhow::Result<()> {
+        self.store.write()?.series.remove(id);
+        Ok(())
+    }
+}
@@ -6,7 +6,10 @@
 use super::schema::posts;
 use chrono::{offset::Utc, TimeZone};
 use chrono::{DateTime, NaiveDateTime};
-use domain::entities::{Post as PostEntity, PostId};
+use domain::entities::{
+    Media as MediaEntity, MediaId, NewMedia, Page as PageEntity, PageName, Post as PostEntity,
+    PostId, PostStats, PostStatus, PostVisibility, Revision as RevisionEntity, RevisionId, Slug,
+};
 
 #[derive(Queryable, Insertable, Debug, Clone)]
 pub(crate) struct Post {
@@ -15,16 +18,121 @@ pub(crate) struct Post {
     pub body: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub is_draft: bool,
+    pub published_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub version: i32,
+    pub slug: Option<String>,
+    pub summary: Option<String>,
+    pub visibility: String,
+    pub pinned_at: Option<DateTime<Utc>>,
+    pub char_count: Option<i32>,
+    pub reading_minutes: Option<i32>,
 }
 
 impl From<Post> for PostEntity {
     fn from(post: Post) -> PostEntity {
-        PostEntity::new(
+        let mut entity = PostEntity::new(
             PostId(post.id),
             post.title,
             post.body,
             post.created_at,
             post.updated_at,
+        );
+        entity.published_at = post.published_at;
+        entity.deleted_at = post.deleted_at;
+        entity.version = post.version;
+        entity.slug = post.slug.and_then(|slug| Slug::new(slug).ok());
+        entity.summary = post.summary;
+        entity.pinned_at = post.pinned_at;
+        entity.stats =
+            post.char_count
+                .zip(post.reading_minutes)
+                .map(|(char_count, reading_minutes)| PostStats {
+                    char_count: char_count as usize,
+                    reading_minutes: reading_minutes as usize,
+                });
+        // 知らない値が入っていたら管理者にだけ見せる
+        entity.visibility = match post.visibility.as_str() {
+            "public" => PostVisibility::Public,
+            "unlisted" => PostVisibility::Unlisted,
+            _ => PostVisibility::Private,
+        };
+        if post.is_draft {
+            entity.status = PostStatus::Draft;
+        }
+        entity
+    }
+}
+
+#[derive(Queryable, Debug, Clone)]
+pub(crate) struct Revision {
+    pub id: i32,
+    pub post_id: i32,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Revision> for RevisionEntity {
+    fn from(revision: Revision) -> RevisionEntity {
+        RevisionEntity::new(
+            RevisionId(revision.id),
+            PostId(revision.post_id),
+            revision.title,
+            revision.body,
+            revision.created_at,
         )
     }
 }
+
+#[derive(Queryable, Debug, Clone)]
+pub(crate) struct Page {
+    pub name: String,
+    pub title: String,
+    pub body: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<Page> for PageEntity {
+    type Error = domain::Error;
+
+    fn try_from(page: Page) -> Result<PageEntity, Self::Error> {
+        Ok(PageEntity::new(
+            PageName::new(page.name)?,
+            page.title,
+            page.body,
+            page.updated_at,
+        ))
+    }
+}
+
+#[derive(Queryable, Debug, Clone)]
+pub(crate) struct Media {
+    pub id: i32,
+    pub name: String,
+    pub original_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Media> for MediaEntity {
+    fn from(media: Media) -> MediaEntity {
+        let dimensions = media
+            .width
+            .zip(media.height)
+            .map(|(width, height)| (width as u32, height as u32));
+        let mut new_media = NewMedia::new(
+            media.original_name,
+            media.content_type,
+            media.size,
+            dimensions,
+            media.created_at,
+        );
+        new_media.name = media.name;
+        MediaEntity::new(MediaId(media.id), new_media)
+    }
+}
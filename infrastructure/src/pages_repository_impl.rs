@@ -0,0 +1,72 @@
+use crate::diesel_helpers::get_conn;
+use crate::models::Page as PageModel;
+use crate::postgres::PgPool;
+use anyhow::Context;
+use application::adapters::PagesRepository;
+use diesel::prelude::*;
+use domain::entities::{Page, PageName};
+
+#[derive(Clone)]
+pub struct PagesRepositoryImpl {
+    conn_pool: PgPool,
+}
+
+impl PagesRepositoryImpl {
+    pub fn new(conn_pool: PgPool) -> PagesRepositoryImpl {
+        PagesRepositoryImpl { conn_pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl PagesRepository for PagesRepositoryImpl {
+    async fn get(&self, name: &PageName) -> anyhow::Result<Option<Page>> {
+        use crate::schema::pages::dsl::pages;
+        let page = pages
+            .find(name.as_str())
+            .get_result::<PageModel>(&mut get_conn(&self.conn_pool)?)
+            .optional()
+            .context("Failed to get result")?;
+        Ok(page.map(Page::try_from).transpose()?)
+    }
+
+    async fn get_all(&self) -> anyhow::Result<Vec<Page>> {
+        use crate::schema::pages::dsl::{name, pages};
+        let results = pages
+            .order_by(name.asc())
+            .load::<PageModel>(&mut get_conn(&self.conn_pool)?)
+            .context("Failed to get results")?;
+        Ok(results
+            .into_iter()
+            .map(Page::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    async fn save(&self, page: &Page) -> anyhow::Result<Page> {
+        use crate::schema::pages::{self, body, name, title, updated_at};
+        let page = diesel::insert_into(pages::table)
+            .values((
+                name.eq(page.name.as_str()),
+                title.eq(&page.title),
+                body.eq(&page.body),
+                updated_at.eq(page.updated_at),
+            ))
+            .on_conflict(name)
+            .do_update()
+            .set((
+                title.eq(&page.title),
+                body.eq(&page.body),
+                updated_at.eq(page.updated_at),
+            ))
+            .get_result::<PageModel>(&mut get_conn(&self.conn_pool)?)
+            .context("Failed to save page")?;
+        Ok(page.try_into()?)
+    }
+
+    async fn remove(&self, name: &PageName) -> anyhow::Result<()> {
+        use crate::schema::pages::dsl::pages;
+        diesel::delete(pages.find(name.as_str()))
+            .execute(&mut get_conn(&self.conn_pool)?)
+            .context("Failed to remove page")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use diesel::{
+    dsl::sql,
+    expression::SqlLiteral,
+    pg::Pg,
+    sql_types::{Date, Integer},
+};
+
+use crate::diesel_helpers::{extract, DatePart};
+use crate::diesel_repositories::impl_post_query_repository;
+use crate::postgres::PgPool;
+use crate::posts_repository_impl::PostsRepositoryImpl;
+
+#[derive(Clone)]
+pub struct PostQueryRepositoryImpl {
+    conn_pool: PgPool,
+}
+
+impl PostQueryRepositoryImpl {
+    /// `posts`とコネクションプールを共有します
+    pub fn new(posts: &PostsRepositoryImpl) -> Self {
+        Self {
+            conn_pool: posts.conn_pool.clone(),
+        }
+    }
+
+    /// `created_at`の年、月、日のいずれかをセッションのタイムゾーンで取り出します
+    fn date_part(part: DatePart) -> SqlLiteral<Integer> {
+        extract(part, "created_at")
+    }
+
+    /// `created_at`のセッションのタイムゾーンでの日付
+    fn local_date() -> SqlLiteral<Date> {
+        sql("CAST(created_at AS DATE)")
+    }
+}
+
+impl_post_query_repository!(PostQueryRepositoryImpl, Pg, crate::schema);
@@ -0,0 +1,20 @@
+//! PostgreSQLのリポジトリが使うコネクションプール
+use anyhow::Context as _;
+use chrono::Local;
+use diesel::{r2d2::ConnectionManager, PgConnection};
+use r2d2::Pool;
+
+use crate::diesel_helpers::TimezoneCustomizer;
+
+pub type PgPool = Pool<ConnectionManager<PgConnection>>;
+
+/// `pg_url`のデータベースへのコネクションプールを作ります。各コネクションのタイムゾーンはローカルのものにそろえます
+pub fn connect(pg_url: &url::Url) -> anyhow::Result<PgPool> {
+    let customizer = TimezoneCustomizer {
+        offset: *Local::now().offset(),
+    };
+    Pool::builder()
+        .connection_customizer(Box::new(customizer))
+        .build(ConnectionManager::<PgConnection>::new(pg_url.as_str()))
+        .context("Failed to build connection pool")
+}
@@ -0,0 +1,58 @@
+use anyhow::Context as _;
+use diesel::prelude::*;
+use domain::entities::{Post, PostId};
+
+use crate::diesel_helpers::{get_conn, search_normalize, search_pattern};
+use crate::postgres::PgPool;
+use crate::posts_repository_impl::PostsRepositoryImpl;
+
+/// 索引を使わず、PostgreSQLの記事をそのままキーワード検索します
+///
+/// Elasticsearchのbigramのフレーズ検索と同じく、正規化したタイトルか本文にキーワードがそのまま含まれていればマッチします。
+/// kuromojiのマッチはスコアにしか影響せず、結果は日時順に並べるので考えません
+#[derive(Clone)]
+pub struct PostgresSearchIndex {
+    conn_pool: PgPool,
+}
+
+impl PostgresSearchIndex {
+    /// `posts`とコネクションプールを共有します
+    pub fn new(posts: &PostsRepositoryImpl) -> Self {
+        Self {
+            conn_pool: posts.conn_pool.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl application::adapters::SearchIndex for PostgresSearchIndex {
+    async fn search<'a>(&self, keywords: &'a [&'a str]) -> anyhow::Result<Vec<PostId>> {
+        use crate::schema::posts::dsl::{body, id, posts, title};
+        let mut query = posts.select(id).into_boxed();
+        for keyword in keywords {
+            query = query.filter(
+                search_normalize(title)
+                    .like(search_pattern(*keyword))
+                    .or(search_normalize(body).like(search_pattern(*keyword))),
+            );
+        }
+        let mut conn = get_conn(&self.conn_pool)?;
+        let ids = query
+            .get_results::<i32>(&mut conn)
+            .context("Failed to search posts")?
+            .into_iter()
+            .map(PostId)
+            .collect();
+        Ok(ids)
+    }
+
+    /// 記事のテーブルをそのまま検索するので、何もしません
+    async fn save(&self, _post: &Post) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// 記事のテーブルをそのまま検索するので、何もしません
+    async fn delete(&self, _id: &PostId) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
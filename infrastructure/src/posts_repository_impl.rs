@@ -1,35 +1,59 @@
-use std::collections::HashMap;
-
-use crate::diesel_helpers::TimezoneCustomizer;
+use crate::diesel_helpers::get_conn;
+use crate::diesel_repositories::impl_posts_repository;
 use crate::models::Post as PostModel;
+use crate::postgres::PgPool;
 use anyhow::{Context, Result as AnyhowResult};
-use application::adapters::PostsRepository;
-use chrono::offset::Local;
 use diesel::prelude::*;
-use diesel::r2d2::ConnectionManager;
-use domain::entities::{NewPost, Post, PostId};
-use r2d2::{Pool, PooledConnection};
+use domain::entities::{Post, Slug, Tag};
 
 #[derive(Clone)]
 pub struct PostsRepositoryImpl {
-    pub(crate) conn_pool: Pool<ConnectionManager<PgConnection>>,
+    pub(crate) conn_pool: PgPool,
 }
 
 impl PostsRepositoryImpl {
-    pub fn new(pg_url: &url::Url) -> anyhow::Result<PostsRepositoryImpl> {
-        let conn_manager = ConnectionManager::<PgConnection>::new(pg_url.as_str());
-        let customizer = TimezoneCustomizer {
-            offset: *Local::now().offset(),
-        };
-        let conn_pool = Pool::builder()
-            .connection_customizer(Box::new(customizer))
-            .build(conn_manager)
-            .context("Failed to build connection pool")?;
-        Ok(PostsRepositoryImpl { conn_pool })
+    pub fn new(conn_pool: PgPool) -> PostsRepositoryImpl {
+        PostsRepositoryImpl { conn_pool }
     }
 
-    fn get_conn(&self) -> AnyhowResult<PooledConnection<ConnectionManager<PgConnection>>> {
-        self.conn_pool.get().context("Failed to get connection")
+    /// Postにタグをつけます。すでについているタグは無視します
+    fn insert_tags(conn: &mut PgConnection, post_id: i32, tags: &[Tag]) -> AnyhowResult<()> {
+        use crate::schema::{posts_tags, tags};
+        if tags.is_empty() {
+            return Ok(());
+        }
+        let names = tags.iter().map(Tag::name).collect::<Vec<_>>();
+        diesel::insert_into(tags::table)
+            .values(
+                names
+                    .iter()
+                    .map(|name| tags::name.eq(name))
+                    .collect::<Vec<_>>(),
+            )
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .context("Failed to insert tags")?;
+        let tag_ids = tags::table
+            .filter(tags::name.eq_any(&names))
+            .select(tags::id)
+            .get_results::<i32>(conn)
+            .context("Failed to get tag ids")?;
+        diesel::insert_into(posts_tags::table)
+            .values(
+                tag_ids
+                    .into_iter()
+                    .map(|tag_id| {
+                        (
+                            posts_tags::post_id.eq(post_id),
+                            posts_tags::tag_id.eq(tag_id),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .context("Failed to attach tags")?;
+        Ok(())
     }
 }
 
@@ -40,7 +64,10 @@ pub trait PostsRepositoryImplTestHelper {
 
 impl PostsRepositoryImplTestHelper for PostsRepositoryImpl {
     fn import(&self, posts: &[Post]) -> anyhow::Result<Vec<Post>> {
-        use crate::schema::posts::{self, body, created_at, id, title, updated_at};
+        use crate::schema::posts::{
+            self, body, char_count, created_at, deleted_at, id, is_draft, pinned_at, published_at,
+            reading_minutes, slug, summary, title, updated_at, version, visibility,
+        };
         let records = posts
             .iter()
             .map(|post| {
@@ -50,84 +77,35 @@ impl PostsRepositoryImplTestHelper for PostsRepositoryImpl {
                     body.eq(post.body.clone()),
                     created_at.eq(post.created_at),
                     updated_at.eq(post.updated_at),
+                    is_draft.eq(post.is_draft()),
+                    published_at.eq(post.published_at),
+                    deleted_at.eq(post.deleted_at),
+                    version.eq(post.version),
+                    slug.eq(post.slug.as_ref().map(Slug::as_str)),
+                    summary.eq(&post.summary),
+                    visibility.eq(post.visibility.as_str()),
+                    pinned_at.eq(post.pinned_at),
+                    char_count.eq(post.stats.map(|stats| stats.char_count as i32)),
+                    reading_minutes.eq(post.stats.map(|stats| stats.reading_minutes as i32)),
                 )
             })
             .collect::<Vec<_>>();
         let post = diesel::insert_into(posts::table)
             .values(&records)
-            .get_results::<PostModel>(&mut self.get_conn()?)
+            .get_results::<PostModel>(&mut get_conn(&self.conn_pool)?)
             .context("Failed to get results")?;
         Ok(post.into_iter().map(Into::into).collect())
     }
 
     fn reset_id_sequence(&self) -> anyhow::Result<()> {
         diesel::sql_query("SELECT reset_posts_id_sequence();")
-            .execute(&mut self.get_conn()?)
+            .execute(&mut get_conn(&self.conn_pool)?)
             .context("Failed to reset id sequence")?;
         Ok(())
     }
 }
 
-#[async_trait::async_trait]
-impl PostsRepository for PostsRepositoryImpl {
-    async fn get_by_id(&self, id: &PostId) -> anyhow::Result<Option<Post>> {
-        use crate::schema::posts::dsl::posts;
-        let post = posts
-            .find(id.0)
-            .get_result::<PostModel>(&mut self.get_conn()?)
-            .optional()
-            .context("Failed to get result")?;
-        Ok(post.map(Post::from))
-    }
-
-    async fn get_by_ids(&self, ids: &[PostId]) -> anyhow::Result<Vec<Post>> {
-        use crate::schema::posts::{dsl::posts, id};
-        let post_ids = ids.iter().map(|post_id| post_id.0).collect::<Vec<_>>();
-        let posts_map: HashMap<_, _> = posts
-            .filter(id.eq_any(&post_ids))
-            .get_results::<PostModel>(&mut self.get_conn()?)
-            .context("Failed to get results")?
-            .into_iter()
-            .map(|post| (post.id, Post::from(post)))
-            .collect();
-
-        Ok(post_ids
-            .iter()
-            .filter_map(|&post_id| posts_map.get(&post_id).cloned())
-            .collect())
-    }
-
-    async fn add(&self, new_post: NewPost) -> anyhow::Result<Post> {
-        use crate::schema::posts::{self, body, created_at, title, updated_at};
-        let post = diesel::insert_into(posts::table)
-            .values((
-                title.eq(new_post.title),
-                body.eq(new_post.body),
-                created_at.eq(new_post.timestamp),
-                updated_at.eq(new_post.timestamp),
-            ))
-            .get_result::<PostModel>(&mut self.get_conn()?)?;
-        Ok(post.into())
-    }
-
-    async fn save(&self, post: &Post) -> anyhow::Result<Post> {
-        use crate::schema::posts::dsl::{body, posts, title, updated_at};
-        let post = diesel::update(posts.find(post.id.0))
-            .set((
-                title.eq(post.title.clone()),
-                body.eq(post.body.clone()),
-                updated_at.eq(post.updated_at),
-            ))
-            .get_result::<PostModel>(&mut self.get_conn()?)?;
-        Ok(post.into())
-    }
-
-    async fn remove(&self, id: &PostId) -> anyhow::Result<()> {
-        use crate::schema::posts::dsl::posts;
-        diesel::delete(posts.find(id.0)).execute(&mut self.get_conn()?)?;
-        Ok(())
-    }
-}
+impl_posts_repository!(PostsRepositoryImpl, PgConnection, crate::schema);
 
 #[cfg(test)]
 mod tests {}
@@ -1,5 +1,131 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    /// Representation of the `media` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    media (id) {
+        /// The `id` column of the `media` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Int4,
+        /// The `name` column of the `media` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        name -> Varchar,
+        /// The `original_name` column of the `media` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        original_name -> Varchar,
+        /// The `content_type` column of the `media` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        content_type -> Varchar,
+        /// The `size` column of the `media` table.
+        ///
+        /// Its SQL type is `Int8`.
+        ///
+        /// (Automatically generated by Diesel.)
+        size -> Int8,
+        /// The `width` column of the `media` table.
+        ///
+        /// Its SQL type is `Nullable<Int4>`.
+        ///
+        /// (Automatically generated by Diesel.)
+        width -> Nullable<Int4>,
+        /// The `height` column of the `media` table.
+        ///
+        /// Its SQL type is `Nullable<Int4>`.
+        ///
+        /// (Automatically generated by Diesel.)
+        height -> Nullable<Int4>,
+        /// The `created_at` column of the `media` table.
+        ///
+        /// Its SQL type is `Timestamptz`.
+        ///
+        /// (Automatically generated by Diesel.)
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// Representation of the `pages` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    pages (name) {
+        /// The `name` column of the `pages` table.
+        ///
+        /// Its SQL type is `Text`.
+        ///
+        /// (Automatically generated by Diesel.)
+        name -> Text,
+        /// The `title` column of the `pages` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        title -> Varchar,
+        /// The `body` column of the `pages` table.
+        ///
+        /// Its SQL type is `Text`.
+        ///
+        /// (Automatically generated by Diesel.)
+        body -> Text,
+        /// The `updated_at` column of the `pages` table.
+        ///
+        /// Its SQL type is `Timestamptz`.
+        ///
+        /// (Automatically generated by Diesel.)
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    /// Representation of the `post_revisions` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    post_revisions (id) {
+        /// The `id` column of the `post_revisions` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Int4,
+        /// The `post_id` column of the `post_revisions` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        post_id -> Int4,
+        /// The `title` column of the `post_revisions` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        title -> Varchar,
+        /// The `body` column of the `post_revisions` table.
+        ///
+        /// Its SQL type is `Text`.
+        ///
+        /// (Automatically generated by Diesel.)
+        body -> Text,
+        /// The `created_at` column of the `post_revisions` table.
+        ///
+        /// Its SQL type is `Timestamptz`.
+        ///
+        /// (Automatically generated by Diesel.)
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     /// Representation of the `posts` table.
     ///
@@ -35,5 +161,168 @@ diesel::table! {
         ///
         /// (Automatically generated by Diesel.)
         updated_at -> Timestamptz,
+        /// The `is_draft` column of the `posts` table.
+        ///
+        /// Its SQL type is `Bool`.
+        ///
+        /// (Automatically generated by Diesel.)
+        is_draft -> Bool,
+        /// The `published_at` column of the `posts` table.
+        ///
+        /// Its SQL type is `Timestamptz`.
+        ///
+        /// (Automatically generated by Diesel.)
+        published_at -> Timestamptz,
+        /// The `deleted_at` column of the `posts` table.
+        ///
+        /// Its SQL type is `Nullable<Timestamptz>`.
+        ///
+        /// (Automatically generated by Diesel.)
+        deleted_at -> Nullable<Timestamptz>,
+        /// The `version` column of the `posts` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        version -> Int4,
+        /// The `slug` column of the `posts` table.
+        ///
+        /// Its SQL type is `Nullable<Text>`.
+        ///
+        /// (Automatically generated by Diesel.)
+        slug -> Nullable<Text>,
+        /// The `summary` column of the `posts` table.
+        ///
+        /// Its SQL type is `Nullable<Text>`.
+        ///
+        /// (Automatically generated by Diesel.)
+        summary -> Nullable<Text>,
+        /// The `visibility` column of the `posts` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        visibility -> Varchar,
+        /// The `pinned_at` column of the `posts` table.
+        ///
+        /// Its SQL type is `Nullable<Timestamptz>`.
+        ///
+        /// (Automatically generated by Diesel.)
+        pinned_at -> Nullable<Timestamptz>,
+        /// The `char_count` column of the `posts` table.
+        ///
+        /// Its SQL type is `Nullable<Int4>`.
+        ///
+        /// (Automatically generated by Diesel.)
+        char_count -> Nullable<Int4>,
+        /// The `reading_minutes` column of the `posts` table.
+        ///
+        /// Its SQL type is `Nullable<Int4>`.
+        ///
+        /// (Automatically generated by Diesel.)
+        reading_minutes -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    /// Representation of the `posts_tags` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    posts_tags (post_id, tag_id) {
+        /// The `post_id` column of the `posts_tags` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        post_id -> Int4,
+        /// The `tag_id` column of the `posts_tags` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        tag_id -> Int4,
     }
 }
+
+diesel::table! {
+    /// Representation of the `series` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    series (id) {
+        /// The `id` column of the `series` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Int4,
+        /// The `title` column of the `series` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        title -> Varchar,
+    }
+}
+
+diesel::table! {
+    /// Representation of the `series_posts` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    series_posts (series_id, post_id) {
+        /// The `series_id` column of the `series_posts` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        series_id -> Int4,
+        /// The `post_id` column of the `series_posts` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        post_id -> Int4,
+        /// The `position` column of the `series_posts` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        position -> Int4,
+    }
+}
+
+diesel::table! {
+    /// Representation of the `tags` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    tags (id) {
+        /// The `id` column of the `tags` table.
+        ///
+        /// Its SQL type is `Int4`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Int4,
+        /// The `name` column of the `tags` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        name -> Varchar,
+    }
+}
+
+diesel::joinable!(post_revisions -> posts (post_id));
+diesel::joinable!(posts_tags -> posts (post_id));
+diesel::joinable!(posts_tags -> tags (tag_id));
+diesel::joinable!(series_posts -> posts (post_id));
+diesel::joinable!(series_posts -> series (series_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    media,
+    pages,
+    post_revisions,
+    posts,
+    posts_tags,
+    series,
+    series_posts,
+    tags,
+);
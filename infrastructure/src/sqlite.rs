@@ -0,0 +1,55 @@
+//! PostgreSQLの代わりにSQLiteのファイルに保存するリポジトリ
+//!
+//! `sqlite`フィーチャーを有効にしたときだけ使えます。各リポジトリはひとつのコネクションプールを共有します
+mod media_repository;
+mod pages_repository;
+mod post_query_repository;
+mod posts_repository;
+mod schema;
+mod search_index;
+mod series_repository;
+
+pub use media_repository::SqliteMediaRepository;
+pub use pages_repository::SqlitePagesRepository;
+pub use post_query_repository::SqlitePostQueryRepository;
+pub use posts_repository::SqlitePostsRepository;
+pub use search_index::SqliteSearchIndex;
+pub use series_repository::SqliteSeriesRepository;
+
+use anyhow::Context as _;
+use diesel::{
+    connection::SimpleConnection as _, r2d2::ConnectionManager, Connection as _, SqliteConnection,
+};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness as _};
+use r2d2::{CustomizeConnection, Pool};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations_sqlite/");
+
+pub type SqlitePool = Pool<ConnectionManager<SqliteConnection>>;
+
+/// `path`のデータベースへのコネクションプールを作ります
+pub fn connect(path: &str) -> anyhow::Result<SqlitePool> {
+    Pool::builder()
+        .connection_customizer(Box::new(PragmaCustomizer))
+        .build(ConnectionManager::<SqliteConnection>::new(path))
+        .context("Failed to build connection pool")
+}
+
+pub fn migrate(path: &str) -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::establish(path)?;
+    conn.run_pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!(e))
+        .context("Failed to run migrations")?;
+    Ok(())
+}
+
+/// 各コネクションで外部キー制約を有効にし、書き込みが重なったときには待つようにするためのCustomizer
+#[derive(Debug, Clone, Copy)]
+struct PragmaCustomizer;
+
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for PragmaCustomizer {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        conn.batch_execute("PRAGMA foreign_keys = ON; PRAGMA busy_timeout = 5000;")
+            .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
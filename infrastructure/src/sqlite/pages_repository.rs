@@ -0,0 +1,77 @@
+use super::SqlitePool;
+use crate::models::Page as PageModel;
+use anyhow::{Context, Result as AnyhowResult};
+use application::adapters::PagesRepository;
+use diesel::prelude::*;
+use diesel::r2d2::ConnectionManager;
+use domain::entities::{Page, PageName};
+use r2d2::PooledConnection;
+
+#[derive(Clone)]
+pub struct SqlitePagesRepository {
+    conn_pool: SqlitePool,
+}
+
+impl SqlitePagesRepository {
+    pub fn new(conn_pool: SqlitePool) -> SqlitePagesRepository {
+        SqlitePagesRepository { conn_pool }
+    }
+
+    fn get_conn(&self) -> AnyhowResult<PooledConnection<ConnectionManager<SqliteConnection>>> {
+        self.conn_pool.get().context("Failed to get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl PagesRepository for SqlitePagesRepository {
+    async fn get(&self, name: &PageName) -> anyhow::Result<Option<Page>> {
+        use super::schema::pages::dsl::pages;
+        let page = pages
+            .find(name.as_str())
+            .get_result::<PageModel>(&mut self.get_conn()?)
+            .optional()
+            .context("Failed to get result")?;
+        Ok(page.map(Page::try_from).transpose()?)
+    }
+
+    async fn get_all(&self) -> anyhow::Result<Vec<Page>> {
+        use super::schema::pages::dsl::{name, pages};
+        let results = pages
+            .order_by(name.asc())
+            .load::<PageModel>(&mut self.get_conn()?)
+            .context("Failed to get results")?;
+        Ok(results
+            .into_iter()
+            .map(Page::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    async fn save(&self, page: &Page) -> anyhow::Result<Page> {
+        use super::schema::pages::{self, body, name, title, updated_at};
+        let page = diesel::insert_into(pages::table)
+            .values((
+                name.eq(page.name.as_str()),
+                title.eq(&page.title),
+                body.eq(&page.body),
+                updated_at.eq(page.updated_at),
+            ))
+            .on_conflict(name)
+            .do_update()
+            .set((
+                title.eq(&page.title),
+                body.eq(&page.body),
+                updated_at.eq(page.updated_at),
+            ))
+            .get_result::<PageModel>(&mut self.get_conn()?)
+            .context("Failed to save page")?;
+        Ok(page.try_into()?)
+    }
+
+    async fn remove(&self, name: &PageName) -> anyhow::Result<()> {
+        use super::schema::pages::dsl::pages;
+        diesel::delete(pages.find(name.as_str()))
+            .execute(&mut self.get_conn()?)
+            .context("Failed to remove page")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use diesel::{
+    dsl::sql,
+    expression::SqlLiteral,
+    sql_types::{Date, Integer},
+    sqlite::Sqlite,
+};
+
+use super::SqlitePool;
+use crate::diesel_helpers::{local_date_part, DatePart};
+use crate::diesel_repositories::impl_post_query_repository;
+
+/// SQLiteのデータベースからPostを条件で探します
+#[derive(Clone)]
+pub struct SqlitePostQueryRepository {
+    conn_pool: SqlitePool,
+}
+
+impl SqlitePostQueryRepository {
+    pub fn new(conn_pool: SqlitePool) -> SqlitePostQueryRepository {
+        SqlitePostQueryRepository { conn_pool }
+    }
+
+    /// `created_at`の年、月、日のいずれかをローカルのタイムゾーンで取り出します
+    fn date_part(part: DatePart) -> SqlLiteral<Integer> {
+        local_date_part(part, "created_at")
+    }
+
+    /// `created_at`のローカルのタイムゾーンでの日付
+    fn local_date() -> SqlLiteral<Date> {
+        sql("date(created_at, 'localtime')")
+    }
+}
+
+impl_post_query_repository!(SqlitePostQueryRepository, Sqlite, super::schema);
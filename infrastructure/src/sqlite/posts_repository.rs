@@ -0,0 +1,51 @@
+use super::SqlitePool;
+use crate::diesel_repositories::impl_posts_repository;
+use anyhow::{Context, Result as AnyhowResult};
+use diesel::prelude::*;
+use domain::entities::Tag;
+
+#[derive(Clone)]
+pub struct SqlitePostsRepository {
+    pub(crate) conn_pool: SqlitePool,
+}
+
+impl SqlitePostsRepository {
+    pub fn new(conn_pool: SqlitePool) -> SqlitePostsRepository {
+        SqlitePostsRepository { conn_pool }
+    }
+
+    /// Postにタグをつけます。すでについているタグは無視します
+    fn insert_tags(conn: &mut SqliteConnection, post_id: i32, tags: &[Tag]) -> AnyhowResult<()> {
+        use super::schema::{posts_tags, tags};
+        if tags.is_empty() {
+            return Ok(());
+        }
+        let names = tags.iter().map(Tag::name).collect::<Vec<_>>();
+        // SQLiteではON CONFLICTつきで複数行をまとめて挿入できないので、1行ずつ挿入する
+        for name in &names {
+            diesel::insert_into(tags::table)
+                .values(tags::name.eq(name))
+                .on_conflict_do_nothing()
+                .execute(conn)
+                .context("Failed to insert tags")?;
+        }
+        let tag_ids = tags::table
+            .filter(tags::name.eq_any(&names))
+            .select(tags::id)
+            .get_results::<i32>(conn)
+            .context("Failed to get tag ids")?;
+        for tag_id in tag_ids {
+            diesel::insert_into(posts_tags::table)
+                .values((
+                    posts_tags::post_id.eq(post_id),
+                    posts_tags::tag_id.eq(tag_id),
+                ))
+                .on_conflict_do_nothing()
+                .execute(conn)
+                .context("Failed to attach tags")?;
+        }
+        Ok(())
+    }
+}
+
+impl_posts_repository!(SqlitePostsRepository, SqliteConnection, super::schema);
@@ -0,0 +1,115 @@
+// SQLiteのマイグレイションに合わせたスキーマ。日時は`TimestamptzSqlite`として読み書きする
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    media (id) {
+        id -> Integer,
+        name -> Text,
+        original_name -> Text,
+        content_type -> Text,
+        size -> BigInt,
+        width -> Nullable<Integer>,
+        height -> Nullable<Integer>,
+        created_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    pages (name) {
+        name -> Text,
+        title -> Text,
+        body -> Text,
+        updated_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    post_revisions (id) {
+        id -> Integer,
+        post_id -> Integer,
+        title -> Text,
+        body -> Text,
+        created_at -> TimestamptzSqlite,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    posts (id) {
+        id -> Integer,
+        title -> Text,
+        body -> Text,
+        created_at -> TimestamptzSqlite,
+        updated_at -> TimestamptzSqlite,
+        is_draft -> Bool,
+        published_at -> TimestamptzSqlite,
+        deleted_at -> Nullable<TimestamptzSqlite>,
+        version -> Integer,
+        slug -> Nullable<Text>,
+        summary -> Nullable<Text>,
+        visibility -> Text,
+        pinned_at -> Nullable<TimestamptzSqlite>,
+        char_count -> Nullable<Integer>,
+        reading_minutes -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    posts_tags (post_id, tag_id) {
+        post_id -> Integer,
+        tag_id -> Integer,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    series (id) {
+        id -> Integer,
+        title -> Text,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    series_posts (series_id, post_id) {
+        series_id -> Integer,
+        post_id -> Integer,
+        position -> Integer,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    tags (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+diesel::joinable!(post_revisions -> posts (post_id));
+diesel::joinable!(posts_tags -> posts (post_id));
+diesel::joinable!(posts_tags -> tags (tag_id));
+diesel::joinable!(series_posts -> posts (post_id));
+diesel::joinable!(series_posts -> series (series_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    media,
+    pages,
+    post_revisions,
+    posts,
+    posts_tags,
+    series,
+    series_posts,
+    tags,
+);
@@ -0,0 +1,58 @@
+use super::SqlitePool;
+use anyhow::Context as _;
+use diesel::prelude::*;
+use domain::entities::{Post, PostId};
+
+/// SQLiteのデータベースをそのままキーワード検索します
+///
+/// `LIKE`による部分一致で、ASCIIの大文字と小文字は区別しません
+#[derive(Clone)]
+pub struct SqliteSearchIndex {
+    conn_pool: SqlitePool,
+}
+
+impl SqliteSearchIndex {
+    pub fn new(conn_pool: SqlitePool) -> SqliteSearchIndex {
+        SqliteSearchIndex { conn_pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl application::adapters::SearchIndex for SqliteSearchIndex {
+    async fn search<'a>(&self, keywords: &'a [&'a str]) -> anyhow::Result<Vec<PostId>> {
+        use super::schema::posts::dsl::{body, id, posts, title};
+        let mut query = posts.select(id).into_boxed();
+        for keyword in keywords {
+            // `LIKE`の特殊文字はそのまま探す
+            let escaped = keyword
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            let pattern = format!("%{}%", escaped);
+            query = query.filter(
+                title
+                    .like(pattern.clone())
+                    .escape('\\')
+                    .or(body.like(pattern).escape('\\')),
+            );
+        }
+        let mut conn = self.conn_pool.get().context("failed to get connection")?;
+        let ids = query
+            .get_results::<i32>(&mut conn)
+            .context("Failed to search posts")?
+            .into_iter()
+            .map(PostId)
+            .collect();
+        Ok(ids)
+    }
+
+    /// データベースをそのまま検索するので、何もしません
+    async fn save(&self, _post: &Post) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// データベースをそのまま検索するので、何もしません
+    async fn delete(&self, _id: &PostId) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
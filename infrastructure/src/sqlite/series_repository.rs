@@ -0,0 +1,133 @@
+use super::SqlitePool;
+use crate::diesel_helpers::map_post_already_in_series;
+use anyhow::{Context, Result as AnyhowResult};
+use application::adapters::SeriesRepository;
+use diesel::prelude::*;
+use diesel::r2d2::ConnectionManager;
+use domain::entities::{PostId, Series, SeriesId};
+use r2d2::PooledConnection;
+
+#[derive(Clone)]
+pub struct SqliteSeriesRepository {
+    conn_pool: SqlitePool,
+}
+
+impl SqliteSeriesRepository {
+    pub fn new(conn_pool: SqlitePool) -> SqliteSeriesRepository {
+        SqliteSeriesRepository { conn_pool }
+    }
+
+    fn get_conn(&self) -> AnyhowResult<PooledConnection<ConnectionManager<SqliteConnection>>> {
+        self.conn_pool.get().context("Failed to get connection")
+    }
+
+    /// Seriesに入っている記事のIDを並び順で取得します
+    fn with_post_ids(
+        conn: &mut SqliteConnection,
+        (id, title): (i32, String),
+    ) -> AnyhowResult<Series> {
+        use super::schema::series_posts::{position, post_id, series_id, table};
+        let post_ids = table
+            .filter(series_id.eq(id))
+            .order_by(position.asc())
+            .select(post_id)
+            .load::<i32>(conn)
+            .context("Failed to get posts in series")?;
+        Ok(Series::new(
+            SeriesId(id),
+            title,
+            post_ids.into_iter().map(PostId).collect(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SeriesRepository for SqliteSeriesRepository {
+    async fn get(&self, id: &SeriesId) -> anyhow::Result<Option<Series>> {
+        use super::schema::series::dsl::series;
+        let mut conn = self.get_conn()?;
+        let row = series
+            .find(id.0)
+            .get_result::<(i32, String)>(&mut conn)
+            .optional()
+            .context("Failed to get result")?;
+        row.map(|row| Self::with_post_ids(&mut conn, row))
+            .transpose()
+    }
+
+    async fn get_all(&self) -> anyhow::Result<Vec<Series>> {
+        use super::schema::series::dsl::{id, series};
+        let mut conn = self.get_conn()?;
+        let rows = series
+            .order_by(id.asc())
+            .load::<(i32, String)>(&mut conn)
+            .context("Failed to get results")?;
+        rows.into_iter()
+            .map(|row| Self::with_post_ids(&mut conn, row))
+            .collect()
+    }
+
+    async fn get_by_post_id(&self, post_id: &PostId) -> anyhow::Result<Option<Series>> {
+        use super::schema::{series, series_posts};
+        let mut conn = self.get_conn()?;
+        let row = series::table
+            .inner_join(series_posts::table)
+            .filter(series_posts::post_id.eq(post_id.0))
+            .select((series::id, series::title))
+            .get_result::<(i32, String)>(&mut conn)
+            .optional()
+            .context("Failed to get result")?;
+        row.map(|row| Self::with_post_ids(&mut conn, row))
+            .transpose()
+    }
+
+    async fn add(&self, title: &str) -> anyhow::Result<Series> {
+        use super::schema::series::{self, id, title as title_column};
+        let new_id = diesel::insert_into(series::table)
+            .values(title_column.eq(title))
+            .returning(id)
+            .get_result::<i32>(&mut self.get_conn()?)
+            .context("Failed to add series")?;
+        Ok(Series::new(SeriesId(new_id), title, vec![]))
+    }
+
+    async fn save(&self, series: &Series) -> anyhow::Result<Series> {
+        use super::schema::{series as series_table, series_posts};
+        self.get_conn()?.transaction(|conn| {
+            diesel::update(series_table::table.find(series.id.0))
+                .set(series_table::title.eq(&series.title))
+                .execute(conn)
+                .context("Failed to update series")?;
+            // 並び順は入れ直す
+            diesel::delete(series_posts::table.filter(series_posts::series_id.eq(series.id.0)))
+                .execute(conn)
+                .context("Failed to clear posts in series")?;
+            let records = series
+                .post_ids
+                .iter()
+                .enumerate()
+                .map(|(index, post_id)| {
+                    (
+                        series_posts::series_id.eq(series.id.0),
+                        series_posts::post_id.eq(post_id.0),
+                        series_posts::position.eq(index as i32),
+                    )
+                })
+                .collect::<Vec<_>>();
+            diesel::insert_into(series_posts::table)
+                .values(&records)
+                .execute(conn)
+                .map_err(map_post_already_in_series)?;
+            anyhow::Ok(())
+        })?;
+        Ok(series.clone())
+    }
+
+    async fn remove(&self, id: &SeriesId) -> anyhow::Result<()> {
+        use super::schema::series::dsl::series;
+        diesel::delete(series.find(id.0))
+            .execute(&mut self.get_conn()?)
+            .context("Failed to remove series")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,392 @@
+//! Elasticsearchの代わりに、ファイルに保存するTantivyの索引でキーワード検索する
+//!
+//! `tantivy`フィーチャーを有効にしたときだけ使えます。索引にはPostのIDとタイトル、本文だけを入れ、
+//! 見せてよい記事やタグでの絞り込み、並べ替えはPostgreSQLで行います
+use anyhow::Context as _;
+use diesel::prelude::*;
+use domain::entities::{Post, PostId};
+use lindera::{
+    dictionary::load_fs_dictionary,
+    mode::{Mode, Penalty},
+    segmenter::Segmenter,
+};
+use std::{
+    borrow::Cow,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard},
+};
+use tantivy::{
+    collector::DocSetCollector,
+    directory::MmapDirectory,
+    query::{AllQuery, BooleanQuery, Occur, PhraseQuery, Query, TermQuery},
+    schema::{
+        Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, Value as _, INDEXED,
+        STORED,
+    },
+    tokenizer::{PreTokenizedStream, PreTokenizedString, TextAnalyzer, Token, Tokenizer},
+    Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term,
+};
+use unicode_normalization::UnicodeNormalization as _;
+
+use crate::diesel_helpers::get_conn;
+use crate::posts_repository_impl::PostsRepositoryImpl;
+
+#[derive(Clone)]
+pub struct TantivyIndex {
+    index: Index,
+    reader: IndexReader,
+    writer: Arc<Mutex<IndexWriter>>,
+    fields: Fields,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Fields {
+    id: Field,
+    /// 形態素解析した`title`。Elasticsearchの`title`にあたります
+    title: Field,
+    /// 形態素解析した`body`。Elasticsearchの`body`にあたります
+    body: Field,
+    /// bigramに区切った`title`。Elasticsearchの`title.bigram`にあたります
+    title_bigram: Field,
+    /// bigramに区切った`body`。Elasticsearchの`body.bigram`にあたります
+    body_bigram: Field,
+}
+
+impl TantivyIndex {
+    const BIGRAM_TOKENIZER: &'static str = "bigram";
+    const MORPHOLOGICAL_TOKENIZER: &'static str = "morphological";
+    const WRITER_MEMORY_BUDGET: usize = 50_000_000;
+    const REBUILD_BATCH_SIZE: i64 = 500;
+
+    /// `path`のディレクトリの索引を開きます。なければ作ります
+    ///
+    /// `dictionary_path`にはLinderaでビルドした辞書のディレクトリを指定します。
+    /// 省略すると形態素解析の代わりにbigramで区切ります
+    pub fn open(path: &Path, dictionary_path: Option<&Path>) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(path)
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+        let (schema, fields) = Self::schema();
+        let directory = MmapDirectory::open(path).context("Failed to open index directory")?;
+        let index = Index::open_or_create(directory, schema).context("Failed to open index")?;
+
+        let morphological = match dictionary_path {
+            Some(dictionary_path) => {
+                TextAnalyzer::from(MorphologicalTokenizer::new(dictionary_path)?)
+            }
+            None => TextAnalyzer::from(BigramTokenizer),
+        };
+        index
+            .tokenizers()
+            .register(Self::BIGRAM_TOKENIZER, BigramTokenizer);
+        index
+            .tokenizers()
+            .register(Self::MORPHOLOGICAL_TOKENIZER, morphological);
+
+        // 書き込んだ直後の検索に反映させるため、コミットしたら自分で読み込み直す
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .context("Failed to create index reader")?;
+        let writer = index
+            .writer(Self::WRITER_MEMORY_BUDGET)
+            .context("Failed to create index writer")?;
+        Ok(Self {
+            index,
+            reader,
+            writer: Arc::new(Mutex::new(writer)),
+            fields,
+        })
+    }
+
+    fn schema() -> (Schema, Fields) {
+        let text_options = |tokenizer| {
+            TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(tokenizer)
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            )
+        };
+        let mut builder = Schema::builder();
+        let fields = Fields {
+            id: builder.add_i64_field("id", INDEXED | STORED),
+            title: builder.add_text_field("title", text_options(Self::MORPHOLOGICAL_TOKENIZER)),
+            body: builder.add_text_field("body", text_options(Self::MORPHOLOGICAL_TOKENIZER)),
+            title_bigram: builder
+                .add_text_field("title_bigram", text_options(Self::BIGRAM_TOKENIZER)),
+            body_bigram: builder
+                .add_text_field("body_bigram", text_options(Self::BIGRAM_TOKENIZER)),
+        };
+        (builder.build(), fields)
+    }
+
+    /// 索引にひとつもPostがなければ`true`を返します
+    pub fn is_empty(&self) -> bool {
+        self.reader.searcher().num_docs() == 0
+    }
+
+    /// 索引が空なら、PostgreSQLの記事で作ります
+    ///
+    /// 記事は`REBUILD_BATCH_SIZE`件ずつIDの順に読み込みます
+    pub fn rebuild_if_empty(&self, posts: &PostsRepositoryImpl) -> anyhow::Result<()> {
+        use crate::schema::posts::{
+            self,
+            dsl::{body, id, title},
+        };
+        if !self.is_empty() {
+            return Ok(());
+        }
+        let mut conn = get_conn(&posts.conn_pool)?;
+        let mut writer = self.lock_writer()?;
+        writer.delete_all_documents()?;
+        let mut last_id = None;
+        loop {
+            let mut query = posts::table
+                .select((id, title, body))
+                .order(id.asc())
+                .limit(Self::REBUILD_BATCH_SIZE)
+                .into_boxed();
+            if let Some(last_id) = last_id {
+                query = query.filter(id.gt(last_id));
+            }
+            let rows = query
+                .load::<(i32, String, String)>(&mut conn)
+                .context("Failed to get posts")?;
+            let Some(&(last, _, _)) = rows.last() else {
+                break;
+            };
+            for (post_id, post_title, post_body) in &rows {
+                writer.add_document(self.document(PostId(*post_id), post_title, post_body))?;
+            }
+            last_id = Some(last);
+        }
+        self.commit(&mut writer)
+    }
+
+    /// `keyword`を`field`と同じように区切り、その並びで現れる記事を探すクエリを返します
+    ///
+    /// 区切った結果が空なら`None`を返します
+    fn phrase_query(&self, field: Field, keyword: &str) -> anyhow::Result<Option<Box<dyn Query>>> {
+        let mut analyzer = self
+            .index
+            .tokenizer_for_field(field)
+            .context("Failed to get tokenizer")?;
+        let mut terms = Vec::new();
+        analyzer.token_stream(keyword).process(&mut |token| {
+            terms.push((token.position, Term::from_field_text(field, &token.text)));
+        });
+        let query: Box<dyn Query> = match terms.len() {
+            0 => return Ok(None),
+            1 => Box::new(TermQuery::new(terms.remove(0).1, IndexRecordOption::Basic)),
+            _ => Box::new(PhraseQuery::new_with_offset(terms)),
+        };
+        Ok(Some(query))
+    }
+
+    fn document(&self, id: PostId, title: &str, body: &str) -> TantivyDocument {
+        let mut document = TantivyDocument::default();
+        document.add_i64(self.fields.id, id.0 as i64);
+        document.add_text(self.fields.title, title);
+        document.add_text(self.fields.body, body);
+        document.add_text(self.fields.title_bigram, title);
+        document.add_text(self.fields.body_bigram, body);
+        document
+    }
+
+    fn lock_writer(&self) -> anyhow::Result<MutexGuard<'_, IndexWriter>> {
+        self.writer
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Index writer is poisoned"))
+    }
+
+    fn commit(&self, writer: &mut IndexWriter) -> anyhow::Result<()> {
+        writer.commit().context("Failed to commit index")?;
+        self.reader.reload().context("Failed to reload index")?;
+        Ok(())
+    }
+
+    /// 索引の読み書きはブロックするので、`f`を別のスレッドで実行します
+    async fn run_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&Self) -> anyhow::Result<T> + Send + 'static,
+    ) -> anyhow::Result<T> {
+        let index = self.clone();
+        tokio::task::spawn_blocking(move || f(&index))
+            .await
+            .context("Index task failed")?
+    }
+
+    fn search_blocking(&self, keywords: &[String]) -> anyhow::Result<Vec<PostId>> {
+        let query: Box<dyn Query> = if keywords.is_empty() {
+            Box::new(AllQuery)
+        } else {
+            let mut clauses = Vec::new();
+            for keyword in keywords {
+                // bigramのマッチはMUST、形態素解析のマッチはSHOULD。Elasticsearchのクエリにそろえている
+                let title = self.phrase_query(self.fields.title_bigram, keyword)?;
+                let body = self.phrase_query(self.fields.body_bigram, keyword)?;
+                let (Some(title), Some(body)) = (title, body) else {
+                    // 記号だけのキーワードには何もマッチしない
+                    return Ok(vec![]);
+                };
+                let bigram = BooleanQuery::new(vec![(Occur::Should, title), (Occur::Should, body)]);
+                clauses.push((Occur::Must, Box::new(bigram) as Box<dyn Query>));
+                for field in [self.fields.title, self.fields.body] {
+                    if let Some(query) = self.phrase_query(field, keyword)? {
+                        clauses.push((Occur::Should, query));
+                    }
+                }
+            }
+            Box::new(BooleanQuery::new(clauses))
+        };
+
+        let searcher = self.reader.searcher();
+        let addresses = searcher
+            .search(&query, &DocSetCollector)
+            .context("Search failed")?;
+        addresses
+            .into_iter()
+            .map(|address| {
+                let document = searcher
+                    .doc::<TantivyDocument>(address)
+                    .context("Failed to get document")?;
+                let id = document
+                    .get_first(self.fields.id)
+                    .and_then(|value| value.as_i64())
+                    .context("Document does not contain `id`")?;
+                Ok(PostId(id as i32))
+            })
+            .collect()
+    }
+
+    fn save_blocking(&self, id: PostId, title: &str, body: &str) -> anyhow::Result<()> {
+        let mut writer = self.lock_writer()?;
+        writer.delete_term(Term::from_field_i64(self.fields.id, id.0 as i64));
+        writer.add_document(self.document(id, title, body))?;
+        self.commit(&mut writer)
+    }
+
+    fn delete_blocking(&self, id: PostId) -> anyhow::Result<()> {
+        let mut writer = self.lock_writer()?;
+        writer.delete_term(Term::from_field_i64(self.fields.id, id.0 as i64));
+        self.commit(&mut writer)
+    }
+}
+
+#[async_trait::async_trait]
+impl application::adapters::SearchIndex for TantivyIndex {
+    /// `keywords`をすべて含むPostのIDを返します。順番は決まっていません
+    async fn search<'a>(&self, keywords: &'a [&'a str]) -> anyhow::Result<Vec<PostId>> {
+        let keywords = keywords
+            .iter()
+            .map(|keyword| keyword.to_string())
+            .collect::<Vec<_>>();
+        self.run_blocking(move |index| index.search_blocking(&keywords))
+            .await
+    }
+
+    /// Postを索引に入れます。すでにあれば置き換えます
+    async fn save(&self, post: &Post) -> anyhow::Result<()> {
+        let (id, title, body) = (post.id, post.title.clone(), post.body.clone());
+        self.run_blocking(move |index| index.save_blocking(id, &title, &body))
+            .await
+    }
+
+    async fn delete(&self, id: &PostId) -> anyhow::Result<()> {
+        let id = *id;
+        self.run_blocking(move |index| index.delete_blocking(id))
+            .await
+    }
+}
+
+/// NFKCで正規化して小文字にし、ひらがなをカタカナにそろえます
+///
+/// Elasticsearchの`normalize`、`lowercase`、`kana_filter`にあたります
+fn normalize(text: &str) -> String {
+    text.nfkc()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            '\u{3041}'..='\u{3096}' | '\u{309D}' | '\u{309E}' => {
+                char::from_u32(c as u32 + 0x60).unwrap_or(c)
+            }
+            _ => c,
+        })
+        .collect()
+}
+
+/// 文字と数字の並びを1文字と2文字ずつに区切ります。Elasticsearchの`bigram_analyzer`にあたります
+#[derive(Debug, Clone, Copy)]
+struct BigramTokenizer;
+
+impl Tokenizer for BigramTokenizer {
+    type TokenStream<'a> = PreTokenizedStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let text = normalize(text);
+        let chars = text.char_indices().collect::<Vec<_>>();
+        let mut tokens = Vec::new();
+        for (i, &(offset_from, _)) in chars.iter().enumerate() {
+            for (last, c) in chars[i..].iter().take(2) {
+                if !c.is_alphanumeric() {
+                    break;
+                }
+                let offset_to = last + c.len_utf8();
+                tokens.push(Token {
+                    offset_from,
+                    offset_to,
+                    position: tokens.len(),
+                    text: text[offset_from..offset_to].to_string(),
+                    position_length: 1,
+                });
+            }
+        }
+        PreTokenizedStream::from(PreTokenizedString { text, tokens })
+    }
+}
+
+/// Linderaで形態素解析して区切ります。Elasticsearchの`kuromoji_analyzer`にあたります
+#[derive(Clone)]
+struct MorphologicalTokenizer {
+    segmenter: Arc<Segmenter>,
+}
+
+impl MorphologicalTokenizer {
+    fn new(dictionary_path: &Path) -> anyhow::Result<Self> {
+        let dictionary = load_fs_dictionary(dictionary_path)
+            .map_err(|e| anyhow::anyhow!("{e}"))
+            .with_context(|| format!("Failed to load {}", dictionary_path.display()))?;
+        // kuromojiの`search`モードと同じく、長い複合語は分解する
+        let segmenter = Segmenter::new(Mode::Decompose(Penalty::default()), dictionary, None);
+        Ok(Self {
+            segmenter: Arc::new(segmenter),
+        })
+    }
+}
+
+impl Tokenizer for MorphologicalTokenizer {
+    type TokenStream<'a> = PreTokenizedStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let text = normalize(text);
+        let tokens = match self.segmenter.segment(Cow::Borrowed(&text)) {
+            Ok(tokens) => tokens
+                .into_iter()
+                // 記号は捨てる
+                .filter(|token| token.surface.chars().any(char::is_alphanumeric))
+                .enumerate()
+                .map(|(position, token)| Token {
+                    offset_from: token.byte_start,
+                    offset_to: token.byte_end,
+                    position,
+                    text: token.surface.into_owned(),
+                    position_length: 1,
+                })
+                .collect(),
+            Err(e) => {
+                log::warn!("failed to segment text: {e}");
+                vec![]
+            }
+        };
+        PreTokenizedStream::from(PreTokenizedString { text, tokens })
+    }
+}
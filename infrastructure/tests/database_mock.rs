@@ -17,7 +17,14 @@ impl DatabaseMock {
     fn new(pg_base_url: url::Url, db_name: impl Into<String>) -> Result<Self> {
         let mut conn = PgConnection::establish(pg_base_url.join("postgres")?.as_str())?;
         let db_name = db_name.into();
-        let query = diesel::sql_query(format!(r#"CREATE DATABASE "{}""#, &db_name).as_str());
+        // 検索のための正規化はUTF8でないとできない
+        let query = diesel::sql_query(
+            format!(
+                r#"CREATE DATABASE "{}" ENCODING 'UTF8' TEMPLATE template0"#,
+                &db_name
+            )
+            .as_str(),
+        );
         query.execute(&mut conn)?;
 
         let pg_url = pg_base_url.join(&db_name)?;
@@ -64,6 +71,8 @@ pub fn mock_db() -> Result<DatabaseMock> {
     DatabaseMock::new(url::Url::parse(&env::var("POSTGRES_URL")?)?, db_name)
 }
 
+// テストファイルによっては使わない
+#[allow(dead_code)]
 pub fn mock_data() -> Vec<Post> {
     (1..=6)
         .flat_map(|m| {
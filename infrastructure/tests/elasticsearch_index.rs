@@ -0,0 +1,24 @@
+use std::env;
+
+use anyhow::Result;
+use application::adapters::SearchIndex as _;
+use chrono::Utc;
+use domain::entities::*;
+use infrastructure::elasticsearch_index::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn save_and_search() -> Result<()> {
+    let es_url = url::Url::parse(&env::var("ES_URL")?)?;
+    let index = ElasticsearchIndex::with_index_name(&es_url, "test_save_and_search")?;
+    let now = Utc::now();
+    let hello = Post::new(PostId(1), "", "hello", now, now);
+    let goodbye = Post::new(PostId(2), "", "hello, goodbye", now, now);
+    index.save(&hello).await?;
+    index.save(&goodbye).await?;
+    index.delete(&goodbye.id).await?;
+
+    let post_ids = index.search(&["hello"]).await?;
+    assert_eq!(post_ids, vec![hello.id]);
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use anyhow::Result;
+use application::adapters::ImageProcessor as _;
+use image::{ImageFormat, RgbImage};
+use infrastructure::image_processor_impl::*;
+use pretty_assertions::assert_eq;
+use std::io::Cursor;
+
+#[tokio::test]
+async fn resize_png() -> Result<()> {
+    let mut png = vec![];
+    RgbImage::new(400, 300).write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+    let processor = ImageProcessorImpl;
+    assert_eq!(processor.dimensions(&png), Some((400, 300)));
+
+    let resized = processor.resize(&png, 320, 240).await?;
+    assert_eq!(image::guess_format(&resized)?, ImageFormat::Png);
+    assert_eq!(processor.dimensions(&resized), Some((320, 240)));
+    // 画像でなければ大きさはわからない
+    assert_eq!(processor.dimensions(b"not an image"), None);
+    Ok(())
+}
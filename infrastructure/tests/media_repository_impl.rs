@@ -0,0 +1,68 @@
+use anyhow::Result;
+use application::adapters::{MediaRepository as _, MediaRepositoryError, MediaStorage as _};
+use chrono::{Duration, Utc};
+use domain::entities::*;
+use infrastructure::{local_media_storage::*, media_repository_impl::*, postgres::connect};
+use pretty_assertions::assert_eq;
+mod database_mock;
+use database_mock::*;
+
+#[tokio::test]
+async fn add_and_get() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = MediaRepositoryImpl::new(connect(pg_url)?);
+    let now = Utc::now();
+    let photo = repo
+        .add(NewMedia::new(
+            "photo.jpg",
+            "image/jpeg",
+            1024,
+            Some((4000, 3000)),
+            now - Duration::hours(1),
+        ))
+        .await?;
+    let memo = repo
+        .add(NewMedia::new("memo.txt", "text/plain", 10, None, now))
+        .await?;
+
+    let media = repo.get(&photo.id).await?.expect("media not found");
+    assert_eq!(media.name, photo.name);
+    assert_eq!(media.dimensions, Some((4000, 3000)));
+    let ids = repo
+        .get_all()
+        .await?
+        .into_iter()
+        .map(|media| media.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![memo.id, photo.id]);
+
+    // 同じ名前では追加できない
+    let error = repo
+        .add(NewMedia::new("memo.txt", "text/plain", 10, None, now))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&MediaRepositoryError::DuplicateName)
+    );
+
+    repo.remove(&photo.id).await?;
+    assert!(repo.get(&photo.id).await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_and_delete_file() -> Result<()> {
+    let root = std::env::temp_dir().join(uuid::Uuid::new_v4().simple().to_string());
+    let storage = LocalMediaStorage::new(&root)?;
+    storage.put("a.txt", b"hello").await?;
+    assert_eq!(std::fs::read(root.join("a.txt"))?, b"hello");
+    storage.delete("a.txt").await?;
+    assert!(!root.join("a.txt").exists());
+    // なければ何もしない
+    storage.delete("a.txt").await?;
+    // ディレクトリの外には書き込まない
+    assert!(storage.put("../a.txt", b"hello").await.is_err());
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+}
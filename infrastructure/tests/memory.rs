@@ -0,0 +1,185 @@
+use anyhow::Result;
+use application::{
+    adapters::{
+        PostQueryRepository as _, PostsRepository as _, PostsRepositoryError, SearchIndex as _,
+    },
+    models::Audience,
+};
+use chrono::{Duration, Local, TimeZone as _, Utc};
+use domain::entities::*;
+use infrastructure::memory::*;
+use pretty_assertions::assert_eq;
+
+const SEED: &str = r#"{
+    "posts": [
+        { "title": "Rust", "body": "Hello, World", "created_at": "2020-01-10T12:00:00Z", "tags": ["code"] },
+        { "title": "Draft", "body": "hello draft", "status": "draft", "created_at": "2020-01-20T12:00:00Z" },
+        { "title": "Private", "body": "hello", "visibility": "private", "created_at": "2020-02-01T12:00:00Z" },
+        { "id": 10, "title": "Diary", "body": "hello again", "created_at": "2020-02-03T12:00:00Z", "slug": "diary" }
+    ],
+    "pages": [
+        { "name": "about", "title": "About", "body": "about me", "updated_at": "2020-01-01T00:00:00Z" }
+    ]
+}"#;
+
+#[tokio::test]
+async fn load_seed() -> Result<()> {
+    let store = MemoryStore::from_seed(SEED)?;
+    let posts = MemoryPostsRepository::new(store.clone());
+    let client = MemoryPostQueryRepository::new(store);
+
+    // IDを省略したPostには順に振り、指定したIDの後に続ける
+    let post_ids = client
+        .get_latest_posts(0, 10, Audience::Admin)
+        .await?
+        .post_ids;
+    assert_eq!(post_ids, vec![PostId(10), PostId(3), PostId(2), PostId(1)]);
+    let post = posts.get_by_slug(&Slug::new("diary")?).await?.unwrap();
+    assert_eq!(post.id, PostId(10));
+    assert_eq!(posts.get_revisions(&post.id).await?.len(), 1);
+
+    let post = posts.add(NewPost::new("New", "body", Utc::now())).await?;
+    assert_eq!(post.id, PostId(11));
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_invalid_seed() {
+    let seed = r#"{ "posts": [{ "title": "No date" }] }"#;
+    assert!(MemoryStore::from_seed(seed).is_err());
+    let seed = r#"{ "posts": [
+        { "slug": "same", "created_at": "2020-01-10T12:00:00Z" },
+        { "slug": "same", "created_at": "2020-01-11T12:00:00Z" }
+    ] }"#;
+    assert!(MemoryStore::from_seed(seed).is_err());
+}
+
+#[tokio::test]
+async fn save_and_revisions() -> Result<()> {
+    let posts = MemoryPostsRepository::new(MemoryStore::default());
+    let mut new_post = NewPost::new("Title", "Body", Utc::now());
+    new_post.tags = vec![Tag::new("b")?, Tag::new("a")?];
+    let mut post = posts.add(new_post).await?;
+    assert_eq!(post.tags, vec![Tag::new("a")?, Tag::new("b")?]);
+
+    post.body = "Updated".to_string();
+    let saved = posts.save(&post).await?;
+    assert_eq!(saved.version, 2);
+    assert_eq!(saved.tags, vec![Tag::new("a")?, Tag::new("b")?]);
+    let revisions = posts.get_revisions(&post.id).await?;
+    assert_eq!(
+        revisions
+            .iter()
+            .map(|r| r.body.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Updated", "Body"]
+    );
+
+    // 古いバージョンでは保存できない
+    let error = posts.save(&post).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&PostsRepositoryError::VersionConflict(post.id))
+    );
+
+    posts.remove(&post.id).await?;
+    assert!(posts.get_by_id(&post.id).await?.is_none());
+    assert!(posts.get_revisions(&post.id).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_and_find_by_ids_and_tags() -> Result<()> {
+    let store = MemoryStore::from_seed(SEED)?;
+    let index = MemorySearchIndex::new(store.clone());
+    let client = MemoryPostQueryRepository::new(store);
+
+    // 大文字と小文字は区別しない。見せてよいかどうかは索引では区別しない
+    let mut ids = index.search(&["hello"]).await?;
+    ids.sort();
+    assert_eq!(ids, vec![PostId(1), PostId(2), PostId(3), PostId(10)]);
+    let result = client
+        .find_by_ids_and_tags(Some(&ids), &[], 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![PostId(10), PostId(1)]);
+    assert_eq!(result.total_count, 2);
+    let result = client
+        .find_by_ids_and_tags(Some(&ids), &[], 0, 10, Audience::Admin)
+        .await?;
+    assert_eq!(result.total_count, 4);
+
+    let ids = index.search(&["hello", "world"]).await?;
+    let result = client
+        .find_by_ids_and_tags(Some(&ids), &[Tag::new("code")?], 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![PostId(1)]);
+    let result = client
+        .find_by_ids_and_tags(None, &[Tag::new("none")?], 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.total_count, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn calendars() -> Result<()> {
+    // ローカルのタイムゾーンの日付で数える
+    let store = MemoryStore::default();
+    let posts = MemoryPostsRepository::new(store.clone());
+    let client = MemoryPostQueryRepository::new(store);
+    for (month, day) in [(1, 31), (1, 31), (1, 2), (2, 1)] {
+        let created_at = Local
+            .with_ymd_and_hms(2021, month, day, 0, 30, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        posts.add(NewPost::new("", "", created_at)).await?;
+    }
+    let year_months = client.get_year_months(Audience::Public).await?;
+    assert_eq!(
+        year_months
+            .iter()
+            .map(|ym| (ym.year_month.year, ym.year_month.month, ym.count))
+            .collect::<Vec<_>>(),
+        vec![(2021, 1, 3), (2021, 2, 1)]
+    );
+    let year_month = year_months[0].year_month;
+    assert_eq!(
+        client
+            .get_days_in_year_month(&year_month, Audience::Public)
+            .await?,
+        vec![2, 31]
+    );
+    let result = client
+        .find_by_year_month(&year_month, 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![PostId(3), PostId(1), PostId(2)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_from_and_until_date() -> Result<()> {
+    let store = MemoryStore::default();
+    let posts = MemoryPostsRepository::new(store.clone());
+    let client = MemoryPostQueryRepository::new(store);
+    let base = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+    for hours in 0..5 {
+        posts
+            .add(NewPost::new("", "", base + Duration::hours(hours)))
+            .await?;
+    }
+
+    // `from`は含み、`until`は含まない
+    let from = base + Duration::hours(2);
+    assert_eq!(
+        client.get_from_date(from, 0, 10, Audience::Public).await?,
+        vec![PostId(3), PostId(4), PostId(5)]
+    );
+    assert_eq!(
+        client.get_from_date(from, 1, 1, Audience::Public).await?,
+        vec![PostId(4)]
+    );
+    assert_eq!(
+        client.get_until_date(from, 0, 10, Audience::Public).await?,
+        vec![PostId(2), PostId(1)]
+    );
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use application::adapters::PagesRepository as _;
+use chrono::Utc;
+use domain::entities::*;
+use infrastructure::{pages_repository_impl::*, postgres::connect};
+use pretty_assertions::assert_eq;
+mod database_mock;
+use database_mock::*;
+
+#[tokio::test]
+async fn save_and_get() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = PagesRepositoryImpl::new(connect(pg_url)?);
+    let name = PageName::new("colophon")?;
+    repo.save(&Page::new(name.clone(), "colophon", "1111", Utc::now()))
+        .await?;
+    // 同じ名前なら上書きする
+    repo.save(&Page::new(name.clone(), "colophon", "1112", Utc::now()))
+        .await?;
+
+    let page = repo.get(&name).await?.expect("page not found");
+    assert_eq!(page.body, "1112");
+    // aboutはマイグレイションで作られている
+    let names = repo
+        .get_all()
+        .await?
+        .into_iter()
+        .map(|page| page.name.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["about", "colophon"]);
+
+    repo.remove(&name).await?;
+    assert!(repo.get(&name).await?.is_none());
+    Ok(())
+}
@@ -0,0 +1,306 @@
+use anyhow::Result;
+use application::{adapters::PostQueryRepository as _, models::Audience};
+use domain::entities::*;
+use infrastructure::{post_query_repository_impl::*, postgres::connect, posts_repository_impl::*};
+use pretty_assertions::assert_eq;
+mod database_mock;
+use database_mock::*;
+
+#[tokio::test]
+async fn get_latest_posts() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mock_data = mock_data();
+    posts.import(&mock_data)?;
+
+    let post_ids = client
+        .get_latest_posts(0, 1000, Audience::Public)
+        .await?
+        .post_ids;
+    let expected_ids = (1..=6)
+        .rev()
+        .flat_map(|m| {
+            (1..=14).rev().flat_map(move |d| {
+                vec![PostId(m * 2 * 100 + d * 2 + 1), PostId(m * 2 * 100 + d * 2)]
+            })
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(post_ids, expected_ids);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_latest_posts_with_visibility() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(3);
+    mock_data[1].visibility = PostVisibility::Unlisted;
+    mock_data[2].visibility = PostVisibility::Private;
+    posts.import(&mock_data)?;
+
+    // 一覧には公開の記事だけを載せ、管理者にはすべて見せる
+    let post_ids = client
+        .get_latest_posts(0, 10, Audience::Public)
+        .await?
+        .post_ids;
+    assert_eq!(post_ids, vec![mock_data[0].id]);
+    let post_ids = client
+        .get_latest_posts(0, 10, Audience::Admin)
+        .await?
+        .post_ids;
+    assert_eq!(
+        post_ids,
+        vec![mock_data[2].id, mock_data[1].id, mock_data[0].id]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_pinned() -> Result<()> {
+    use application::adapters::PostsRepository as _;
+    use chrono::Duration;
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(3);
+    mock_data[2].visibility = PostVisibility::Private;
+    posts.import(&mock_data)?;
+    let now = chrono::Utc::now();
+    posts.set_pinned_at(&mock_data[0].id, Some(now)).await?;
+    posts
+        .set_pinned_at(&mock_data[1].id, Some(now + Duration::seconds(1)))
+        .await?;
+    posts.set_pinned_at(&mock_data[2].id, Some(now)).await?;
+
+    // あとから固定したものが先に来て、見せない記事は含まない
+    let post_ids = client.get_pinned(Audience::Public).await?;
+    assert_eq!(post_ids, vec![mock_data[1].id, mock_data[0].id]);
+
+    posts.set_pinned_at(&mock_data[1].id, None).await?;
+    let post_ids = client.get_pinned(Audience::Public).await?;
+    assert_eq!(post_ids, vec![mock_data[0].id]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_monthly_stats() -> Result<()> {
+    use application::adapters::PostsRepository as _;
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(3);
+    posts.import(&mock_data)?;
+    assert_eq!(client.get_without_stats().await?.len(), 3);
+    for post in &mock_data {
+        posts.set_stats(&post.id, &PostStats::new(600)).await?;
+    }
+    assert!(client.get_without_stats().await?.is_empty());
+
+    let months = client.get_monthly_stats(Audience::Public).await?;
+    assert_eq!(months.iter().map(|m| m.post_count).sum::<usize>(), 3);
+    assert_eq!(months.iter().map(|m| m.char_count).sum::<usize>(), 1800);
+    assert_eq!(months.iter().map(|m| m.reading_minutes).sum::<usize>(), 6);
+    assert!(months.windows(2).all(|w| w[0].year_month < w[1].year_month));
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_dates_and_rankings() -> Result<()> {
+    use application::adapters::PostsRepository as _;
+    use chrono::NaiveDate;
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(4);
+    posts.import(&mock_data)?;
+
+    // 同じ日の記事はまとめて1日と数える
+    let dates = client.get_dates(Audience::Admin).await?;
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2020, 2, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2020, 2, 3).unwrap(),
+        ]
+    );
+
+    for (post, chars) in mock_data.iter().zip([300, 100, 400, 200]) {
+        posts.set_stats(&post.id, &PostStats::new(chars)).await?;
+    }
+    let longest = client.get_longest(2, Audience::Admin).await?;
+    assert_eq!(longest, vec![mock_data[2].id, mock_data[0].id]);
+    let shortest = client.get_shortest(2, Audience::Admin).await?;
+    assert_eq!(shortest, vec![mock_data[1].id, mock_data[3].id]);
+
+    let mut post = posts
+        .add(NewPost::new("title", "body", chrono::Utc::now()))
+        .await?;
+    post.body = "body 2".to_owned();
+    posts.save(&post).await?;
+    let most_revised = client.get_most_revised(1, Audience::Admin).await?;
+    assert_eq!(most_revised, vec![(post.id, 2)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_by_month_day() -> Result<()> {
+    use application::models::MonthDay;
+    use chrono::{Local, TimeZone as _, Utc};
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(4);
+    mock_data[2].created_at = Local
+        .with_ymd_and_hms(2019, 2, 1, 12, 0, 0)
+        .unwrap()
+        .with_timezone(&Utc);
+    posts.import(&mock_data)?;
+
+    // 年をまたいで同じ月日の記事を新しい順に返す
+    let result = client
+        .find_by_month_day(&MonthDay::new(2, 1)?, 0, 2, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![mock_data[1].id, mock_data[0].id]);
+    assert_eq!(result.total_count, 3);
+    let result = client
+        .find_by_month_day(&MonthDay::new(2, 1)?, 2, 2, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![mock_data[2].id]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_year_months_and_headlines() -> Result<()> {
+    use application::models::YearMonth;
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(30);
+    mock_data[0].title = "first".to_owned();
+    mock_data[0].slug = Some(Slug::new("first")?);
+    posts.import(&mock_data)?;
+
+    // 2月に28件、4月に2件
+    let year_months = client.get_year_months(Audience::Public).await?;
+    assert_eq!(
+        year_months
+            .iter()
+            .map(|ym| (ym.year_month, ym.count))
+            .collect::<Vec<_>>(),
+        vec![
+            (YearMonth::new(2020, 2)?, 28),
+            (YearMonth::new(2020, 4)?, 2)
+        ]
+    );
+
+    let headlines = client.get_headlines(Some(2020), Audience::Public).await?;
+    assert_eq!(headlines.len(), 30);
+    assert_eq!(headlines[0].id, mock_data[29].id);
+    let first = headlines.last().unwrap();
+    assert_eq!(first.title, "first");
+    assert_eq!(first.slug, Some(Slug::new("first")?));
+    assert!(client
+        .get_headlines(Some(2019), Audience::Public)
+        .await?
+        .is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_posts_around_cursor() -> Result<()> {
+    use application::models::Cursor;
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(5);
+    // 同じ日時の記事はIDの順に並べる
+    mock_data[3].created_at = mock_data[2].created_at;
+    posts.import(&mock_data)?;
+    let ids = mock_data.iter().map(|post| post.id).collect::<Vec<_>>();
+
+    let latest = client.get_before_cursor(None, 2, Audience::Public).await?;
+    assert_eq!(latest, vec![ids[4], ids[3]]);
+    let cursor = Cursor::new(&mock_data[3]);
+    let older = client
+        .get_before_cursor(Some(&cursor), 2, Audience::Public)
+        .await?;
+    assert_eq!(older, vec![ids[2], ids[1]]);
+    let cursor = Cursor::new(&mock_data[2]);
+    let newer = client
+        .get_after_cursor(&cursor, 10, Audience::Public)
+        .await?;
+    assert_eq!(newer, vec![ids[3], ids[4]]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_random() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(4);
+    mock_data[3].visibility = PostVisibility::Private;
+    posts.import(&mock_data)?;
+
+    // 見せない記事は選ばない
+    let mut post_ids = client.get_random(10, Audience::Public).await?;
+    post_ids.sort();
+    assert_eq!(
+        post_ids,
+        vec![mock_data[0].id, mock_data[1].id, mock_data[2].id]
+    );
+    assert_eq!(client.get_random(2, Audience::Public).await?.len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_by_ids_and_tags() -> Result<()> {
+    use application::adapters::PostsRepository as _;
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let client = PostQueryRepositoryImpl::new(&posts);
+    let mut mock_data = mock_data();
+    mock_data.truncate(4);
+    mock_data[3].status = PostStatus::Draft;
+    posts.import(&mock_data)?;
+    posts
+        .attach_tags(&mock_data[1].id, &[Tag::new("code")?])
+        .await?;
+    let ids = mock_data.iter().map(|post| post.id).collect::<Vec<_>>();
+
+    // `ids`がなければタグだけで絞り込む
+    let result = client
+        .find_by_ids_and_tags(None, &[], 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![ids[2], ids[1], ids[0]]);
+    let result = client
+        .find_by_ids_and_tags(None, &[Tag::new("code")?], 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![ids[1]]);
+
+    // 見せない記事は`ids`にあっても除き、新しい順に並べる
+    let result = client
+        .find_by_ids_and_tags(Some(&[ids[0], ids[2], ids[3]]), &[], 0, 1, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![ids[2]]);
+    assert_eq!(result.total_count, 2);
+    let result = client
+        .find_by_ids_and_tags(Some(&[ids[0], ids[3]]), &[], 0, 10, Audience::Admin)
+        .await?;
+    assert_eq!(result.post_ids, vec![ids[3], ids[0]]);
+    let result = client
+        .find_by_ids_and_tags(Some(&[]), &[], 0, 10, Audience::Admin)
+        .await?;
+    assert_eq!(result.total_count, 0);
+    Ok(())
+}
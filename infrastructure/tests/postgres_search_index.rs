@@ -0,0 +1,74 @@
+use anyhow::Result;
+use application::adapters::SearchIndex as _;
+use domain::entities::*;
+use infrastructure::{
+    elasticsearch_index::*, fallback_search_index::*, postgres::connect, postgres_search_index::*,
+    posts_repository_impl::*,
+};
+use pretty_assertions::assert_eq;
+mod database_mock;
+use database_mock::*;
+
+/// キーワード検索用の記事を入れます
+fn import_posts_for_keywords(posts: &PostsRepositoryImpl) -> Result<Vec<Post>> {
+    let mut mock_data = mock_data();
+    mock_data.truncate(3);
+    mock_data[0].title = "Rust".to_string();
+    mock_data[0].body = "ひらがなのてきすと".to_string();
+    mock_data[1].body = "ＨＥＬＬＯ、カタカナのテキスト".to_string();
+    mock_data[2].body = "100% hello".to_string();
+    posts.import(&mock_data)?;
+    Ok(mock_data)
+}
+
+fn sorted(mut ids: Vec<PostId>) -> Vec<PostId> {
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn search_in_database() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let index = PostgresSearchIndex::new(&posts);
+    let mock_data = import_posts_for_keywords(&posts)?;
+    let ids = mock_data.iter().map(|post| post.id).collect::<Vec<_>>();
+
+    // 全角と半角、大文字と小文字、ひらがなとカタカナは区別しない
+    assert_eq!(
+        sorted(index.search(&["hello"]).await?),
+        vec![ids[1], ids[2]]
+    );
+    assert_eq!(
+        sorted(index.search(&["テキスト", "rust"]).await?),
+        vec![ids[0]]
+    );
+    assert_eq!(
+        sorted(index.search(&["てきすと"]).await?),
+        vec![ids[0], ids[1]]
+    );
+
+    // `%`はワイルドカードとして扱わない
+    assert_eq!(sorted(index.search(&["0%"]).await?), vec![ids[2]]);
+    assert_eq!(sorted(index.search(&["R%"]).await?), vec![]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_falls_back_to_database() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    // つながらないElasticsearch
+    let es_url = url::Url::parse("http://127.0.0.1:1")?;
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let index = FallbackSearchIndex::new(
+        ElasticsearchIndex::with_index_name(&es_url, "test_fallback")?,
+        PostgresSearchIndex::new(&posts),
+    );
+    let mock_data = import_posts_for_keywords(&posts)?;
+
+    assert_eq!(
+        sorted(index.search(&["hello"]).await?),
+        vec![mock_data[1].id, mock_data[2].id]
+    );
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use application::adapters::PostsRepository;
+use application::adapters::{PostsRepository, PostsRepositoryError};
 use chrono::{Local, TimeZone, Utc};
 use domain::entities::*;
-use infrastructure::posts_repository_impl::*;
+use infrastructure::{postgres::connect, posts_repository_impl::*};
 use pretty_assertions::assert_eq;
 mod database_mock;
 use database_mock::*;
@@ -10,7 +10,7 @@ use database_mock::*;
 #[tokio::test]
 async fn get_by_id() -> Result<()> {
     let DatabaseMock { ref pg_url, .. } = mock_db()?;
-    let repo = PostsRepositoryImpl::new(pg_url)?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
     repo.import(&[Post::new(PostId(1), "1", "1111", Utc::now(), Utc::now())])?;
     let post = repo.get_by_id(&PostId(1)).await?.expect("post not found");
     assert_eq!(post.id.0, 1);
@@ -22,7 +22,7 @@ async fn get_by_id() -> Result<()> {
 #[tokio::test]
 async fn get_by_id_not_found() -> Result<()> {
     let DatabaseMock { ref pg_url, .. } = mock_db()?;
-    let repo = PostsRepositoryImpl::new(pg_url)?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
     let post = repo.get_by_id(&PostId(1)).await?;
     assert!(post.is_none());
     Ok(())
@@ -31,7 +31,7 @@ async fn get_by_id_not_found() -> Result<()> {
 #[tokio::test]
 async fn import_update_sequence() -> Result<()> {
     let DatabaseMock { ref pg_url, .. } = mock_db()?;
-    let repo = PostsRepositoryImpl::new(pg_url)?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
     repo.import(&mock_data())?;
     repo.reset_id_sequence()?;
     let post = repo.add(NewPost::new("1230", "1230", Utc::now())).await?;
@@ -42,7 +42,7 @@ async fn import_update_sequence() -> Result<()> {
 #[tokio::test]
 async fn create_and_find() -> Result<()> {
     let DatabaseMock { ref pg_url, .. } = mock_db()?;
-    let repo = PostsRepositoryImpl::new(pg_url)?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
     repo.add(NewPost::new("1", "1111", Utc::now())).await?;
     let post = repo.get_by_id(&PostId(1)).await?.expect("post not found");
     assert_eq!(post.id.0, 1);
@@ -54,7 +54,7 @@ async fn create_and_find() -> Result<()> {
 #[tokio::test]
 async fn create_and_increment_id() -> Result<()> {
     let DatabaseMock { ref pg_url, .. } = mock_db()?;
-    let repo = PostsRepositoryImpl::new(pg_url)?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
     repo.add(NewPost::new("1", "1111", Utc::now())).await?;
     repo.add(NewPost::new("2", "2222", Utc::now())).await?;
     let post = repo.get_by_id(&PostId(2)).await?.expect("post not found");
@@ -67,7 +67,7 @@ async fn create_and_increment_id() -> Result<()> {
 #[tokio::test]
 async fn create_and_update() -> Result<()> {
     let DatabaseMock { ref pg_url, .. } = mock_db()?;
-    let repo = PostsRepositoryImpl::new(pg_url)?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
     let created_at = Local
         .with_ymd_and_hms(2021, 5, 2, 2, 10, 28)
         .unwrap()
@@ -92,7 +92,7 @@ async fn create_and_update() -> Result<()> {
 #[tokio::test]
 async fn create_and_delete() -> Result<()> {
     let DatabaseMock { ref pg_url, .. } = mock_db()?;
-    let repo = PostsRepositoryImpl::new(pg_url)?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
     let created_at = Local
         .with_ymd_and_hms(2021, 5, 2, 2, 10, 28)
         .unwrap()
@@ -108,3 +108,161 @@ async fn create_and_delete() -> Result<()> {
     assert!(result.is_none());
     Ok(())
 }
+
+#[tokio::test]
+async fn attach_and_detach_tags() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
+    let post = repo.add(NewPost::new("1", "1111", Utc::now())).await?;
+    let rust = Tag::new("rust")?;
+    let diary = Tag::new("diary")?;
+    repo.attach_tags(&post.id, &[rust.clone(), diary.clone()])
+        .await?;
+    // 同じタグを重ねてつけても重複しない
+    repo.attach_tags(&post.id, &[Tag::new("rust")?]).await?;
+    let post = repo.get_by_id(&post.id).await?.expect("post not found");
+    assert_eq!(post.tags, vec![diary.clone(), rust.clone()]);
+
+    repo.detach_tags(&post.id, &[diary]).await?;
+    let posts = repo.get_by_ids(&[post.id]).await?;
+    assert_eq!(posts[0].tags, vec![rust]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_and_save_with_tags() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
+    let rust = Tag::new("rust")?;
+    let diary = Tag::new("diary")?;
+    let mut new_post = NewPost::new("1", "1111", Utc::now());
+    new_post.tags = vec![rust.clone(), diary.clone()];
+    let mut post = repo.add(new_post).await?;
+    assert_eq!(post.tags, vec![diary.clone(), rust.clone()]);
+
+    // 保存するとタグは`post.tags`に置き換わる
+    let code = Tag::new("code")?;
+    post.tags = vec![rust.clone(), code.clone()];
+    repo.save(&post).await?;
+    let post = repo.get_by_id(&post.id).await?.expect("post not found");
+    assert_eq!(post.tags, vec![code, rust]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_and_get_revisions() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
+    let mut post = repo.add(NewPost::new("1", "1111", Utc::now())).await?;
+    post.body = "1112".to_string();
+    let post = repo.save(&post).await?;
+    // タイトルも本文も変わっていなければリビジョンは増えない
+    repo.save(&post).await?;
+
+    let revisions = repo.get_revisions(&post.id).await?;
+    assert_eq!(
+        revisions
+            .iter()
+            .map(|revision| revision.body.as_str())
+            .collect::<Vec<_>>(),
+        vec!["1112", "1111"]
+    );
+    let revision = repo
+        .get_revision(&revisions[1].id)
+        .await?
+        .expect("revision not found");
+    assert_eq!(revision.post_id, post.id);
+    assert_eq!(revision.title, "1");
+    assert_eq!(revision.body, "1111");
+    Ok(())
+}
+
+#[tokio::test]
+async fn move_to_trash_and_restore() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
+    let post = repo.add(NewPost::new("1", "1111", Utc::now())).await?;
+
+    repo.move_to_trash(&post.id).await?;
+    let trashed = repo.get_by_id(&post.id).await?.expect("post not found");
+    assert!(trashed.is_deleted());
+
+    let restored = repo.restore_from_trash(&post.id).await?;
+    assert!(!restored.is_deleted());
+    assert_eq!(restored.body, "1111");
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_stale_version() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
+    let mut post = repo.add(NewPost::new("1", "1111", Utc::now())).await?;
+    let stale = post.clone();
+    post.body = "1112".to_string();
+    let post = repo.save(&post).await?;
+    assert_eq!(post.version, stale.version + 1);
+
+    // 古いバージョンのまま保存しようとすると失敗する
+    let error = repo.save(&stale).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&PostsRepositoryError::VersionConflict(stale.id))
+    );
+    let saved = repo.get_by_id(&post.id).await?.expect("post not found");
+    assert_eq!(saved.body, "1112");
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_by_slug() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
+    let mut new_post = NewPost::new("1", "1111", Utc::now());
+    new_post.slug = Some(Slug::new("first-post")?);
+    let post = repo.add(new_post).await?;
+
+    let found = repo
+        .get_by_slug(&Slug::new("first-post")?)
+        .await?
+        .expect("post not found");
+    assert_eq!(found.id, post.id);
+    assert!(repo
+        .get_by_slug(&Slug::new("second-post")?)
+        .await?
+        .is_none());
+
+    // 同じスラッグは追加も保存もできない
+    let mut duplicated = NewPost::new("2", "2222", Utc::now());
+    duplicated.slug = Some(Slug::new("first-post")?);
+    let error = repo.add(duplicated).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&PostsRepositoryError::DuplicateSlug)
+    );
+    let mut other = repo.add(NewPost::new("2", "2222", Utc::now())).await?;
+    other.slug = Some(Slug::new("first-post")?);
+    let error = repo.save(&other).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&PostsRepositoryError::DuplicateSlug)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_with_stats_and_set_stats() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let repo = PostsRepositoryImpl::new(connect(pg_url)?);
+    let mut new_post = NewPost::new("1", "1111", Utc::now());
+    new_post.stats = Some(PostStats::new(4));
+    let post = repo.add(new_post).await?;
+    assert_eq!(post.stats, Some(PostStats::new(4)));
+
+    // 統計の保存ではバージョンは進まない
+    repo.set_stats(&post.id, &PostStats::new(1000)).await?;
+    let saved = repo.get_by_id(&post.id).await?.expect("post not found");
+    assert_eq!(saved.stats, Some(PostStats::new(1000)));
+    assert_eq!(saved.version, post.version);
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use application::adapters::{PostsRepository as _, SeriesRepository as _, SeriesRepositoryError};
+use chrono::Utc;
+use domain::entities::*;
+use infrastructure::{postgres::connect, posts_repository_impl::*, series_repository_impl::*};
+use pretty_assertions::assert_eq;
+mod database_mock;
+use database_mock::*;
+
+#[tokio::test]
+async fn save_and_get() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let conn_pool = connect(pg_url)?;
+    let posts = PostsRepositoryImpl::new(conn_pool.clone());
+    let repo = SeriesRepositoryImpl::new(conn_pool);
+    let post1 = posts.add(NewPost::new("1", "1111", Utc::now())).await?;
+    let post2 = posts.add(NewPost::new("2", "2222", Utc::now())).await?;
+    let post3 = posts.add(NewPost::new("3", "3333", Utc::now())).await?;
+
+    let mut series = repo.add("series").await?;
+    series.add_post(post1.id);
+    series.add_post(post2.id);
+    series.add_post(post3.id);
+    series.move_post(post3.id, -2);
+    repo.save(&series).await?;
+
+    let series = repo.get(&series.id).await?.expect("series not found");
+    assert_eq!(series.post_ids, vec![post3.id, post1.id, post2.id]);
+    let found = repo
+        .get_by_post_id(&post2.id)
+        .await?
+        .expect("series not found");
+    assert_eq!(found.id, series.id);
+
+    // 記事を削除するとSeriesからも外れる
+    posts.remove(&post1.id).await?;
+    let series = repo.get(&series.id).await?.expect("series not found");
+    assert_eq!(series.post_ids, vec![post3.id, post2.id]);
+
+    repo.remove(&series.id).await?;
+    assert!(repo.get(&series.id).await?.is_none());
+    assert!(repo.get_by_post_id(&post2.id).await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_post_in_other_series() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let conn_pool = connect(pg_url)?;
+    let posts = PostsRepositoryImpl::new(conn_pool.clone());
+    let repo = SeriesRepositoryImpl::new(conn_pool);
+    let post = posts.add(NewPost::new("1", "1111", Utc::now())).await?;
+
+    let mut series = repo.add("series").await?;
+    series.add_post(post.id);
+    repo.save(&series).await?;
+
+    // ひとつの記事は複数のSeriesに入れられない
+    let mut other = repo.add("other").await?;
+    other.add_post(post.id);
+    let error = repo.save(&other).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&SeriesRepositoryError::PostAlreadyInSeries)
+    );
+    Ok(())
+}
@@ -0,0 +1,283 @@
+#![cfg(feature = "sqlite")]
+
+use anyhow::Result;
+use application::{
+    adapters::{
+        MediaRepository as _, MediaRepositoryError, PagesRepository as _, PostQueryRepository as _,
+        PostsRepository as _, PostsRepositoryError, SearchIndex as _, SeriesRepository as _,
+        SeriesRepositoryError,
+    },
+    models::{Audience, Cursor, MonthDay, YearMonth},
+};
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone as _, Utc};
+use domain::entities::*;
+use infrastructure::sqlite::*;
+use pretty_assertions::assert_eq;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// テストごとに一時ファイルのデータベースを作り、終わったら削除します
+struct SqliteMock {
+    path: PathBuf,
+    conn_pool: SqlitePool,
+}
+
+impl SqliteMock {
+    fn new() -> Result<Self> {
+        let path = std::env::temp_dir().join(format!("{}.sqlite3", Uuid::new_v4().simple()));
+        let path_str = path.to_str().unwrap();
+        migrate(path_str)?;
+        let conn_pool = connect(path_str)?;
+        Ok(Self { path, conn_pool })
+    }
+}
+
+impl Drop for SqliteMock {
+    fn drop(&mut self) {
+        std::fs::remove_file(&self.path).ok();
+    }
+}
+
+fn local(month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+    Local
+        .with_ymd_and_hms(2021, month, day, hour, 30, 0)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[tokio::test]
+async fn save_and_revisions() -> Result<()> {
+    let db = SqliteMock::new()?;
+    let repo = SqlitePostsRepository::new(db.conn_pool.clone());
+    let mut new_post = NewPost::new("Title", "Body", Utc::now());
+    new_post.slug = Some(Slug::new("title")?);
+    new_post.tags = vec![Tag::new("b")?, Tag::new("a")?, Tag::new("a")?];
+    let mut post = repo.add(new_post).await?;
+    assert_eq!(post.id, PostId(1));
+    assert_eq!(post.tags, vec![Tag::new("a")?, Tag::new("b")?]);
+
+    post.body = "Updated".to_string();
+    let saved = repo.save(&post).await?;
+    assert_eq!(saved.version, 2);
+    assert_eq!(saved.tags, vec![Tag::new("a")?, Tag::new("b")?]);
+    let revisions = repo.get_revisions(&post.id).await?;
+    assert_eq!(
+        revisions
+            .iter()
+            .map(|r| r.body.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Updated", "Body"]
+    );
+    let found = repo.get_by_slug(&Slug::new("title")?).await?.unwrap();
+    assert_eq!(found.created_at, post.created_at);
+
+    // 古いバージョンでは保存できない
+    let error = repo.save(&post).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&PostsRepositoryError::VersionConflict(post.id))
+    );
+
+    // 同じスラッグは追加も保存もできない
+    let mut duplicated = NewPost::new("Other", "Body", Utc::now());
+    duplicated.slug = Some(Slug::new("title")?);
+    let error = repo.add(duplicated).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&PostsRepositoryError::DuplicateSlug)
+    );
+    let mut other = repo.add(NewPost::new("Other", "Body", Utc::now())).await?;
+    other.slug = Some(Slug::new("title")?);
+    let error = repo.save(&other).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&PostsRepositoryError::DuplicateSlug)
+    );
+    repo.remove(&other.id).await?;
+
+    // 外部キーでリビジョンも削除される
+    repo.remove(&post.id).await?;
+    assert!(repo.get_by_id(&post.id).await?.is_none());
+    assert!(repo.get_revisions(&post.id).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn calendars() -> Result<()> {
+    let db = SqliteMock::new()?;
+    let repo = SqlitePostsRepository::new(db.conn_pool.clone());
+    let client = SqlitePostQueryRepository::new(db.conn_pool.clone());
+    // ローカルのタイムゾーンの日付で数える
+    for (month, day) in [(1, 31), (1, 31), (1, 2), (2, 1)] {
+        repo.add(NewPost::new("", "", local(month, day, 0))).await?;
+    }
+    let year_months = client.get_year_months(Audience::Public).await?;
+    assert_eq!(
+        year_months
+            .iter()
+            .map(|ym| (ym.year_month, ym.count))
+            .collect::<Vec<_>>(),
+        vec![(YearMonth::new(2021, 1)?, 3), (YearMonth::new(2021, 2)?, 1)]
+    );
+    let year_month = YearMonth::new(2021, 1)?;
+    assert_eq!(
+        client
+            .get_days_in_year_month(&year_month, Audience::Public)
+            .await?,
+        vec![2, 31]
+    );
+    let result = client
+        .find_by_year_month(&year_month, 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![PostId(3), PostId(1), PostId(2)]);
+    let result = client
+        .find_by_date(
+            &NaiveDate::from_ymd_opt(2021, 1, 31).unwrap(),
+            0,
+            10,
+            Audience::Public,
+        )
+        .await?;
+    assert_eq!(result.post_ids, vec![PostId(1), PostId(2)]);
+    let result = client
+        .find_by_month_day(&MonthDay::new(2, 1)?, 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![PostId(4)]);
+    assert_eq!(
+        client.get_dates(Audience::Public).await?,
+        vec![
+            NaiveDate::from_ymd_opt(2021, 1, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 1, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 2, 1).unwrap(),
+        ]
+    );
+    assert_eq!(
+        client
+            .get_headlines(Some(2021), Audience::Public)
+            .await?
+            .len(),
+        4
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_from_and_until_date() -> Result<()> {
+    let db = SqliteMock::new()?;
+    let repo = SqlitePostsRepository::new(db.conn_pool.clone());
+    let client = SqlitePostQueryRepository::new(db.conn_pool.clone());
+    // 小数点以下の秒があってもなくても、桁数が違っても正しく並べる
+    let base = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+    for micros in [0, 500_000, 1_000_000, 1_000_001, 2_000_000] {
+        repo.add(NewPost::new("", "", base + Duration::microseconds(micros)))
+            .await?;
+    }
+
+    // `from`は含み、`until`は含まない
+    let from = base + Duration::seconds(1);
+    assert_eq!(
+        client.get_from_date(from, 0, 10, Audience::Public).await?,
+        vec![PostId(3), PostId(4), PostId(5)]
+    );
+    assert_eq!(
+        client.get_until_date(from, 0, 10, Audience::Public).await?,
+        vec![PostId(2), PostId(1)]
+    );
+    let post = repo.get_by_id(&PostId(3)).await?.unwrap();
+    assert_eq!(
+        client
+            .get_before_cursor(Some(&Cursor::new(&post)), 10, Audience::Public)
+            .await?,
+        vec![PostId(2), PostId(1)]
+    );
+    assert_eq!(
+        client
+            .get_after_cursor(&Cursor::new(&post), 10, Audience::Public)
+            .await?,
+        vec![PostId(4), PostId(5)]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_and_find_by_ids_and_tags() -> Result<()> {
+    let db = SqliteMock::new()?;
+    let repo = SqlitePostsRepository::new(db.conn_pool.clone());
+    let index = SqliteSearchIndex::new(db.conn_pool.clone());
+    let client = SqlitePostQueryRepository::new(db.conn_pool.clone());
+    repo.add(NewPost::new("Rust", "Hello, World", local(1, 1, 0)))
+        .await?;
+    repo.add(NewPost::new("100%", "hello", local(1, 2, 0)))
+        .await?;
+    let mut draft = NewPost::new("Draft", "hello", local(1, 3, 0));
+    draft.status = PostStatus::Draft;
+    repo.add(draft).await?;
+    repo.attach_tags(&PostId(1), &[Tag::new("code")?]).await?;
+
+    let ids = index.search(&["hello"]).await?;
+    let result = client
+        .find_by_ids_and_tags(Some(&ids), &[], 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![PostId(2), PostId(1)]);
+    assert_eq!(result.total_count, 2);
+    let result = client
+        .find_by_ids_and_tags(Some(&ids), &[], 0, 10, Audience::Admin)
+        .await?;
+    assert_eq!(result.total_count, 3);
+    let result = client
+        .find_by_ids_and_tags(Some(&ids), &[Tag::new("code")?], 0, 10, Audience::Public)
+        .await?;
+    assert_eq!(result.post_ids, vec![PostId(1)]);
+    // `%`はワイルドカードとして扱わない
+    assert_eq!(index.search(&["0%"]).await?, vec![PostId(2)]);
+    assert_eq!(index.search(&["R%"]).await?, vec![]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn pages_and_series() -> Result<()> {
+    let db = SqliteMock::new()?;
+    let posts = SqlitePostsRepository::new(db.conn_pool.clone());
+    let pages = SqlitePagesRepository::new(db.conn_pool.clone());
+    let series_repository = SqliteSeriesRepository::new(db.conn_pool.clone());
+
+    let page = Page::new(PageName::new("about")?, "About", "about", Utc::now());
+    pages.save(&page).await?;
+    pages.save(&page).await?;
+    assert_eq!(pages.get_all().await?.len(), 1);
+
+    let post = posts.add(NewPost::new("", "", Utc::now())).await?;
+    let mut series = series_repository.add("Series").await?;
+    series.add_post(post.id);
+    series_repository.save(&series).await?;
+    let found = series_repository.get_by_post_id(&post.id).await?.unwrap();
+    assert_eq!(found.post_ids, vec![post.id]);
+
+    let mut other = series_repository.add("Other").await?;
+    other.add_post(post.id);
+    let error = series_repository.save(&other).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&SeriesRepositoryError::PostAlreadyInSeries)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_duplicate_media() -> Result<()> {
+    let db = SqliteMock::new()?;
+    let repo = SqliteMediaRepository::new(db.conn_pool.clone());
+    let now = Utc::now();
+    repo.add(NewMedia::new("memo.txt", "text/plain", 10, None, now))
+        .await?;
+    // 同じ名前では追加できない
+    let error = repo
+        .add(NewMedia::new("memo.txt", "text/plain", 10, None, now))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&MediaRepositoryError::DuplicateName)
+    );
+    Ok(())
+}
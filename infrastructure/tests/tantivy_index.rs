@@ -0,0 +1,141 @@
+#![cfg(feature = "tantivy")]
+
+use anyhow::Result;
+use application::adapters::SearchIndex as _;
+use domain::entities::*;
+use infrastructure::{postgres::connect, posts_repository_impl::*, tantivy_index::*};
+use pretty_assertions::assert_eq;
+use std::path::PathBuf;
+use uuid::Uuid;
+mod database_mock;
+use database_mock::*;
+
+/// テストごとに一時ディレクトリに索引を作り、終わったら削除します
+struct IndexDirectory {
+    path: PathBuf,
+}
+
+impl IndexDirectory {
+    fn new() -> Self {
+        let path = std::env::temp_dir().join(Uuid::new_v4().simple().to_string());
+        Self { path }
+    }
+
+    fn open(&self) -> Result<TantivyIndex> {
+        TantivyIndex::open(&self.path, None)
+    }
+}
+
+impl Drop for IndexDirectory {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.path).ok();
+    }
+}
+
+fn sorted(mut ids: Vec<PostId>) -> Vec<PostId> {
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn search() -> Result<()> {
+    let directory = IndexDirectory::new();
+    let index = directory.open()?;
+    assert!(index.is_empty());
+    let mut mock_data = mock_data();
+    mock_data.truncate(3);
+    mock_data[0].title = "Rust".to_string();
+    mock_data[0].body = "ひらがなのてきすと".to_string();
+    mock_data[1].body = "ＨＥＬＬＯ、カタカナのテキスト".to_string();
+    mock_data[2].body = "Hello, world".to_string();
+    for post in mock_data.iter() {
+        index.save(post).await?;
+    }
+    let ids = mock_data.iter().map(|post| post.id).collect::<Vec<_>>();
+
+    // 全角と半角、大文字と小文字、ひらがなとカタカナは区別しない
+    assert_eq!(
+        sorted(index.search(&["hello"]).await?),
+        vec![ids[1], ids[2]]
+    );
+    assert_eq!(
+        sorted(index.search(&["テキスト"]).await?),
+        vec![ids[0], ids[1]]
+    );
+    assert_eq!(index.search(&["rust", "てきすと"]).await?, vec![ids[0]]);
+    // 文字の並びが同じときだけマッチする
+    assert_eq!(index.search(&["キテ"]).await?, vec![]);
+    assert_eq!(index.search(&["ト"]).await?.len(), 2);
+    assert_eq!(index.search(&["、"]).await?, vec![]);
+    assert_eq!(index.search(&[]).await?.len(), 3);
+
+    let mut post = mock_data[2].clone();
+    post.body = "Goodbye".to_string();
+    index.save(&post).await?;
+    assert_eq!(index.search(&["hello"]).await?, vec![ids[1]]);
+    index.delete(&ids[1]).await?;
+    assert_eq!(index.search(&["hello"]).await?, vec![]);
+
+    // 開き直しても残っている
+    drop(index);
+    let index = directory.open()?;
+    assert_eq!(sorted(index.search(&[]).await?), vec![ids[0], ids[2]]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rebuild_if_empty() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let directory = IndexDirectory::new();
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    let mut mock_data = mock_data();
+    mock_data.truncate(4);
+    mock_data[1].body = "hello".to_string();
+    mock_data[2].body = "hello".to_string();
+    mock_data[3].body = "hello".to_string();
+    mock_data[3].status = PostStatus::Draft;
+    posts.import(&mock_data)?;
+    let ids = mock_data.iter().map(|post| post.id).collect::<Vec<_>>();
+
+    // 空の索引はPostgreSQLの記事から作る。見せてよいかどうかは索引では区別しない
+    let index = directory.open()?;
+    index.rebuild_if_empty(&posts)?;
+    assert_eq!(
+        sorted(index.search(&["hello"]).await?),
+        vec![ids[1], ids[2], ids[3]]
+    );
+
+    // 空でなければ作り直さない
+    index.delete(&ids[1]).await?;
+    index.rebuild_if_empty(&posts)?;
+    assert_eq!(
+        sorted(index.search(&["hello"]).await?),
+        vec![ids[2], ids[3]]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn rebuild_if_empty_reads_all_pages() -> Result<()> {
+    let DatabaseMock { ref pg_url, .. } = mock_db()?;
+    let directory = IndexDirectory::new();
+    let posts = PostsRepositoryImpl::new(connect(pg_url)?);
+    // 一度に読み込む件数をまたぐだけの記事を用意する
+    let template = mock_data().remove(0);
+    let mock_data = (1..=1001)
+        .map(|id| {
+            let mut post = template.clone();
+            post.id = PostId(id);
+            post
+        })
+        .collect::<Vec<_>>();
+    posts.import(&mock_data)?;
+
+    let index = directory.open()?;
+    index.rebuild_if_empty(&posts)?;
+    assert_eq!(
+        sorted(index.search(&[]).await?),
+        mock_data.iter().map(|post| post.id).collect::<Vec<_>>()
+    );
+    Ok(())
+}
@@ -10,7 +10,6 @@ use std::future::{ready, Ready};
 pub struct AppContext {
     // TODO: AppContextProvider traitを定義して、UseCaseから参照するようにする
     pub is_authorized: bool,
-    pub is_development: bool,
     pub config: Config,
     pub message: Option<String>,
 }
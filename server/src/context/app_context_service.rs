@@ -57,10 +57,8 @@ where
                 .remove_as::<String>("message")
                 .and_then(Result::ok);
             let is_authorized = matches!(req.get_identity().and_then(|id| id.id()), Ok(ref id) if app.authorize(id));
-            let is_development = app.is_development;
             req.extensions_mut().insert(AppContext {
                 is_authorized,
-                is_development,
                 config: app.config.clone(),
                 message,
             });
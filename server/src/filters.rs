@@ -1,4 +1,6 @@
+use application::models::PostHeadline;
 use chrono::{DateTime, Local, Utc};
+use domain::entities::{Post, PostId, Slug};
 
 pub fn format_date(date: &DateTime<Utc>) -> ::askama::Result<String> {
     Ok(date.with_timezone(&Local).format("%F %T").to_string())
@@ -7,3 +9,41 @@ pub fn format_date(date: &DateTime<Utc>) -> ::askama::Result<String> {
 pub fn iso8601(date: &DateTime<Utc>) -> ::askama::Result<String> {
     Ok(date.with_timezone(&Local).to_rfc3339())
 }
+
+/// 年を除いた`{月}-{日}`の形式にします
+pub fn month_day(date: &DateTime<Utc>) -> ::askama::Result<String> {
+    Ok(date.with_timezone(&Local).format("%m-%d").to_string())
+}
+
+/// `<input type="datetime-local">`に設定できる形式にします
+pub fn datetime_local(date: &DateTime<Utc>) -> ::askama::Result<String> {
+    Ok(date
+        .with_timezone(&Local)
+        .format("%Y-%m-%dT%H:%M")
+        .to_string())
+}
+
+/// 記事のパスを先頭の`/`なしで返します。スラッグがあれば`{年}-{月}-{日}/{スラッグ}`、なければIDを使います
+pub fn post_path(post: &Post) -> ::askama::Result<String> {
+    Ok(path(&post.id, post.slug.as_ref(), &post.created_at))
+}
+
+/// `post_path`と同じく、見出しから記事のパスを返します
+pub fn headline_path(headline: &PostHeadline) -> ::askama::Result<String> {
+    Ok(path(
+        &headline.id,
+        headline.slug.as_ref(),
+        &headline.created_at,
+    ))
+}
+
+fn path(id: &PostId, slug: Option<&Slug>, created_at: &DateTime<Utc>) -> String {
+    match slug {
+        Some(slug) => format!(
+            "{}/{}",
+            created_at.with_timezone(&Local).format("%Y-%m-%d"),
+            slug
+        ),
+        None => id.to_string(),
+    }
+}
@@ -1,9 +1,11 @@
-pub mod about;
 pub mod admin;
 pub mod api;
+pub mod archive;
 pub mod args;
 pub mod atom;
 pub mod auth;
 pub mod errors;
+pub mod pages;
 pub mod posts;
 mod responses;
+pub mod series;
@@ -1,22 +1,68 @@
-use super::args::{CreateFormParams, DeleteFormParams, IdArguments, UpdateFormParams};
+use super::args::{
+    CreateFormParams, DeleteFormParams, DiffArguments, IdArguments, MoveSeriesPostFormParams,
+    PageFormParams, PageNameArguments, SeriesFormParams, SeriesPostFormParams, UpdateFormParams,
+    UpdateSeriesFormParams, UploadMediaFormParams,
+};
 use crate::context::AppContext;
+use crate::filters;
+use crate::presentation::diff::Diff;
 use crate::{Error, Service};
+use actix_multipart::form::MultipartForm;
 use actix_session::Session;
-use actix_web::{http::header, web, HttpResponse};
+use actix_web::{
+    http::{header, StatusCode},
+    web, HttpResponse,
+};
+use application::errors::ApplicationError;
+use application::models::{Audience, UploadedFile};
 use application::use_cases::{
-    CreateNewPostUseCase, DeletePostUseCase, GetPostByIdUseCase, UpdatePostUseCase,
+    AddPostToSeriesUseCase, CreateNewPostUseCase, CreateSeriesUseCase, DeleteMediaUseCase,
+    DeletePageUseCase, DeletePostUseCase, DeleteSeriesUseCase, GetAllMediaUseCase,
+    GetAllSeriesUseCase, GetDraftsUseCase, GetPageUseCase, GetPagesUseCase, GetPostByIdUseCase,
+    GetRevisionUseCase, GetRevisionsUseCase, GetSeriesUseCase, GetStatisticsUseCase,
+    GetTrashedPostsUseCase, PinPostUseCase, PurgePostUseCase, RestorePostUseCase,
+    RestoreRevisionUseCase, SavePageUseCase, UnpinPostUseCase, UpdatePostUseCase,
+    UpdateSeriesUseCase, UploadMediaUseCase,
 };
 use askama_actix::TemplateToResponse;
-use chrono::Utc;
-use domain::entities::{NewPost, PostId};
-use templates::{AdminIndexTemplate, EditPostTemplate, NewPostTemplate};
+use chrono::{Local, Utc};
+use domain::entities::{MediaId, NewPost, Page, PostId, RevisionId, Series, SeriesId};
+use templates::{
+    AdminIndexTemplate, ConflictTemplate, DiffTemplate, EditPageTemplate, EditPostTemplate,
+    EditSeriesTemplate, MediaTemplate, NewPostTemplate, PagesTemplate, RevisionTemplate,
+    RevisionsTemplate, SeriesListTemplate, TrashTemplate,
+};
+
+const DUPLICATE_SLUG_MESSAGE: &str = "このスラッグはほかの記事で使われています";
 
-pub async fn index(context: AppContext) -> Result<HttpResponse, Error> {
-    Ok(AdminIndexTemplate { context }.to_response())
+pub async fn index(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let drafts =
+        GetDraftsUseCase::execute(&*service.posts_repository, &*service.post_query_repository)
+            .await?;
+    let statistics = GetStatisticsUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        Local::now().date_naive(),
+    )
+    .await?;
+    Ok(AdminIndexTemplate {
+        context,
+        drafts,
+        statistics,
+    }
+    .to_response())
 }
 
 pub async fn new_post_form(context: AppContext) -> Result<HttpResponse, Error> {
-    Ok(NewPostTemplate { context }.to_response())
+    Ok(NewPostTemplate {
+        context,
+        new_post: None,
+        error: None,
+    }
+    .to_response())
 }
 
 pub async fn edit_post_form(
@@ -25,21 +71,69 @@ pub async fn edit_post_form(
     args: web::Query<IdArguments>,
 ) -> Result<HttpResponse, Error> {
     let post_id = PostId(args.id);
-    let post =
-        GetPostByIdUseCase::execute(&service.posts_repository, &service.search_client, &post_id)
-            .await?
-            .post()?;
-    Ok(EditPostTemplate { context, post }.to_response())
+    let post = GetPostByIdUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &post_id,
+        Audience::Admin,
+    )
+    .await?
+    .post()?;
+    Ok(EditPostTemplate {
+        context,
+        post,
+        error: None,
+    }
+    .to_response())
 }
 
 pub async fn create(
+    context: AppContext,
     service: web::Data<Service>,
     form: web::Form<CreateFormParams>,
     session: Session,
 ) -> Result<HttpResponse, Error> {
-    let new_post = NewPost::new(&form.title, &form.body, Utc::now());
-    CreateNewPostUseCase::execute(&service.posts_repository, &service.search_client, new_post)
-        .await?;
+    // 公開日時が指定されていれば、その日時の記事として予約投稿する
+    let timestamp = form.published_at()?.unwrap_or_else(Utc::now);
+    let mut new_post = NewPost::new(&form.title, &form.body, timestamp);
+    new_post.status = form.status;
+    new_post.visibility = form.visibility;
+    new_post.tags = form.tags()?;
+    new_post.slug = form.slug()?;
+    new_post.summary = form.summary();
+    let post = match CreateNewPostUseCase::execute(
+        &*service.posts_repository,
+        &*service.search_index,
+        &service.body_analyzer,
+        new_post.clone(),
+    )
+    .await
+    {
+        Ok(post) => post,
+        Err(ApplicationError::DuplicateSlug) => {
+            let mut res = NewPostTemplate {
+                context,
+                new_post: Some(new_post),
+                error: Some(DUPLICATE_SLUG_MESSAGE),
+            }
+            .to_response();
+            *res.status_mut() = StatusCode::BAD_REQUEST;
+            return Ok(res);
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if post.is_draft() {
+        session.insert("message", "下書きを保存しました").ok();
+        return Ok(HttpResponse::SeeOther()
+            .append_header((header::LOCATION, "/admin/"))
+            .finish());
+    }
+    if post.is_scheduled() {
+        session.insert("message", "記事の投稿を予約しました").ok();
+        return Ok(HttpResponse::SeeOther()
+            .append_header((header::LOCATION, format!("/{}", filters::post_path(&post)?)))
+            .finish());
+    }
     session.insert("message", "記事の投稿に成功しました").ok();
     Ok(HttpResponse::SeeOther()
         .append_header((header::LOCATION, "/"))
@@ -47,21 +141,74 @@ pub async fn create(
 }
 
 pub async fn update(
+    context: AppContext,
     service: web::Data<Service>,
     form: web::Form<UpdateFormParams>,
     session: Session,
 ) -> Result<HttpResponse, Error> {
     let post_id = PostId(form.id);
-    let mut post =
-        GetPostByIdUseCase::execute(&service.posts_repository, &service.search_client, &post_id)
-            .await?
-            .post()?;
+    let mut post = GetPostByIdUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &post_id,
+        Audience::Admin,
+    )
+    .await?
+    .post()?;
+    post.set_published_at(form.published_at()?, form.status);
     post.title = form.title.clone();
     post.body = form.body.clone();
-    UpdatePostUseCase::execute(&service.posts_repository, &service.search_client, &post).await?;
-    session.insert("message", "記事の編集に成功しました").ok();
+    post.status = form.status;
+    post.visibility = form.visibility;
+    post.tags = form.tags()?;
+    post.slug = form.slug()?;
+    post.summary = form.summary();
+    post.version = form.version;
+    match UpdatePostUseCase::execute(
+        &*service.posts_repository,
+        &*service.search_index,
+        &service.body_analyzer,
+        &post,
+    )
+    .await
+    {
+        Ok(()) => {}
+        Err(ApplicationError::PostConflict(current)) => {
+            // 他の編集が先に保存されていたら、両方の内容を表示してマージしてもらう
+            let title_diff = Diff::new(&current.title, &post.title);
+            let body_diff = Diff::new(&current.body, &post.body);
+            let mut res = ConflictTemplate {
+                context,
+                post,
+                current: *current,
+                title_diff,
+                body_diff,
+            }
+            .to_response();
+            *res.status_mut() = StatusCode::CONFLICT;
+            return Ok(res);
+        }
+        Err(ApplicationError::DuplicateSlug) => {
+            let mut res = EditPostTemplate {
+                context,
+                post,
+                error: Some(DUPLICATE_SLUG_MESSAGE),
+            }
+            .to_response();
+            *res.status_mut() = StatusCode::BAD_REQUEST;
+            return Ok(res);
+        }
+        Err(e) => return Err(e.into()),
+    }
+    if post.is_draft() {
+        session.insert("message", "下書きを保存しました").ok();
+    } else if post.is_scheduled() {
+        session.insert("message", "記事の投稿を予約しました").ok();
+    } else {
+        session.insert("message", "記事の編集に成功しました").ok();
+    }
     Ok(HttpResponse::SeeOther()
-        .append_header((header::LOCATION, format!("/{}", form.id)))
+        .append_header((header::LOCATION, format!("/{}", filters::post_path(&post)?)))
         .finish())
 }
 
@@ -71,28 +218,439 @@ pub async fn delete(
     session: Session,
 ) -> Result<HttpResponse, Error> {
     let post_id = PostId(form.id);
-    DeletePostUseCase::execute(&service.posts_repository, &service.search_client, &post_id).await?;
-    session.insert("message", "記事の削除に成功しました").ok();
+    DeletePostUseCase::execute(&*service.posts_repository, &*service.search_index, &post_id)
+        .await?;
+    session.insert("message", "記事をゴミ箱に入れました").ok();
     Ok(HttpResponse::SeeOther()
         .append_header((header::LOCATION, "/"))
         .finish())
 }
 
+pub async fn revisions(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Query<IdArguments>,
+) -> Result<HttpResponse, Error> {
+    let post_id = PostId(args.id);
+    let post = GetPostByIdUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &post_id,
+        Audience::Admin,
+    )
+    .await?
+    .post()?;
+    let revisions = GetRevisionsUseCase::execute(&*service.posts_repository, &post_id).await?;
+    Ok(RevisionsTemplate {
+        context,
+        post,
+        revisions,
+    }
+    .to_response())
+}
+
+pub async fn revision(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Query<IdArguments>,
+) -> Result<HttpResponse, Error> {
+    let revision =
+        GetRevisionUseCase::execute(&*service.posts_repository, &RevisionId(args.id)).await?;
+    let post = GetPostByIdUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &revision.post_id,
+        Audience::Admin,
+    )
+    .await?
+    .post()?;
+    // 復元したときに現在の記事からどう変わるかを表示する
+    let title_diff = Diff::new(&post.title, &revision.title);
+    let body_diff = Diff::new(&post.body, &revision.body);
+    Ok(RevisionTemplate {
+        context,
+        revision,
+        title_diff,
+        body_diff,
+    }
+    .to_response())
+}
+
+pub async fn diff(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Query<DiffArguments>,
+) -> Result<HttpResponse, Error> {
+    let from =
+        GetRevisionUseCase::execute(&*service.posts_repository, &RevisionId(args.from)).await?;
+    let to = GetRevisionUseCase::execute(&*service.posts_repository, &RevisionId(args.to)).await?;
+    let title_diff = Diff::new(&from.title, &to.title);
+    let body_diff = Diff::new(&from.body, &to.body);
+    Ok(DiffTemplate {
+        context,
+        from,
+        to,
+        title_diff,
+        body_diff,
+    }
+    .to_response())
+}
+
+pub async fn restore(
+    service: web::Data<Service>,
+    form: web::Form<IdArguments>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let post = RestoreRevisionUseCase::execute(
+        &*service.posts_repository,
+        &*service.search_index,
+        &service.body_analyzer,
+        &RevisionId(form.id),
+    )
+    .await?;
+    session.insert("message", "リビジョンを復元しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, format!("/{}", filters::post_path(&post)?)))
+        .finish())
+}
+
+pub async fn pin(
+    service: web::Data<Service>,
+    form: web::Form<IdArguments>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    PinPostUseCase::execute(&*service.posts_repository, &PostId(form.id), Utc::now()).await?;
+    session.insert("message", "記事を先頭に固定しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, format!("/admin/edit?id={}", form.id)))
+        .finish())
+}
+
+pub async fn unpin(
+    service: web::Data<Service>,
+    form: web::Form<IdArguments>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    UnpinPostUseCase::execute(&*service.posts_repository, &PostId(form.id)).await?;
+    session.insert("message", "記事の固定を外しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, format!("/admin/edit?id={}", form.id)))
+        .finish())
+}
+
+pub async fn trash(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let posts = GetTrashedPostsUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+    )
+    .await?;
+    let retention_days = service.config.trash.retention_days;
+    Ok(TrashTemplate {
+        context,
+        posts,
+        retention_days,
+    }
+    .to_response())
+}
+
+pub async fn restore_from_trash(
+    service: web::Data<Service>,
+    form: web::Form<IdArguments>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let post = RestorePostUseCase::execute(
+        &*service.posts_repository,
+        &*service.search_index,
+        &PostId(form.id),
+    )
+    .await?;
+    session.insert("message", "記事をゴミ箱から戻しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, format!("/{}", filters::post_path(&post)?)))
+        .finish())
+}
+
+pub async fn purge(
+    service: web::Data<Service>,
+    form: web::Form<IdArguments>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    PurgePostUseCase::execute(&*service.posts_repository, &PostId(form.id)).await?;
+    session.insert("message", "記事を完全に削除しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, "/admin/trash"))
+        .finish())
+}
+
+pub async fn pages(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let pages = GetPagesUseCase::execute(&*service.pages_repository).await?;
+    Ok(PagesTemplate { context, pages }.to_response())
+}
+
+pub async fn new_page_form(context: AppContext) -> Result<HttpResponse, Error> {
+    Ok(EditPageTemplate {
+        context,
+        page: None,
+    }
+    .to_response())
+}
+
+pub async fn edit_page_form(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Query<PageNameArguments>,
+) -> Result<HttpResponse, Error> {
+    let page = GetPageUseCase::execute(&*service.pages_repository, &args.name()?).await?;
+    Ok(EditPageTemplate {
+        context,
+        page: Some(page),
+    }
+    .to_response())
+}
+
+pub async fn save_page(
+    service: web::Data<Service>,
+    form: web::Form<PageFormParams>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let page = Page::new(form.name()?, &form.title, &form.body, Utc::now());
+    let page = SavePageUseCase::execute(&*service.pages_repository, &page).await?;
+    session.insert("message", "ページを保存しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, format!("/{}", page.name)))
+        .finish())
+}
+
+pub async fn delete_page(
+    service: web::Data<Service>,
+    form: web::Form<PageNameArguments>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    DeletePageUseCase::execute(&*service.pages_repository, &form.name()?).await?;
+    session.insert("message", "ページを削除しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, "/admin/pages"))
+        .finish())
+}
+
+pub async fn media(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let media = GetAllMediaUseCase::execute(&*service.media_repository).await?;
+    Ok(MediaTemplate { context, media }.to_response())
+}
+
+pub async fn upload_media(
+    service: web::Data<Service>,
+    MultipartForm(form): MultipartForm<UploadMediaFormParams>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let content_types = form
+        .files
+        .iter()
+        .map(|file| {
+            file.content_type
+                .as_ref()
+                .map_or("application/octet-stream".to_owned(), ToString::to_string)
+        })
+        .collect::<Vec<_>>();
+    let files = form
+        .files
+        .iter()
+        .zip(&content_types)
+        .map(|(file, content_type)| UploadedFile {
+            name: file.file_name.as_deref().unwrap_or_default(),
+            content_type,
+            data: &file.data,
+        })
+        .collect::<Vec<_>>();
+    UploadMediaUseCase::execute(
+        &*service.media_repository,
+        &service.media_storage,
+        &service.image_processor,
+        &files,
+        Utc::now(),
+    )
+    .await?;
+    session
+        .insert("message", "ファイルをアップロードしました")
+        .ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, "/admin/media"))
+        .finish())
+}
+
+pub async fn delete_media(
+    service: web::Data<Service>,
+    form: web::Form<IdArguments>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    DeleteMediaUseCase::execute(
+        &*service.media_repository,
+        &service.media_storage,
+        &MediaId(form.id),
+    )
+    .await?;
+    session.insert("message", "ファイルを削除しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, "/admin/media"))
+        .finish())
+}
+
+pub async fn series(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let series = GetAllSeriesUseCase::execute(&*service.series_repository).await?;
+    Ok(SeriesListTemplate { context, series }.to_response())
+}
+
+pub async fn edit_series_form(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Query<IdArguments>,
+) -> Result<HttpResponse, Error> {
+    let series = GetSeriesUseCase::execute(
+        &*service.series_repository,
+        &*service.posts_repository,
+        &SeriesId(args.id),
+        Audience::Admin,
+    )
+    .await?;
+    Ok(EditSeriesTemplate { context, series }.to_response())
+}
+
+pub async fn create_series(
+    service: web::Data<Service>,
+    form: web::Form<SeriesFormParams>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let series = CreateSeriesUseCase::execute(&*service.series_repository, &form.title).await?;
+    session.insert("message", "シリーズを作成しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((
+            header::LOCATION,
+            format!("/admin/series/edit?id={}", series.id),
+        ))
+        .finish())
+}
+
+pub async fn update_series(
+    service: web::Data<Service>,
+    form: web::Form<UpdateSeriesFormParams>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let mut series = get_series(&service, form.id).await?;
+    series.title = form.title.clone();
+    UpdateSeriesUseCase::execute(&*service.series_repository, &series).await?;
+    session.insert("message", "シリーズを保存しました").ok();
+    Ok(redirect_to_series_form(form.id))
+}
+
+pub async fn add_post_to_series(
+    service: web::Data<Service>,
+    form: web::Form<SeriesPostFormParams>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    AddPostToSeriesUseCase::execute(
+        &*service.series_repository,
+        &*service.posts_repository,
+        &SeriesId(form.id),
+        &PostId(form.post_id),
+    )
+    .await?;
+    session
+        .insert("message", "シリーズに記事を追加しました")
+        .ok();
+    Ok(redirect_to_series_form(form.id))
+}
+
+pub async fn remove_post_from_series(
+    service: web::Data<Service>,
+    form: web::Form<SeriesPostFormParams>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    let mut series = get_series(&service, form.id).await?;
+    series.remove_post(PostId(form.post_id));
+    UpdateSeriesUseCase::execute(&*service.series_repository, &series).await?;
+    session
+        .insert("message", "シリーズから記事を外しました")
+        .ok();
+    Ok(redirect_to_series_form(form.id))
+}
+
+pub async fn move_post_in_series(
+    service: web::Data<Service>,
+    form: web::Form<MoveSeriesPostFormParams>,
+) -> Result<HttpResponse, Error> {
+    let mut series = get_series(&service, form.id).await?;
+    series.move_post(PostId(form.post_id), form.offset);
+    UpdateSeriesUseCase::execute(&*service.series_repository, &series).await?;
+    Ok(redirect_to_series_form(form.id))
+}
+
+pub async fn delete_series(
+    service: web::Data<Service>,
+    form: web::Form<IdArguments>,
+    session: Session,
+) -> Result<HttpResponse, Error> {
+    DeleteSeriesUseCase::execute(&*service.series_repository, &SeriesId(form.id)).await?;
+    session.insert("message", "シリーズを削除しました").ok();
+    Ok(HttpResponse::SeeOther()
+        .append_header((header::LOCATION, "/admin/series"))
+        .finish())
+}
+
+/// 並び順を変えるために、閲覧できない記事も含めたSeriesを取得します
+async fn get_series(service: &Service, id: i32) -> Result<Series, Error> {
+    let series = GetSeriesUseCase::execute(
+        &*service.series_repository,
+        &*service.posts_repository,
+        &SeriesId(id),
+        Audience::Admin,
+    )
+    .await?;
+    Ok(series.series)
+}
+
+fn redirect_to_series_form(id: i32) -> HttpResponse {
+    HttpResponse::SeeOther()
+        .append_header((header::LOCATION, format!("/admin/series/edit?id={id}")))
+        .finish()
+}
+
 mod templates {
     use crate::context::AppContext;
+    use crate::filters;
+    use crate::presentation::diff::Diff;
+    use application::models::{SeriesPosts, Statistics};
     use askama::Template;
-    use domain::entities::Post;
+    use domain::entities::{
+        Media, MediaVariant, NewPost, Page, Post, PostStatus, Revision, Series,
+    };
 
     #[derive(Template)]
     #[template(path = "admin.html")]
     pub struct AdminIndexTemplate {
         pub context: AppContext,
+        pub drafts: Vec<Post>,
+        pub statistics: Statistics,
     }
 
     #[derive(Template)]
     #[template(path = "admin/new.html")]
     pub struct NewPostTemplate {
         pub context: AppContext,
+        /// 入力内容に問題があったときに、入力された内容を表示し直すために使う
+        pub new_post: Option<NewPost>,
+        pub error: Option<&'static str>,
     }
 
     #[derive(Template)]
@@ -100,5 +658,112 @@ mod templates {
     pub struct EditPostTemplate {
         pub context: AppContext,
         pub post: Post,
+        pub error: Option<&'static str>,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/conflict.html")]
+    pub struct ConflictTemplate {
+        pub context: AppContext,
+        /// 保存しようとしたPost
+        pub post: Post,
+        /// 現在保存されているPost
+        pub current: Post,
+        pub title_diff: Diff,
+        pub body_diff: Diff,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/revisions.html")]
+    pub struct RevisionsTemplate {
+        pub context: AppContext,
+        pub post: Post,
+        pub revisions: Vec<Revision>,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/revision.html")]
+    pub struct RevisionTemplate {
+        pub context: AppContext,
+        pub revision: Revision,
+        pub title_diff: Diff,
+        pub body_diff: Diff,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/diff.html")]
+    pub struct DiffTemplate {
+        pub context: AppContext,
+        pub from: Revision,
+        pub to: Revision,
+        pub title_diff: Diff,
+        pub body_diff: Diff,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/trash.html")]
+    pub struct TrashTemplate {
+        pub context: AppContext,
+        pub posts: Vec<Post>,
+        pub retention_days: u32,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/pages.html")]
+    pub struct PagesTemplate {
+        pub context: AppContext,
+        pub pages: Vec<Page>,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/edit_page.html")]
+    pub struct EditPageTemplate {
+        pub context: AppContext,
+        /// 新しく作成するときは`None`
+        pub page: Option<Page>,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/series.html")]
+    pub struct SeriesListTemplate {
+        pub context: AppContext,
+        pub series: Vec<Series>,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/edit_series.html")]
+    pub struct EditSeriesTemplate {
+        pub context: AppContext,
+        pub series: SeriesPosts,
+    }
+
+    #[derive(Template)]
+    #[template(path = "admin/media.html")]
+    pub struct MediaTemplate {
+        pub context: AppContext,
+        pub media: Vec<Media>,
+    }
+
+    trait MediaExt {
+        /// 一覧に表示する画像のパス
+        fn thumbnail_path(&self) -> String;
+        /// 本文に貼りつける記法。画像でなければURLを返します
+        fn snippet(&self) -> String;
+    }
+
+    impl MediaExt for Media {
+        fn thumbnail_path(&self) -> String {
+            format!("/media/{}", self.variant_name(MediaVariant::Thumbnail))
+        }
+
+        fn snippet(&self) -> String {
+            match self.variant_dimensions(MediaVariant::Large) {
+                Some((width, height)) => format!(
+                    "[img /media/{} {width}x{height}]",
+                    self.variant_name(MediaVariant::Large)
+                ),
+                None => format!("/media/{}", self.name),
+            }
+        }
     }
 }
@@ -1,23 +1,108 @@
+use crate::context::AppContext;
 use crate::{Error, Service};
-use actix_web::{web, HttpResponse};
-use application::use_cases::{GetDaysInYearMonthUseCase, GetYearMonthsUseCase};
+use actix_web::{http::header, web, HttpResponse};
+use application::models::{AdjacentPageInfo, Audience, MonthDay};
+use application::use_cases::{
+    GetDaysInYearMonthUseCase, GetMonthlyStatsUseCase, GetPostsOnThisDayUseCase,
+    GetRandomPostsUseCase, GetTagsUseCase, GetYearMonthsUseCase,
+};
 
 use super::{
-    args::YearMonthArguments,
-    responses::{DaysResponse, YearMonthsResponse},
+    args::{CountQuery, MonthDayArguments, PageQuery, YearMonthArguments},
+    responses::{
+        DaysResponse, MonthlyStatsResponse, PostsOnThisDayResponse, PostsResponse, TagsResponse,
+        YearMonthsResponse,
+    },
 };
 
 pub async fn days_in_year_month(
+    context: AppContext,
     service: web::Data<Service>,
     args: web::Path<YearMonthArguments>,
 ) -> Result<HttpResponse, Error> {
-    let days =
-        GetDaysInYearMonthUseCase::execute(&service.search_client, &args.into_inner().try_into()?)
-            .await?;
+    let days = GetDaysInYearMonthUseCase::execute(
+        &*service.post_query_repository,
+        &args.into_inner().try_into()?,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
     Ok(HttpResponse::Ok().json(DaysResponse { days }))
 }
 
-pub async fn year_months(service: web::Data<Service>) -> Result<HttpResponse, Error> {
-    let year_months = GetYearMonthsUseCase::execute(&service.search_client).await?;
+pub async fn year_months(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let year_months = GetYearMonthsUseCase::execute(
+        &*service.post_query_repository,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
     Ok(HttpResponse::Ok().json(YearMonthsResponse { year_months }))
 }
+
+pub async fn tags(context: AppContext, service: web::Data<Service>) -> Result<HttpResponse, Error> {
+    let tags = GetTagsUseCase::execute(
+        &*service.post_query_repository,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(TagsResponse { tags }))
+}
+
+pub async fn monthly_stats(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let months = GetMonthlyStatsUseCase::execute(
+        &*service.post_query_repository,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(MonthlyStatsResponse { months }))
+}
+
+pub async fn posts_on_this_day(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Path<MonthDayArguments>,
+    query: web::Query<PageQuery>,
+) -> Result<HttpResponse, Error> {
+    let month_day: MonthDay = args.into_inner().try_into()?;
+    let page = GetPostsOnThisDayUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &month_day,
+        query.into_inner().try_into()?,
+        context.config.pagination.archives.get(),
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    // 次の日へのリンクはHTMLのページにだけ出す
+    let next_page = match page.next_page {
+        Some(AdjacentPageInfo::PageIndex(page)) => Some(page.into()),
+        _ => None,
+    };
+    Ok(HttpResponse::Ok().json(PostsOnThisDayResponse {
+        month_day,
+        posts: page.posts,
+        next_page,
+    }))
+}
+
+pub async fn random_posts(
+    context: AppContext,
+    service: web::Data<Service>,
+    query: web::Query<CountQuery>,
+) -> Result<HttpResponse, Error> {
+    let posts = GetRandomPostsUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        query.count(5, 20),
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    Ok(HttpResponse::Ok()
+        .append_header((header::CACHE_CONTROL, "no-store"))
+        .json(PostsResponse { posts }))
+}
@@ -0,0 +1,75 @@
+use super::args::YearArguments;
+use crate::context::AppContext;
+use crate::{Error, Service};
+use actix_web::{web, HttpResponse};
+use application::models::{Audience, YearArchive};
+use application::use_cases::{GetArchiveUseCase, GetYearArchiveUseCase};
+use askama_actix::TemplateToResponse;
+use templates::{ArchiveTemplate, YearTemplate};
+
+pub async fn year(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Path<YearArguments>,
+) -> Result<HttpResponse, Error> {
+    let year = GetYearArchiveUseCase::execute(
+        &*service.post_query_repository,
+        args.year,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    match year {
+        Some(YearArchive {
+            archive,
+            prev_year,
+            next_year,
+        }) => Ok(YearTemplate {
+            context,
+            archive,
+            prev_year,
+            next_year,
+        }
+        .to_response()),
+        None => Err(Error::NoResult("記事が存在しません。".to_owned())),
+    }
+}
+
+pub async fn archive(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let years = GetArchiveUseCase::execute(
+        &*service.post_query_repository,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    if years.is_empty() {
+        return Err(Error::NoResult("記事が存在しません。".to_owned()));
+    }
+    Ok(ArchiveTemplate { context, years }.to_response())
+}
+
+mod templates {
+    use crate::context::AppContext;
+    use crate::filters;
+    use application::models::ArchiveYear;
+    use askama::Template;
+
+    #[derive(Template)]
+    #[template(path = "year.html")]
+    pub struct YearTemplate {
+        pub context: AppContext,
+        pub archive: ArchiveYear,
+        /// 記事のある前の年
+        pub prev_year: Option<u16>,
+        /// 記事のある次の年
+        pub next_year: Option<u16>,
+    }
+
+    #[derive(Template)]
+    #[template(path = "archive.html")]
+    pub struct ArchiveTemplate {
+        pub context: AppContext,
+        pub years: Vec<ArchiveYear>,
+    }
+}
@@ -1,9 +1,11 @@
+use actix_multipart::form::{self as multipart, MultipartForm};
 use anyhow::anyhow;
 use application::{
     errors::ApplicationError,
-    models::{PageNumber, YearMonth},
+    models::{MonthDay, PageCursor, PageNumber, YearMonth},
 };
-use chrono::NaiveDate;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone as _, Utc};
+use domain::entities::{PageName, PostStatus, PostVisibility, Slug, Tag};
 use serde::Deserialize;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -17,6 +19,24 @@ pub struct DateArguments {
     day: u8,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlugArguments {
+    year: u16,
+    month: u8,
+    day: u8,
+    slug: String,
+}
+
+impl SlugArguments {
+    pub fn date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year as i32, self.month as u32, self.day as u32)
+    }
+
+    pub fn slug(&self) -> Result<Slug, domain::Error> {
+        Slug::new(&self.slug)
+    }
+}
+
 impl TryFrom<DateArguments> for NaiveDate {
     type Error = anyhow::Error;
 
@@ -26,6 +46,11 @@ impl TryFrom<DateArguments> for NaiveDate {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct YearArguments {
+    pub year: u16,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct YearMonthArguments {
     year: u16,
@@ -39,6 +64,31 @@ impl TryFrom<YearMonthArguments> for YearMonth {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct MonthDayArguments {
+    month: u8,
+    day: u8,
+}
+
+impl TryFrom<MonthDayArguments> for MonthDay {
+    type Error = ApplicationError;
+    fn try_from(args: MonthDayArguments) -> Result<MonthDay, Self::Error> {
+        MonthDay::new(args.month, args.day)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TagArguments {
+    name: String,
+}
+
+impl TryFrom<TagArguments> for Tag {
+    type Error = domain::Error;
+    fn try_from(args: TagArguments) -> Result<Tag, Self::Error> {
+        Tag::new(args.name)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct PageQuery {
     page: Option<usize>,
@@ -58,6 +108,8 @@ impl TryFrom<PageQuery> for PageNumber {
 pub struct KeywordsQuery {
     page: Option<usize>,
     pub keywords: Option<String>,
+    before: Option<String>,
+    after: Option<String>,
 }
 
 impl KeywordsQuery {
@@ -67,19 +119,182 @@ impl KeywordsQuery {
             None => Ok(PageNumber::default()),
         }
     }
+
+    pub fn position(&self) -> Result<PagePosition, ApplicationError> {
+        parse_position(self.page, self.before.as_deref(), self.after.as_deref())
+    }
+}
+
+/// 無作為に選ぶ記事の数
+#[derive(Debug, Clone, Deserialize)]
+pub struct CountQuery {
+    count: Option<usize>,
+}
+
+impl CountQuery {
+    /// 多すぎる数を指定されても`max`件までにします
+    pub fn count(&self, default: usize, max: usize) -> usize {
+        self.count.unwrap_or(default).min(max)
+    }
+}
+
+/// `?page=`か`?before=`、`?after=`のどれかでページを指定するクエリ
+#[derive(Debug, Clone, Deserialize)]
+pub struct PositionQuery {
+    page: Option<usize>,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+impl PositionQuery {
+    pub fn position(&self) -> Result<PagePosition, ApplicationError> {
+        parse_position(self.page, self.before.as_deref(), self.after.as_deref())
+    }
+}
+
+/// 記事の流れのページの位置。ページ番号は以前のURLのために残している
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PagePosition {
+    Number(PageNumber),
+    Cursor(PageCursor),
+}
+
+fn parse_position(
+    page: Option<usize>,
+    before: Option<&str>,
+    after: Option<&str>,
+) -> Result<PagePosition, ApplicationError> {
+    match (page, before, after) {
+        (Some(page), None, None) => Ok(PagePosition::Number(PageNumber::new(page)?)),
+        (None, Some(before), None) => Ok(PagePosition::Cursor(PageCursor::Before(before.parse()?))),
+        (None, None, Some(after)) => Ok(PagePosition::Cursor(PageCursor::After(after.parse()?))),
+        (None, None, None) => Ok(PagePosition::Cursor(PageCursor::Latest)),
+        _ => Err(ApplicationError::InvalidCursor),
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateFormParams {
     pub title: String,
     pub body: String,
+    #[serde(default)]
+    pub status: PostStatus,
+    #[serde(default)]
+    pub visibility: PostVisibility,
+    #[serde(default)]
+    published_at: Option<String>,
+    #[serde(default)]
+    tags: String,
+    #[serde(default)]
+    slug: String,
+    #[serde(default)]
+    summary: String,
+}
+
+impl CreateFormParams {
+    pub fn published_at(&self) -> anyhow::Result<Option<DateTime<Utc>>> {
+        parse_published_at(self.published_at.as_deref())
+    }
+
+    pub fn tags(&self) -> Result<Vec<Tag>, domain::Error> {
+        parse_tags(&self.tags)
+    }
+
+    pub fn slug(&self) -> Result<Option<Slug>, domain::Error> {
+        parse_slug(&self.slug)
+    }
+
+    pub fn summary(&self) -> Option<String> {
+        parse_summary(&self.summary)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateFormParams {
     pub id: i32,
+    /// 編集を始めたときのPostのバージョン
+    pub version: i32,
     pub title: String,
     pub body: String,
+    #[serde(default)]
+    pub status: PostStatus,
+    #[serde(default)]
+    pub visibility: PostVisibility,
+    #[serde(default)]
+    published_at: Option<String>,
+    #[serde(default)]
+    tags: String,
+    #[serde(default)]
+    slug: String,
+    #[serde(default)]
+    summary: String,
+}
+
+impl UpdateFormParams {
+    pub fn published_at(&self) -> anyhow::Result<Option<DateTime<Utc>>> {
+        parse_published_at(self.published_at.as_deref())
+    }
+
+    pub fn tags(&self) -> Result<Vec<Tag>, domain::Error> {
+        parse_tags(&self.tags)
+    }
+
+    pub fn slug(&self) -> Result<Option<Slug>, domain::Error> {
+        parse_slug(&self.slug)
+    }
+
+    pub fn summary(&self) -> Option<String> {
+        parse_summary(&self.summary)
+    }
+}
+
+/// 空欄なら`None`を返します
+fn parse_summary(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+/// 空欄なら`None`を返します
+fn parse_slug(value: &str) -> Result<Option<Slug>, domain::Error> {
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+    Slug::new(value).map(Some)
+}
+
+/// 空白かカンマで区切られたタグを重複を除いて返します
+fn parse_tags(value: &str) -> Result<Vec<Tag>, domain::Error> {
+    let mut tags = vec![];
+    for name in value.split(|c: char| c.is_whitespace() || c == ',' || c == '、') {
+        if name.is_empty() {
+            continue;
+        }
+        let tag = Tag::new(name)?;
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    Ok(tags)
+}
+
+/// `<input type="datetime-local">`の値をローカル時刻として解釈します。空なら`None`を返します
+fn parse_published_at(value: Option<&str>) -> anyhow::Result<Option<DateTime<Utc>>> {
+    let Some(value) = value.filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    let datetime = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M")
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S"))?;
+    let datetime = Local
+        .from_local_datetime(&datetime)
+        .earliest()
+        .ok_or(anyhow!("invalid published_at"))?;
+    Ok(Some(datetime.with_timezone(&Utc)))
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiffArguments {
+    pub from: i32,
+    pub to: i32,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -87,6 +302,72 @@ pub struct DeleteFormParams {
     pub id: i32,
 }
 
+/// 既存のルーティングと重なるためページ名に使えない名前
+const RESERVED_PAGE_NAMES: &[&str] = &[
+    "admin", "api", "archive", "atom", "login", "logout", "media", "random", "series", "static",
+    "tags",
+];
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PageNameArguments {
+    name: String,
+}
+
+impl PageNameArguments {
+    pub fn name(&self) -> Result<PageName, domain::Error> {
+        PageName::new(&self.name)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PageFormParams {
+    name: String,
+    pub title: String,
+    pub body: String,
+}
+
+impl PageFormParams {
+    pub fn name(&self) -> Result<PageName, domain::Error> {
+        let name = PageName::new(&self.name)?;
+        if RESERVED_PAGE_NAMES.contains(&name.as_str()) {
+            return Err(domain::Error::InvalidPageName);
+        }
+        Ok(name)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SeriesFormParams {
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateSeriesFormParams {
+    pub id: i32,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SeriesPostFormParams {
+    pub id: i32,
+    pub post_id: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MoveSeriesPostFormParams {
+    pub id: i32,
+    pub post_id: i32,
+    /// 正なら後ろへ、負なら前へ移動する
+    pub offset: isize,
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct UploadMediaFormParams {
+    /// 一度に複数のファイルをアップロードできる
+    #[multipart(rename = "file")]
+    pub files: Vec<multipart::bytes::Bytes>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct LoginFormParams {
     pub id_token: String,
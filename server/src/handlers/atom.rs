@@ -1,25 +1,102 @@
-use super::args::PageQuery;
+use super::args::{PagePosition, PageQuery, PositionQuery, TagArguments};
 use crate::context::AppContext;
 use crate::{Error, Service};
 use actix_web::{web, HttpResponse};
-use application::use_cases::{GetLastUpdatedDateUseCase, GetLatestPostsUseCase};
+use application::models::{AdjacentPageInfo, Audience, Cursor, PageCursor};
+use application::use_cases::{
+    GetLastUpdatedDateUseCase, GetLatestPostsByCursorUseCase, GetLatestPostsUseCase,
+    GetPostsByTagUseCase,
+};
 use askama_actix::TemplateToResponse;
+use domain::entities::Tag;
 use templates::AtomTemplate;
 
 pub async fn all_posts(
     context: AppContext,
     service: web::Data<Service>,
+    query: web::Query<PositionQuery>,
+) -> Result<HttpResponse, Error> {
+    let updated_at = GetLastUpdatedDateUseCase::execute(&*service.post_query_repository).await?;
+    match query.position()? {
+        PagePosition::Number(page_index) => {
+            let (_, page) = GetLatestPostsUseCase::execute(
+                &*service.posts_repository,
+                &*service.post_query_repository,
+                page_index,
+                context.config.pagination.feeds.get(),
+                Audience::Public,
+            )
+            .await?;
+            // 番号のページからも、続きはカーソルでたどれるようにする
+            let next_path = match (&page.next_page, page.posts.last()) {
+                (Some(_), Some(post)) => Some(cursor_path(&PageCursor::Before(Cursor::new(post)))),
+                _ => None,
+            };
+            Ok(AtomTemplate {
+                context,
+                path: format!("atom?page={page_index}"),
+                next_path,
+                updated_at,
+                page,
+            }
+            .to_response())
+        }
+        PagePosition::Cursor(cursor) => {
+            let (_, page) = GetLatestPostsByCursorUseCase::execute(
+                &*service.posts_repository,
+                &*service.post_query_repository,
+                cursor,
+                context.config.pagination.feeds.get(),
+                Audience::Public,
+            )
+            .await?;
+            let next_path = match &page.next_page {
+                Some(AdjacentPageInfo::PageIndex(next_cursor)) => Some(cursor_path(next_cursor)),
+                _ => None,
+            };
+            Ok(AtomTemplate {
+                context,
+                path: cursor_path(&cursor),
+                next_path,
+                updated_at,
+                page,
+            }
+            .to_response())
+        }
+    }
+}
+
+/// カーソルで指定したフィードのページのパスを返します
+fn cursor_path(cursor: &PageCursor) -> String {
+    match cursor {
+        PageCursor::Latest => "atom".to_owned(),
+        PageCursor::Before(cursor) => format!("atom?before={cursor}"),
+        PageCursor::After(cursor) => format!("atom?after={cursor}"),
+    }
+}
+
+pub async fn posts_with_tag(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Path<TagArguments>,
     query: web::Query<PageQuery>,
 ) -> Result<HttpResponse, Error> {
-    let updated_at = GetLastUpdatedDateUseCase::execute(&service.search_client).await?;
-    let page = GetLatestPostsUseCase::execute(
-        &service.posts_repository,
-        &service.search_client,
+    let tag: Tag = args.into_inner().try_into()?;
+    let page = GetPostsByTagUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &tag,
         query.into_inner().try_into()?,
+        context.config.pagination.feeds.get(),
+        Audience::Public,
     )
     .await?;
+    // タグのフィードではそのタグの記事の最終更新日時をフィードの更新日時とする
+    let updated_at = page.posts.iter().map(|post| post.updated_at).max();
     Ok(AtomTemplate {
         context,
+        path: format!("tags/{}/atom", urlencoding::encode(tag.name())),
+        next_path: None,
         updated_at,
         page,
     }
@@ -28,38 +105,44 @@ pub async fn all_posts(
 
 mod templates {
     use crate::filters;
-    use crate::{context::AppContext, presentation::posts::Body};
-    use application::models::{Page, PageNumber};
+    use crate::{
+        context::AppContext,
+        presentation::posts::{Body, Excerpt},
+    };
+    use application::models::{ListingSettings, Page};
     use askama::Template;
     use chrono::{DateTime, Utc};
     use domain::entities::Post;
 
     #[derive(Template)]
     #[template(path = "atom.xml")]
-    pub struct AtomTemplate<'a> {
+    pub struct AtomTemplate<'a, C, I> {
         pub context: AppContext,
+        /// フィード自身のパス
+        pub path: String,
+        /// フィードの続きのパス。カーソルでページを指定したときだけ使う
+        pub next_path: Option<String>,
         pub updated_at: Option<DateTime<Utc>>,
-        pub page: Page<'a, (), PageNumber>,
+        pub page: Page<'a, C, I>,
     }
 
     trait PostExt {
         /// 本文の段落記法をHTMLタグに変換します
         fn converted_body(&self) -> String;
+        /// 要約があれば要約を、なければ本文の抜粋を返します
+        fn excerpt(&self, settings: &ListingSettings) -> Excerpt;
     }
 
     impl PostExt for Post {
         fn converted_body(&self) -> String {
             Body::new(&self.body).to_html(false)
         }
-    }
-
-    trait ConditionToString {
-        fn to_string(&self) -> String;
-    }
 
-    impl ConditionToString for () {
-        fn to_string(&self) -> String {
-            "".to_owned()
+        fn excerpt(&self, settings: &ListingSettings) -> Excerpt {
+            match &self.summary {
+                Some(summary) => Excerpt::from_summary(summary),
+                None => Excerpt::new(&self.body, settings.excerpt_length),
+            }
         }
     }
 }
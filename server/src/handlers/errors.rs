@@ -129,24 +129,57 @@ pub fn error_500(res: ServiceResponse<BoxBody>) -> Result<ErrorHandlerResponse<B
 impl ResponseError for Error {
     fn status_code(&self) -> StatusCode {
         use application::errors::ApplicationError::{
-            InvalidPageNumber, InvalidYearMonth, JwtError, PostNotFound,
+            DuplicateMediaName, DuplicateSlug, InvalidCursor, InvalidMonthDay, InvalidPageNumber,
+            InvalidYearMonth, JwtError, MediaNotFound, PageNotFound, PostAlreadyInSeries,
+            PostConflict, PostNotFound, RevisionNotFound, SeriesNotFound,
         };
         match self {
             Self::NoResult(_) => StatusCode::NOT_FOUND,
             Self::Application(PostNotFound) => StatusCode::NOT_FOUND,
+            Self::Application(RevisionNotFound) => StatusCode::NOT_FOUND,
+            Self::Application(PostConflict(_)) => StatusCode::CONFLICT,
             Self::Application(JwtError(_)) => StatusCode::BAD_REQUEST,
             Self::Application(InvalidPageNumber) => StatusCode::BAD_REQUEST,
+            Self::Application(InvalidCursor) => StatusCode::BAD_REQUEST,
             Self::Application(InvalidYearMonth) => StatusCode::BAD_REQUEST,
+            Self::Application(InvalidMonthDay) => StatusCode::BAD_REQUEST,
+            Self::Domain(domain::Error::InvalidTag) => StatusCode::BAD_REQUEST,
+            Self::Domain(domain::Error::InvalidSlug) => StatusCode::BAD_REQUEST,
+            Self::Domain(domain::Error::InvalidPageName) => StatusCode::BAD_REQUEST,
+            Self::Application(PageNotFound) => StatusCode::NOT_FOUND,
+            Self::Application(DuplicateSlug) => StatusCode::BAD_REQUEST,
+            Self::Application(SeriesNotFound) => StatusCode::NOT_FOUND,
+            Self::Application(MediaNotFound) => StatusCode::NOT_FOUND,
+            Self::Application(DuplicateMediaName) => StatusCode::BAD_REQUEST,
+            Self::Application(PostAlreadyInSeries) => StatusCode::BAD_REQUEST,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
     fn error_response(&self) -> HttpResponse {
-        use application::errors::ApplicationError::{JwtError, PostNotFound};
+        use application::errors::ApplicationError::{
+            JwtError, MediaNotFound, PageNotFound, PostAlreadyInSeries, PostNotFound,
+            RevisionNotFound, SeriesNotFound,
+        };
         match self {
             Self::Application(PostNotFound) => HttpResponseBuilder::new(self.status_code())
                 .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
                 .body("指定されたIDの記事が見つかりませんでした。"),
+            Self::Application(PageNotFound) => HttpResponseBuilder::new(self.status_code())
+                .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
+                .body("指定されたページが見つかりませんでした。"),
+            Self::Application(SeriesNotFound) => HttpResponseBuilder::new(self.status_code())
+                .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
+                .body("指定されたIDのシリーズが見つかりませんでした。"),
+            Self::Application(PostAlreadyInSeries) => HttpResponseBuilder::new(self.status_code())
+                .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
+                .body("この記事はすでにほかのシリーズに入っています。"),
+            Self::Application(MediaNotFound) => HttpResponseBuilder::new(self.status_code())
+                .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
+                .body("指定されたIDのファイルが見つかりませんでした。"),
+            Self::Application(RevisionNotFound) => HttpResponseBuilder::new(self.status_code())
+                .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
+                .body("指定されたIDのリビジョンが見つかりませんでした。"),
             Self::NoResult(message) => HttpResponseBuilder::new(self.status_code())
                 .insert_header((header::CONTENT_TYPE, "text/html; charset=utf-8"))
                 .body(message.clone()),
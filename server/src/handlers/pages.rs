@@ -0,0 +1,62 @@
+use super::args::PageNameArguments;
+use crate::context::AppContext;
+use crate::{Error, Service};
+use actix_web::{web, HttpResponse};
+use application::errors::ApplicationError;
+use application::use_cases::GetPageUseCase;
+use askama_actix::TemplateToResponse;
+use domain::entities::PageName;
+use templates::{AboutTemplate, PageTemplate};
+
+pub async fn about(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let name = PageName::new("about")?;
+    let page = GetPageUseCase::execute(&*service.pages_repository, &name).await?;
+    Ok(AboutTemplate { context, page }.to_response())
+}
+
+pub async fn page(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Path<PageNameArguments>,
+) -> Result<HttpResponse, Error> {
+    // ページ名として使えないパスは存在しないページとして扱う
+    let name = args
+        .name()
+        .map_err(|_| Error::Application(ApplicationError::PageNotFound))?;
+    let page = GetPageUseCase::execute(&*service.pages_repository, &name).await?;
+    Ok(PageTemplate { context, page }.to_response())
+}
+
+mod templates {
+    use crate::{context::AppContext, presentation::posts::Body};
+    use askama::Template;
+    use domain::entities::Page;
+
+    #[derive(Template)]
+    #[template(path = "about.html")]
+    pub struct AboutTemplate {
+        pub context: AppContext,
+        pub page: Page,
+    }
+
+    #[derive(Template)]
+    #[template(path = "page.html")]
+    pub struct PageTemplate {
+        pub context: AppContext,
+        pub page: Page,
+    }
+
+    trait PageExt {
+        /// 本文の段落記法をHTMLタグに変換します
+        fn converted_body(&self) -> String;
+    }
+
+    impl PageExt for Page {
+        fn converted_body(&self) -> String {
+            Body::new(&self.body).to_html(true)
+        }
+    }
+}
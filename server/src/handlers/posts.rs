@@ -1,18 +1,23 @@
-use super::args::{DateArguments, IdArguments, KeywordsQuery, PageQuery, YearMonthArguments};
+use super::args::{
+    DateArguments, IdArguments, KeywordsQuery, MonthDayArguments, PagePosition, PageQuery,
+    SlugArguments, TagArguments, YearMonthArguments,
+};
 use crate::context::AppContext;
-use crate::{Error, Service};
-use actix_web::{web, HttpResponse};
-use application::models::YearMonth;
+use crate::{filters, Error, Service};
+use actix_web::{http::header, web, HttpResponse};
+use application::models::{Audience, MonthDay, PageCursor, PageNumber, YearMonth};
 use application::use_cases::{
-    GetLatestPostsUseCase, GetPostByIdUseCase, GetPostsByDateUseCase, GetPostsByYearMonthUseCase,
-    SearchPostsUseCase,
+    GetLatestPostsByCursorUseCase, GetLatestPostsUseCase, GetPostByIdUseCase,
+    GetPostIdBySlugUseCase, GetPostsByDateUseCase, GetPostsByTagUseCase,
+    GetPostsByYearMonthUseCase, GetPostsOnThisDayUseCase, GetRandomPostsUseCase,
+    GetSeriesNavigationUseCase, SearchPostsUseCase,
 };
 use askama_actix::TemplateToResponse;
-use chrono::NaiveDate;
-use domain::entities::PostId;
+use chrono::{Datelike as _, Local, NaiveDate};
+use domain::entities::{Post, PostId, Tag};
 use templates::{
-    AllPostsTemplate, PostTemplate, PostsWithDateTemplate, PostsWithYearMonthTemplate,
-    SearchPostsTemplate,
+    AllPostsTemplate, LatestPostsTemplate, PostTemplate, PostsOnThisDayTemplate,
+    PostsWithDateTemplate, PostsWithTagTemplate, PostsWithYearMonthTemplate, SearchPostsTemplate,
 };
 
 pub async fn all_posts(
@@ -23,10 +28,13 @@ pub async fn all_posts(
     if let Some(keywords) = &query.keywords {
         let keywords = keywords.split_whitespace().collect::<Vec<_>>();
         let page = SearchPostsUseCase::execute(
-            &service.search_client,
-            &service.posts_repository,
+            &*service.search_index,
+            &*service.post_query_repository,
+            &*service.posts_repository,
             &keywords,
             query.page_index()?,
+            context.config.pagination.search.get(),
+            Audience::new(context.is_authorized),
         )
         .await?;
         if page.posts.is_empty() {
@@ -36,31 +44,186 @@ pub async fn all_posts(
         }
         Ok(SearchPostsTemplate { context, page }.to_response())
     } else {
-        let page = GetLatestPostsUseCase::execute(
-            &service.posts_repository,
-            &service.search_client,
-            query.page_index()?,
-        )
-        .await?;
-        if page.posts.is_empty() {
-            return Err(Error::NoResult(
-                "このページには記事が存在しません。".to_owned(),
-            ));
+        match query.position()? {
+            PagePosition::Number(page_index) => {
+                let (pinned_posts, mut page) = GetLatestPostsUseCase::execute(
+                    &*service.posts_repository,
+                    &*service.post_query_repository,
+                    page_index,
+                    context.config.pagination.front_page.get(),
+                    Audience::new(context.is_authorized),
+                )
+                .await?;
+                if page.posts.is_empty() {
+                    return Err(Error::NoResult(
+                        "このページには記事が存在しません。".to_owned(),
+                    ));
+                }
+                // 先頭に固定した記事は一覧の中では繰り返さない
+                page.posts
+                    .retain(|post| !pinned_posts.iter().any(|pinned| pinned.id == post.id));
+                let on_this_day = if page.index.0 == 1 {
+                    get_on_this_day(&context, &service).await?
+                } else {
+                    vec![]
+                };
+                Ok(AllPostsTemplate {
+                    context,
+                    page,
+                    pinned_posts,
+                    on_this_day,
+                }
+                .to_response())
+            }
+            PagePosition::Cursor(cursor) => {
+                let (pinned_posts, mut page) = GetLatestPostsByCursorUseCase::execute(
+                    &*service.posts_repository,
+                    &*service.post_query_repository,
+                    cursor,
+                    context.config.pagination.front_page.get(),
+                    Audience::new(context.is_authorized),
+                )
+                .await?;
+                if page.posts.is_empty() {
+                    return Err(Error::NoResult(
+                        "このページには記事が存在しません。".to_owned(),
+                    ));
+                }
+                page.posts
+                    .retain(|post| !pinned_posts.iter().any(|pinned| pinned.id == post.id));
+                let on_this_day = if page.index == PageCursor::Latest {
+                    get_on_this_day(&context, &service).await?
+                } else {
+                    vec![]
+                };
+                Ok(LatestPostsTemplate {
+                    context,
+                    page,
+                    pinned_posts,
+                    on_this_day,
+                }
+                .to_response())
+            }
         }
-        Ok(AllPostsTemplate { context, page }.to_response())
     }
 }
 
+/// トップページに表示する過去の同じ日付の記事を返します
+async fn get_on_this_day(context: &AppContext, service: &Service) -> Result<Vec<Post>, Error> {
+    if !context.config.front_page.on_this_day {
+        return Ok(vec![]);
+    }
+    let today = Local::now().date_naive();
+    let month_day = MonthDay::from(today);
+    let page = GetPostsOnThisDayUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &month_day,
+        PageNumber::default(),
+        context.config.pagination.archives.get(),
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    // 今年の記事は一覧にあるので過去の年だけにする
+    Ok(page
+        .posts
+        .into_iter()
+        .filter(|post| post.created_at.with_timezone(&Local).year() < today.year())
+        .collect())
+}
+
 pub async fn post_with_id(
     context: AppContext,
     service: web::Data<Service>,
     args: web::Path<IdArguments>,
 ) -> Result<HttpResponse, Error> {
     let post_id = PostId(args.id);
-    let page =
-        GetPostByIdUseCase::execute(&service.posts_repository, &service.search_client, &post_id)
-            .await?;
-    Ok(PostTemplate { context, page }.to_response())
+    let page = GetPostByIdUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &post_id,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    // スラッグがあればそちらを正規のURLとする
+    if page.posts[0].slug.is_some() {
+        return Ok(HttpResponse::MovedPermanently()
+            .append_header((
+                header::LOCATION,
+                format!("/{}", filters::post_path(&page.posts[0])?),
+            ))
+            .finish());
+    }
+    let series = GetSeriesNavigationUseCase::execute(
+        &*service.series_repository,
+        &*service.posts_repository,
+        &post_id,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    Ok(PostTemplate {
+        context,
+        page,
+        series,
+    }
+    .to_response())
+}
+
+pub async fn post_with_slug(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Path<SlugArguments>,
+) -> Result<HttpResponse, Error> {
+    let post_id =
+        GetPostIdBySlugUseCase::execute(&*service.posts_repository, &args.slug()?).await?;
+    let page = GetPostByIdUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &post_id,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    // 日付が記事と合っていなければ正規のURLに転送する
+    let post = &page.posts[0];
+    if args.date() != Some(post.created_at.with_timezone(&Local).date_naive()) {
+        return Ok(HttpResponse::MovedPermanently()
+            .append_header((header::LOCATION, format!("/{}", filters::post_path(post)?)))
+            .finish());
+    }
+    let series = GetSeriesNavigationUseCase::execute(
+        &*service.series_repository,
+        &*service.posts_repository,
+        &post_id,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    Ok(PostTemplate {
+        context,
+        page,
+        series,
+    }
+    .to_response())
+}
+
+pub async fn random(
+    context: AppContext,
+    service: web::Data<Service>,
+) -> Result<HttpResponse, Error> {
+    let posts = GetRandomPostsUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        1,
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    let Some(post) = posts.first() else {
+        return Err(Error::NoResult("記事が存在しません。".to_owned()));
+    };
+    // 毎回違う記事に転送するのでキャッシュさせない
+    Ok(HttpResponse::Found()
+        .append_header((header::LOCATION, format!("/{}", filters::post_path(post)?)))
+        .append_header((header::CACHE_CONTROL, "no-store"))
+        .finish())
 }
 
 pub async fn posts_with_date(
@@ -71,10 +234,12 @@ pub async fn posts_with_date(
 ) -> Result<HttpResponse, Error> {
     let date: NaiveDate = args.into_inner().try_into()?; // TODO: map to 404
     let page = GetPostsByDateUseCase::execute(
-        &service.posts_repository,
-        &service.search_client,
+        &*service.posts_repository,
+        &*service.post_query_repository,
         &date,
         query.into_inner().try_into()?,
+        context.config.pagination.archives.get(),
+        Audience::new(context.is_authorized),
     )
     .await?;
     if page.posts.is_empty() {
@@ -93,10 +258,12 @@ pub async fn posts_with_year_month(
 ) -> Result<HttpResponse, Error> {
     let year_month: YearMonth = args.into_inner().try_into()?;
     let page = GetPostsByYearMonthUseCase::execute(
-        &service.posts_repository,
-        &service.search_client,
+        &*service.posts_repository,
+        &*service.post_query_repository,
         &year_month,
         query.into_inner().try_into()?,
+        context.config.pagination.archives.get(),
+        Audience::new(context.is_authorized),
     )
     .await?;
     if page.posts.is_empty() {
@@ -107,13 +274,65 @@ pub async fn posts_with_year_month(
     Ok(PostsWithYearMonthTemplate { context, page }.to_response())
 }
 
+pub async fn posts_on_this_day(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Path<MonthDayArguments>,
+    query: web::Query<PageQuery>,
+) -> Result<HttpResponse, Error> {
+    let month_day: MonthDay = args.into_inner().try_into()?;
+    let page = GetPostsOnThisDayUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &month_day,
+        query.into_inner().try_into()?,
+        context.config.pagination.archives.get(),
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    if page.posts.is_empty() {
+        return Err(Error::NoResult(
+            "この日付には記事が存在しません。".to_owned(),
+        ));
+    }
+    Ok(PostsOnThisDayTemplate { context, page }.to_response())
+}
+
+pub async fn posts_with_tag(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Path<TagArguments>,
+    query: web::Query<PageQuery>,
+) -> Result<HttpResponse, Error> {
+    let tag: Tag = args.into_inner().try_into()?;
+    let page = GetPostsByTagUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &tag,
+        query.into_inner().try_into()?,
+        context.config.pagination.archives.get(),
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    if page.posts.is_empty() {
+        return Err(Error::NoResult("このタグの記事は存在しません。".to_owned()));
+    }
+    Ok(PostsWithTagTemplate { context, page }.to_response())
+}
+
 mod templates {
     use crate::filters;
-    use crate::{context::AppContext, presentation::posts::Body};
-    use application::models::{AdjacentPageInfo, Page, PageNumber, YearMonth};
+    use crate::{
+        context::AppContext,
+        presentation::posts::{Body, Excerpt},
+    };
+    use application::models::{
+        AdjacentPageInfo, ListingSettings, MonthDay, Page, PageCursor, PageNumber,
+        SeriesNavigation, YearMonth,
+    };
     use askama::Template;
     use chrono::NaiveDate;
-    use domain::entities::{Post, PostId};
+    use domain::entities::{Post, PostId, Tag};
     use urlencoding::encode;
 
     #[derive(Template)]
@@ -121,6 +340,22 @@ mod templates {
     pub struct AllPostsTemplate<'a> {
         pub context: AppContext,
         pub page: Page<'a, (), PageNumber>,
+        /// 先頭に固定した記事。1ページ目でだけ表示する
+        pub pinned_posts: Vec<Post>,
+        /// 過去の同じ日付の記事。1ページ目でだけ表示する
+        pub on_this_day: Vec<Post>,
+    }
+
+    /// カーソルでページを指定したトップページ
+    #[derive(Template)]
+    #[template(path = "all_posts.html")]
+    pub struct LatestPostsTemplate<'a> {
+        pub context: AppContext,
+        pub page: Page<'a, (), PageCursor>,
+        /// 先頭に固定した記事。最新のページでだけ表示する
+        pub pinned_posts: Vec<Post>,
+        /// 過去の同じ日付の記事。最新のページでだけ表示する
+        pub on_this_day: Vec<Post>,
     }
 
     #[derive(Template)]
@@ -143,22 +378,124 @@ mod templates {
         pub page: Page<'a, NaiveDate, PageNumber>,
     }
 
+    #[derive(Template)]
+    #[template(path = "posts.html")]
+    pub struct PostsOnThisDayTemplate<'a> {
+        pub context: AppContext,
+        pub page: Page<'a, MonthDay, PageNumber>,
+    }
+
+    #[derive(Template)]
+    #[template(path = "posts.html")]
+    pub struct PostsWithTagTemplate<'a> {
+        pub context: AppContext,
+        pub page: Page<'a, Tag, PageNumber>,
+    }
+
     #[derive(Template)]
     #[template(path = "posts.html")]
     pub struct PostTemplate<'a> {
         pub context: AppContext,
         pub page: Page<'a, PostId, ()>,
+        pub series: Option<SeriesNavigation>,
+    }
+
+    trait SeriesExt {
+        /// 記事が入っているシリーズの前後の記事を返します。記事一覧では表示しない
+        fn series(&self) -> Option<&SeriesNavigation> {
+            None
+        }
+    }
+
+    impl SeriesExt for AllPostsTemplate<'_> {}
+    impl SeriesExt for LatestPostsTemplate<'_> {}
+    impl SeriesExt for SearchPostsTemplate<'_> {}
+    impl SeriesExt for PostsWithYearMonthTemplate<'_> {}
+    impl SeriesExt for PostsWithDateTemplate<'_> {}
+    impl SeriesExt for PostsOnThisDayTemplate<'_> {}
+    impl SeriesExt for PostsWithTagTemplate<'_> {}
+
+    impl SeriesExt for PostTemplate<'_> {
+        fn series(&self) -> Option<&SeriesNavigation> {
+            self.series.as_ref()
+        }
+    }
+
+    trait FrontPageExt {
+        /// 先頭に固定した記事を返します。検索結果では表示しない
+        fn pinned_posts(&self) -> &[Post] {
+            &[]
+        }
+
+        /// 過去の同じ日付の記事を返します。検索結果では表示しない
+        fn on_this_day(&self) -> &[Post] {
+            &[]
+        }
+    }
+
+    impl FrontPageExt for SearchPostsTemplate<'_> {}
+
+    impl FrontPageExt for AllPostsTemplate<'_> {
+        fn pinned_posts(&self) -> &[Post] {
+            &self.pinned_posts
+        }
+
+        fn on_this_day(&self) -> &[Post] {
+            &self.on_this_day
+        }
+    }
+
+    impl FrontPageExt for LatestPostsTemplate<'_> {
+        fn pinned_posts(&self) -> &[Post] {
+            &self.pinned_posts
+        }
+
+        fn on_this_day(&self) -> &[Post] {
+            &self.on_this_day
+        }
     }
 
     trait PostExt {
         /// 本文の段落記法をHTMLタグに変換します
         fn converted_body(&self) -> String;
+        /// 要約があれば要約を、なければ本文の抜粋を返します
+        fn excerpt(&self, settings: &ListingSettings) -> Excerpt;
     }
 
     impl PostExt for Post {
         fn converted_body(&self) -> String {
             Body::new(&self.body).to_html(true)
         }
+
+        fn excerpt(&self, settings: &ListingSettings) -> Excerpt {
+            match &self.summary {
+                Some(summary) => Excerpt::from_summary(summary),
+                None => Excerpt::new(&self.body, settings.excerpt_length),
+            }
+        }
+    }
+
+    trait PageExt {
+        /// 記事一覧のページかどうかを返します。一覧でなければ常に本文をすべて表示する
+        fn is_listing(&self) -> bool;
+    }
+
+    impl<C> PageExt for Page<'_, C, PageNumber> {
+        fn is_listing(&self) -> bool {
+            true
+        }
+    }
+
+    impl<C> PageExt for Page<'_, C, PageCursor> {
+        fn is_listing(&self) -> bool {
+            true
+        }
+    }
+
+    impl PageExt for Page<'_, PostId, ()> {
+        fn is_listing(&self) -> bool {
+            false
+        }
     }
 
     trait KeywordsConditionExt {
@@ -181,6 +518,12 @@ mod templates {
         }
     }
 
+    impl ConditionToString for MonthDay {
+        fn to_string(&self) -> String {
+            format!("{:02}-{:02}", self.month, self.day)
+        }
+    }
+
     impl ConditionToString for Vec<&str> {
         fn to_string(&self) -> String {
             format!("keywords({})", self.join(", "))
@@ -214,6 +557,26 @@ mod templates {
         }
     }
 
+    impl ConditionToUrl for Page<'_, (), PageCursor> {
+        fn next_href(&self) -> Option<String> {
+            match self.next_page {
+                Some(AdjacentPageInfo::PageIndex(PageCursor::Before(cursor))) => {
+                    Some(format!("/?before={cursor}"))
+                }
+                _ => None,
+            }
+        }
+
+        fn prev_href(&self) -> Option<String> {
+            match self.prev_page {
+                Some(AdjacentPageInfo::PageIndex(PageCursor::After(cursor))) => {
+                    Some(format!("/?after={cursor}"))
+                }
+                _ => None,
+            }
+        }
+    }
+
     impl ConditionToUrl for Page<'_, PostId, ()> {
         fn next_href(&self) -> Option<String> {
             match self.next_page {
@@ -306,6 +669,52 @@ mod templates {
         }
     }
 
+    impl ConditionToUrl for Page<'_, MonthDay, PageNumber> {
+        fn next_href(&self) -> Option<String> {
+            match self.next_page {
+                Some(AdjacentPageInfo::Condition(md)) => Some(format!("/on/{}", md.to_string())),
+                Some(AdjacentPageInfo::PageIndex(page)) => {
+                    Some(format!("/on/{}?page={}", self.condition.to_string(), page))
+                }
+                _ => None,
+            }
+        }
+
+        fn prev_href(&self) -> Option<String> {
+            match self.prev_page {
+                Some(AdjacentPageInfo::Condition(md)) => Some(format!("/on/{}", md.to_string())),
+                Some(AdjacentPageInfo::PageIndex(page)) => {
+                    Some(format!("/on/{}?page={}", self.condition.to_string(), page))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    impl ConditionToUrl for Page<'_, Tag, PageNumber> {
+        fn next_href(&self) -> Option<String> {
+            match self.next_page {
+                Some(AdjacentPageInfo::PageIndex(page)) => Some(format!(
+                    "/tags/{}?page={}",
+                    encode(self.condition.name()),
+                    page
+                )),
+                _ => None,
+            }
+        }
+
+        fn prev_href(&self) -> Option<String> {
+            match self.prev_page {
+                Some(AdjacentPageInfo::PageIndex(page)) => Some(format!(
+                    "/tags/{}?page={}",
+                    encode(self.condition.name()),
+                    page
+                )),
+                _ => None,
+            }
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -316,5 +725,11 @@ mod templates {
             let condition = YearMonth::new(1989, 9).unwrap();
             assert_eq!(condition.to_string(), "1989-09");
         }
+
+        #[test]
+        fn month_day_to_string() {
+            let condition = MonthDay::new(2, 9).unwrap();
+            assert_eq!(condition.to_string(), "02-09");
+        }
     }
 }
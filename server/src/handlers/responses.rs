@@ -1,4 +1,5 @@
-use application::models::YearMonth;
+use application::models::{MonthDay, MonthlyStats, TagCount, YearMonthCount};
+use domain::entities::Post;
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct DaysResponse {
@@ -8,5 +9,28 @@ pub struct DaysResponse {
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct YearMonthsResponse {
-    pub year_months: Vec<YearMonth>,
+    pub year_months: Vec<YearMonthCount>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TagsResponse {
+    pub tags: Vec<TagCount>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MonthlyStatsResponse {
+    pub months: Vec<MonthlyStats>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostsOnThisDayResponse {
+    pub month_day: MonthDay,
+    pub posts: Vec<Post>,
+    pub next_page: Option<usize>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PostsResponse {
+    pub posts: Vec<Post>,
 }
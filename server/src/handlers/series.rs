@@ -0,0 +1,38 @@
+use super::args::IdArguments;
+use crate::context::AppContext;
+use crate::{Error, Service};
+use actix_web::{web, HttpResponse};
+use application::models::Audience;
+use application::use_cases::GetSeriesUseCase;
+use askama_actix::TemplateToResponse;
+use domain::entities::SeriesId;
+use templates::SeriesTemplate;
+
+pub async fn series(
+    context: AppContext,
+    service: web::Data<Service>,
+    args: web::Path<IdArguments>,
+) -> Result<HttpResponse, Error> {
+    let series = GetSeriesUseCase::execute(
+        &*service.series_repository,
+        &*service.posts_repository,
+        &SeriesId(args.id),
+        Audience::new(context.is_authorized),
+    )
+    .await?;
+    Ok(SeriesTemplate { context, series }.to_response())
+}
+
+mod templates {
+    use crate::context::AppContext;
+    use crate::filters;
+    use application::models::SeriesPosts;
+    use askama::Template;
+
+    #[derive(Template)]
+    #[template(path = "series.html")]
+    pub struct SeriesTemplate {
+        pub context: AppContext,
+        pub series: SeriesPosts,
+    }
+}
@@ -1,5 +1,5 @@
 use actix_web_lab::middleware::CatchPanic;
-use clap::{ArgAction, Parser};
+use clap::{ArgAction, Parser, ValueEnum};
 use errors::Error;
 use service::Service;
 use std::path::PathBuf;
@@ -10,6 +10,7 @@ mod handlers;
 mod presentation;
 mod routers;
 mod service;
+mod tasks;
 
 #[derive(Parser, Debug, Clone)]
 #[clap(version = "11.0.0", author = "@necocen <necocen@gmail.com>")]
@@ -23,12 +24,31 @@ struct Opts {
     /// 静的ファイルの配信元ディレクトリ
     #[clap(long("static"), default_value = "./frontend/dist/assets")]
     static_path: PathBuf,
+    /// アップロードされたファイルの保存先ディレクトリ
+    #[clap(long("media"), default_value = "./media")]
+    media_path: PathBuf,
     /// 本番環境モード
     #[clap(long("production"), action = ArgAction::SetFalse)]
     is_development: bool,
     /// マイグレイションを実行する
     #[clap(long("migrate"), action = ArgAction::SetTrue)]
     migrate: bool,
+    /// 記事などの保存先
+    #[clap(long, value_enum, default_value = "database")]
+    backend: Backend,
+    /// `--backend memory`のときに最初に読み込むJSONファイル
+    #[clap(long)]
+    seed: Option<PathBuf>,
+}
+
+/// 記事などの保存先
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    /// `DATABASE_URL`のデータベース。`postgres://`ならElasticsearchも使い、`sqlite://`ならSQLiteのファイルだけを使う
+    #[value(alias = "postgres")]
+    Database,
+    /// メモリ上。プロセスが終了すると失われる
+    Memory,
 }
 
 #[actix_web::main]
@@ -38,6 +58,9 @@ async fn main() -> anyhow::Result<()> {
     dotenv::dotenv().ok();
     let opts = Opts::parse();
     let service = Service::new(&opts)?;
+    actix_web::rt::spawn(tasks::publish_scheduled_posts(service.clone()));
+    actix_web::rt::spawn(tasks::purge_expired_posts(service.clone()));
+    actix_web::rt::spawn(tasks::fill_post_stats(service.clone()));
     HttpServer::new(move || {
         App::new()
             .configure(routers::routing(service.clone()))
@@ -0,0 +1,255 @@
+use askama::Html;
+use askama_escape::escape;
+use similar::{ChangeTag, DiffTag, TextDiff};
+
+/// 2つのテキストの行単位の差分。変更された行の中は文字単位で差分をとる
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff {
+    lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    kind: DiffLineKind,
+    segments: Vec<DiffSegment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Equal,
+    Delete,
+    Insert,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffSegment {
+    text: String,
+    /// 行の中で変更された部分かどうか
+    changed: bool,
+}
+
+impl Diff {
+    pub fn new(old: &str, new: &str) -> Diff {
+        let line_diff = TextDiff::from_lines(old, new);
+        let old_lines = line_diff.old_slices();
+        let new_lines = line_diff.new_slices();
+        let mut lines = vec![];
+        for op in line_diff.ops() {
+            match op.tag() {
+                DiffTag::Equal => lines.extend(
+                    old_lines[op.old_range()]
+                        .iter()
+                        .map(|line| DiffLine::whole(DiffLineKind::Equal, line)),
+                ),
+                DiffTag::Delete => lines.extend(
+                    old_lines[op.old_range()]
+                        .iter()
+                        .map(|line| DiffLine::whole(DiffLineKind::Delete, line)),
+                ),
+                DiffTag::Insert => lines.extend(
+                    new_lines[op.new_range()]
+                        .iter()
+                        .map(|line| DiffLine::whole(DiffLineKind::Insert, line)),
+                ),
+                DiffTag::Replace => {
+                    // 日本語は単語で区切れないので、置き換えられた行どうしは文字単位で比べる
+                    let old_text = old_lines[op.old_range()].concat();
+                    let new_text = new_lines[op.new_range()].concat();
+                    let char_diff = TextDiff::from_chars(old_text.as_str(), new_text.as_str());
+                    let mut deleted = LinesBuilder::new(DiffLineKind::Delete);
+                    let mut inserted = LinesBuilder::new(DiffLineKind::Insert);
+                    for change in char_diff.iter_all_changes() {
+                        match change.tag() {
+                            ChangeTag::Equal => {
+                                deleted.push(change.value(), false);
+                                inserted.push(change.value(), false);
+                            }
+                            ChangeTag::Delete => deleted.push(change.value(), true),
+                            ChangeTag::Insert => inserted.push(change.value(), true),
+                        }
+                    }
+                    lines.extend(deleted.build());
+                    lines.extend(inserted.build());
+                }
+            }
+        }
+        Diff { lines }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines
+            .iter()
+            .all(|line| line.kind == DiffLineKind::Equal)
+    }
+
+    pub fn to_html(&self) -> String {
+        self.lines
+            .iter()
+            .map(DiffLine::to_html)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl DiffLine {
+    fn whole(kind: DiffLineKind, line: &str) -> DiffLine {
+        let text = line.strip_suffix('\n').unwrap_or(line);
+        DiffLine {
+            kind,
+            segments: vec![DiffSegment {
+                text: text.to_owned(),
+                changed: false,
+            }],
+        }
+    }
+
+    fn to_html(&self) -> String {
+        let (class, tag) = match self.kind {
+            DiffLineKind::Equal => ("equal", ""),
+            DiffLineKind::Delete => ("delete", "del"),
+            DiffLineKind::Insert => ("insert", "ins"),
+        };
+        let content = self
+            .segments
+            .iter()
+            .map(|segment| {
+                if segment.changed {
+                    format!("<{tag}>{}</{tag}>", escape(&segment.text, Html))
+                } else {
+                    escape(&segment.text, Html).to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("");
+        format!(r#"<div class="{class}">{content}</div>"#)
+    }
+}
+
+/// 文字単位の差分を行ごとにまとめる
+struct LinesBuilder {
+    kind: DiffLineKind,
+    lines: Vec<DiffLine>,
+    current: Vec<DiffSegment>,
+}
+
+impl LinesBuilder {
+    fn new(kind: DiffLineKind) -> LinesBuilder {
+        LinesBuilder {
+            kind,
+            lines: vec![],
+            current: vec![],
+        }
+    }
+
+    fn push(&mut self, text: &str, changed: bool) {
+        if text == "\n" {
+            let segments = std::mem::take(&mut self.current);
+            self.lines.push(DiffLine {
+                kind: self.kind,
+                segments,
+            });
+            return;
+        }
+        match self.current.last_mut() {
+            Some(last) if last.changed == changed => last.text.push_str(text),
+            _ => self.current.push(DiffSegment {
+                text: text.to_owned(),
+                changed,
+            }),
+        }
+    }
+
+    fn build(mut self) -> Vec<DiffLine> {
+        if !self.current.is_empty() {
+            self.lines.push(DiffLine {
+                kind: self.kind,
+                segments: self.current,
+            });
+        }
+        self.lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn segment(text: &str, changed: bool) -> DiffSegment {
+        DiffSegment {
+            text: text.to_owned(),
+            changed,
+        }
+    }
+
+    #[test]
+    fn no_changes() {
+        let diff = Diff::new("一行目\n二行目", "一行目\n二行目");
+        assert!(diff.is_empty());
+        assert_eq!(
+            diff.to_html(),
+            "<div class=\"equal\">一行目</div>\n<div class=\"equal\">二行目</div>"
+        );
+    }
+
+    #[test]
+    fn inserted_line() {
+        let diff = Diff::new("一行目\n三行目\n", "一行目\n二行目\n三行目\n");
+        assert_eq!(
+            diff.lines,
+            vec![
+                DiffLine {
+                    kind: DiffLineKind::Equal,
+                    segments: vec![segment("一行目", false)]
+                },
+                DiffLine {
+                    kind: DiffLineKind::Insert,
+                    segments: vec![segment("二行目", false)]
+                },
+                DiffLine {
+                    kind: DiffLineKind::Equal,
+                    segments: vec![segment("三行目", false)]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn replaced_characters() {
+        let diff = Diff::new("今日は晴れでした。\n", "今日は雨でした。\n");
+        assert_eq!(
+            diff.lines,
+            vec![
+                DiffLine {
+                    kind: DiffLineKind::Delete,
+                    segments: vec![
+                        segment("今日は", false),
+                        segment("晴れ", true),
+                        segment("でした。", false)
+                    ]
+                },
+                DiffLine {
+                    kind: DiffLineKind::Insert,
+                    segments: vec![
+                        segment("今日は", false),
+                        segment("雨", true),
+                        segment("でした。", false)
+                    ]
+                },
+            ]
+        );
+        assert_eq!(
+            diff.to_html(),
+            "<div class=\"delete\">今日は<del>晴れ</del>でした。</div>\n<div class=\"insert\">今日は<ins>雨</ins>でした。</div>"
+        );
+    }
+
+    #[test]
+    fn escape_html() {
+        let diff = Diff::new("<b>", "<i>");
+        assert_eq!(
+            diff.to_html(),
+            "<div class=\"delete\">&lt;<del>b</del>&gt;</div>\n<div class=\"insert\">&lt;<ins>i</ins>&gt;</div>"
+        );
+    }
+}
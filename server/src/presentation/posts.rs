@@ -1,10 +1,14 @@
+mod analyzer;
 mod body;
+mod excerpt;
 mod line;
 mod line_fragment;
 mod paragraph;
 mod topic;
 
+pub use analyzer::BodyAnalyzerImpl;
 pub use body::Body;
+pub use excerpt::Excerpt;
 use line::Line;
 use line_fragment::LineFragment;
 use paragraph::Paragraph;
@@ -0,0 +1,13 @@
+use super::Body;
+use application::adapters::BodyAnalyzer;
+use domain::entities::PostStats;
+
+/// 本文を段落記法のとおりに分解して文字数を数えます
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BodyAnalyzerImpl;
+
+impl BodyAnalyzer for BodyAnalyzerImpl {
+    fn stats(&self, body: &str) -> PostStats {
+        PostStats::new(Body::new(body).char_count())
+    }
+}
@@ -5,11 +5,20 @@ use regex::Regex;
 pub struct Body<'a>(Vec<Topic<'a>>);
 
 impl Body<'_> {
-    pub fn new(body: &str) -> Body {
+    pub fn new(body: &str) -> Body<'_> {
         let separator = Regex::new(r"\n{3,}").unwrap();
         Body(separator.split(body.trim()).map(Topic::new).collect())
     }
 
+    /// 数式やURL、画像を除いた本文の文字数を返します
+    pub fn topics(&self) -> &[Topic<'_>] {
+        &self.0
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.0.iter().map(Topic::char_count).sum()
+    }
+
     pub fn to_html(&self, yakumono: bool) -> String {
         self.0
             .iter()
@@ -67,6 +76,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn counts_chars_without_math_and_links() {
+        let body = "今日は「晴れ」。\nhttp://example.com を見た\n\n\n$$\nx^2\n$$";
+        assert_eq!(Body::new(body).char_count(), 11);
+    }
+
     #[test]
     fn has_many_linebreaks() {
         let body = "Topic 1\n\n\n\n\nTopic 2\n\n\nTopic 3\n\n\n\nTopic 4";
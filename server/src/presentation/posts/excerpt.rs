@@ -0,0 +1,183 @@
+use super::{Body, Line, LineFragment, Paragraph};
+
+/// 文の終わりとみなす文字
+const SENTENCE_TERMINATORS: &[char] = &['。', '！', '？', '．', '!', '?'];
+
+/// 一覧やフィードに表示する本文の抜粋
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Excerpt {
+    text: String,
+    is_truncated: bool,
+}
+
+impl Excerpt {
+    /// 本文の最初のトピックから、`max_chars`文字に収まるところまでを段落単位で切り出します
+    ///
+    /// 文字数は`Body::char_count`と同じく数式やURLを除いて数えます。
+    /// 最初の段落だけで`max_chars`文字を超えるときは、その範囲の最後の文末（`。`など）で切ります
+    pub fn new(body: &str, max_chars: usize) -> Excerpt {
+        let body = Body::new(body);
+        let topics = body.topics();
+        let has_more_topics = topics.len() > 1;
+
+        let mut paragraphs = vec![];
+        let mut chars = 0;
+        let mut rest = topics[0].paragraphs().iter().peekable();
+        while let Some(paragraph) = rest.next_if(|p| chars + p.char_count() <= max_chars) {
+            chars += paragraph.char_count();
+            paragraphs.push(paragraph.to_text());
+        }
+        let has_more_paragraphs = rest.peek().is_some();
+        if paragraphs.is_empty() {
+            if let Some(paragraph) = rest.next() {
+                return Excerpt {
+                    text: Self::cut_at_sentence(paragraph, max_chars),
+                    is_truncated: true,
+                };
+            }
+        }
+        Excerpt {
+            text: paragraphs.join("\n\n"),
+            is_truncated: has_more_topics || has_more_paragraphs,
+        }
+    }
+
+    /// 著者が書いた要約を抜粋として使います
+    pub fn from_summary(summary: &str) -> Excerpt {
+        Excerpt {
+            text: summary.trim().to_owned(),
+            is_truncated: true,
+        }
+    }
+
+    /// `max_chars`文字以内の最後の文末で切ります。文末がなければ`max_chars`文字で切って`…`をつけます
+    ///
+    /// 切るのは通常の行の文字の中だけで、数式や画像の行、URLは途中で切りません
+    fn cut_at_sentence(paragraph: &Paragraph, max_chars: usize) -> String {
+        let mut text = String::new();
+        let mut chars = 0;
+        let mut sentence_end = None;
+        'lines: for (index, line) in paragraph.lines().iter().enumerate() {
+            if index > 0 {
+                text.push('\n');
+            }
+            let Line::Normal(fragments) = line else {
+                if chars >= max_chars {
+                    break;
+                }
+                text += &line.to_text();
+                continue;
+            };
+            for fragment in fragments {
+                if let LineFragment::Link(link) = fragment {
+                    if chars >= max_chars {
+                        break 'lines;
+                    }
+                    text += link;
+                    continue;
+                }
+                for c in fragment.as_str().chars() {
+                    if !c.is_whitespace() {
+                        if chars >= max_chars {
+                            break 'lines;
+                        }
+                        chars += 1;
+                    }
+                    text.push(c);
+                    if SENTENCE_TERMINATORS.contains(&c) {
+                        sentence_end = Some(text.len());
+                    }
+                }
+            }
+        }
+        match sentence_end {
+            Some(end) => text[..end].to_owned(),
+            None => text.trim_end().to_owned() + "…",
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 本文の一部だけを切り出したかどうかを返します
+    pub fn is_truncated(&self) -> bool {
+        self.is_truncated
+    }
+
+    pub fn to_html(&self, yakumono: bool) -> String {
+        Body::new(&self.text).to_html(yakumono)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn short_body() {
+        let excerpt = Excerpt::new("Paragraph 1\n\nParagraph 2", 100);
+        assert_eq!(excerpt.text(), "Paragraph 1\n\nParagraph 2");
+        assert!(!excerpt.is_truncated());
+    }
+
+    #[test]
+    fn first_topic_only() {
+        let excerpt = Excerpt::new("Topic 1\n\n\nTopic 2", 100);
+        assert_eq!(excerpt.text(), "Topic 1");
+        assert!(excerpt.is_truncated());
+    }
+
+    #[test]
+    fn cut_at_paragraph() {
+        let excerpt = Excerpt::new("一つめの段落。\n\n二つめの段落。\n\n三つめの段落。", 16);
+        assert_eq!(excerpt.text(), "一つめの段落。\n\n二つめの段落。");
+        assert!(excerpt.is_truncated());
+    }
+
+    #[test]
+    fn cut_at_sentence() {
+        let excerpt = Excerpt::new("一つめの文です。二つめの文です。三つめの文です。", 20);
+        assert_eq!(excerpt.text(), "一つめの文です。二つめの文です。");
+        assert!(excerpt.is_truncated());
+    }
+
+    #[test]
+    fn count_chars_without_math_and_links() {
+        // 数式とURLを数えなければ段落が収まる
+        let body = "数式\n$$\nx^2 + y^2\n$$\nと http://example.com/a?b=c を見た。\n\n次の段落。";
+        let excerpt = Excerpt::new(body, 7);
+        assert_eq!(
+            excerpt.text(),
+            "数式\n$$\nx^2 + y^2\n$$\nと http://example.com/a?b=c を見た。"
+        );
+        assert!(excerpt.is_truncated());
+    }
+
+    #[test]
+    fn cut_without_splitting_links() {
+        let excerpt = Excerpt::new("リンクはhttp://example.com/a?b=c!です。続きの文です。", 9);
+        assert_eq!(excerpt.text(), "リンクはhttp://example.com/a?b=c!です。");
+        assert!(excerpt.is_truncated());
+    }
+
+    #[test]
+    fn cut_without_splitting_math_and_figures() {
+        let body = "はじめの文。\n$$\nx = 1. y = 2!\n$$\n[img /media/photo.jpg 640x480 写真!]\nおわりまでとても長い文";
+        let excerpt = Excerpt::new(body, 10);
+        assert_eq!(excerpt.text(), "はじめの文。");
+        assert!(excerpt.is_truncated());
+
+        let excerpt = Excerpt::new("長い文のあとの\n[img /media/photo.jpg 640x480]\nつづき", 7);
+        assert_eq!(excerpt.text(), "長い文のあとの…");
+        assert!(excerpt.is_truncated());
+    }
+
+    #[test]
+    fn cut_without_sentence_terminator() {
+        let excerpt = Excerpt::new("とても長い文がずっと続いている", 5);
+        assert_eq!(excerpt.text(), "とても長い…");
+        assert!(excerpt.is_truncated());
+    }
+}
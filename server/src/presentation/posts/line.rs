@@ -1,4 +1,6 @@
 use super::LineFragment;
+use askama::Html;
+use askama_escape::escape;
 use regex::Regex;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -7,13 +9,31 @@ pub enum Line<'a> {
     Normal(Vec<LineFragment<'a>>),
     /// 数式モードの行。リンク変換や約物アキ調整をしない。
     Math(&'a str),
+    /// `[img パス 幅x高さ キャプション]`と書いた行。<figure>で囲んだ画像にする。
+    Figure {
+        src: &'a str,
+        width: u32,
+        height: u32,
+        caption: Option<&'a str>,
+    },
 }
 
 impl Line<'_> {
-    pub fn new(line: &str) -> Line {
+    pub fn new(line: &str) -> Line<'_> {
         if line.is_empty() {
             return Line::Normal(vec![]);
         }
+        let figure_pattern = Regex::new(r"^\[img (\S+) (\d+)x(\d+)(?: (.+))?\]$").unwrap();
+        if let Some(captures) = figure_pattern.captures(line.trim()) {
+            if let (Ok(width), Ok(height)) = (captures[2].parse(), captures[3].parse()) {
+                return Line::Figure {
+                    src: captures.get(1).unwrap().as_str(),
+                    width,
+                    height,
+                    caption: captures.get(4).map(|c| c.as_str().trim()),
+                };
+            }
+        }
         let url_pattern = Regex::new(r"https?://[-_.!~*'()a-zA-Z0-9;/?:@&=+$,%#]+").unwrap();
         let mut pos: usize = 0;
         let mut fragments: Vec<LineFragment> = vec![];
@@ -31,7 +51,7 @@ impl Line<'_> {
         )
     }
 
-    pub fn new_math(math: &str) -> Line {
+    pub fn new_math(math: &str) -> Line<'_> {
         Line::Math(math)
     }
 
@@ -43,6 +63,54 @@ impl Line<'_> {
                 .collect::<Vec<_>>()
                 .join(""),
             Line::Math(math) => math.to_string(),
+            Line::Figure {
+                src,
+                width,
+                height,
+                caption,
+            } => {
+                let alt = escape(caption.unwrap_or_default(), Html).to_string();
+                let img = format!(
+                    r#"<img src="{}" width="{width}" height="{height}" alt="{alt}" loading="lazy" />"#,
+                    escape(src, Html)
+                );
+                match caption {
+                    Some(_) => format!("<figure>{img}<figcaption>{alt}</figcaption></figure>"),
+                    None => format!("<figure>{img}</figure>"),
+                }
+            }
+        }
+    }
+
+    /// 本文の記法で書いた行に戻します
+    pub fn to_text(&self) -> String {
+        match self {
+            Line::Normal(fragments) => fragments.iter().map(LineFragment::as_str).collect(),
+            Line::Math(math) => math.to_string(),
+            Line::Figure {
+                src,
+                width,
+                height,
+                caption: Some(caption),
+            } => format!("[img {src} {width}x{height} {caption}]"),
+            Line::Figure {
+                src,
+                width,
+                height,
+                caption: None,
+            } => format!("[img {src} {width}x{height}]"),
+        }
+    }
+
+    pub fn is_figure(&self) -> bool {
+        matches!(self, Line::Figure { .. })
+    }
+
+    /// 本文として読む文字数を返します。数式と画像は数えません
+    pub fn char_count(&self) -> usize {
+        match self {
+            Line::Normal(fragments) => fragments.iter().map(LineFragment::char_count).sum(),
+            Line::Math(_) | Line::Figure { .. } => 0,
         }
     }
 }
@@ -118,4 +186,32 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn has_figure() {
+        let line = Line::new("[img /media/photo.large.jpg 1280x960 夕方の<空>]");
+        assert_eq!(
+            line,
+            Line::Figure {
+                src: "/media/photo.large.jpg",
+                width: 1280,
+                height: 960,
+                caption: Some("夕方の<空>"),
+            }
+        );
+        assert_eq!(
+            line.to_html(true),
+            r#"<figure><img src="/media/photo.large.jpg" width="1280" height="960" alt="夕方の&lt;空&gt;" loading="lazy" /><figcaption>夕方の&lt;空&gt;</figcaption></figure>"#
+        );
+        assert_eq!(
+            Line::new("[img /media/photo.jpg 640x480]").to_html(true),
+            r#"<figure><img src="/media/photo.jpg" width="640" height="480" alt="" loading="lazy" /></figure>"#
+        );
+        assert_eq!(
+            line.to_text(),
+            "[img /media/photo.large.jpg 1280x960 夕方の<空>]"
+        );
+        // 大きさがなければふつうの行として扱う
+        assert!(!Line::new("[img /media/photo.jpg]").is_figure());
+    }
 }
@@ -75,6 +75,28 @@ impl LineFragment<'_> {
     }
 }
 
+impl<'a> LineFragment<'a> {
+    pub fn as_str(&self) -> &'a str {
+        use LineFragment::*;
+        match self {
+            Text(s) | Link(s) | OpenBracket(s) | CloseBracket(s) | Punctuation(s)
+            | Interpunct(s) | Other(s) => s,
+        }
+    }
+}
+
+impl LineFragment<'_> {
+    /// 空白以外の文字数を返します。URLは数えません
+    pub fn char_count(&self) -> usize {
+        use LineFragment::*;
+        match self {
+            Link(_) => 0,
+            Text(s) | OpenBracket(s) | CloseBracket(s) | Punctuation(s) | Interpunct(s)
+            | Other(s) => s.chars().filter(|c| !c.is_whitespace()).count(),
+        }
+    }
+}
+
 impl<'a> LineFragment<'a> {
     pub fn into_split(self) -> Vec<LineFragment<'a>> {
         use LineFragment::*;
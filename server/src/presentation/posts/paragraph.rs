@@ -4,7 +4,7 @@ use super::Line;
 pub struct Paragraph<'a>(Vec<Line<'a>>);
 
 impl Paragraph<'_> {
-    pub fn new(paragraph: &str) -> Paragraph {
+    pub fn new(paragraph: &str) -> Paragraph<'_> {
         // MathJaxのディスプレイ数式を検出し、その中では数式モード行にする
         let mut math_mode = false;
         let lines = paragraph.split('\n').fold(vec![], |mut acc, line| {
@@ -24,7 +24,33 @@ impl Paragraph<'_> {
         Paragraph(lines)
     }
 
+    pub fn lines(&self) -> &[Line<'_>] {
+        &self.0
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.0.iter().map(Line::char_count).sum()
+    }
+
+    /// 本文の記法で書いた段落に戻します
+    pub fn to_text(&self) -> String {
+        self.0
+            .iter()
+            .map(Line::to_text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     pub fn to_html(&self, yakumono: bool) -> String {
+        // <figure>は<p>の中に置けないので、画像だけの段落は<p>で囲まない
+        if self.0.iter().all(Line::is_figure) {
+            return self
+                .0
+                .iter()
+                .map(|line| line.to_html(yakumono))
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
         "<p>".to_owned()
             + &self
                 .0
@@ -4,10 +4,18 @@ use super::Paragraph;
 pub struct Topic<'a>(Vec<Paragraph<'a>>);
 
 impl Topic<'_> {
-    pub fn new(topic: &str) -> Topic {
+    pub fn new(topic: &str) -> Topic<'_> {
         Topic(topic.split("\n\n").map(Paragraph::new).collect())
     }
 
+    pub fn paragraphs(&self) -> &[Paragraph<'_>] {
+        &self.0
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.0.iter().map(Paragraph::char_count).sum()
+    }
+
     pub fn to_html(&self, yakumono: bool) -> String {
         self.0
             .iter()
@@ -1,11 +1,12 @@
 use crate::{
     context::{AppContext, AppContextService},
-    handlers::{about, admin, api, atom, auth, errors, posts},
+    handlers::{admin, api, archive, atom, auth, errors, pages, posts, series},
     Service,
 };
 use actix_cors::Cors;
 use actix_files::Files;
 use actix_identity::IdentityMiddleware;
+use actix_multipart::form::MultipartFormConfig;
 use actix_session::{storage::CookieSessionStore, SessionMiddleware};
 use actix_web::{
     cookie::{Key, SameSite},
@@ -19,14 +20,15 @@ use actix_web::{
 
 pub fn routing(service: Service) -> impl FnOnce(&mut ServiceConfig) {
     move |cfg: &mut ServiceConfig| {
-        let session = SessionMiddleware::builder(
-            CookieSessionStore::default(),
-            Key::derive_from(service.secret_key.as_bytes()),
-        )
-        .cookie_name("nocturne-session".to_string())
-        .cookie_same_site(SameSite::Lax)
-        .cookie_secure(!service.is_development)
-        .build();
+        let key = Key::derive_from(service.secret_key.as_bytes());
+        let is_development = service.is_development;
+        let session = || {
+            SessionMiddleware::builder(CookieSessionStore::default(), key.clone())
+                .cookie_name("nocturne-session".to_string())
+                .cookie_same_site(SameSite::Lax)
+                .cookie_secure(!is_development)
+                .build()
+        };
 
         let cors = if service.is_development {
             Cors::default().allowed_origin("http://localhost:5173")
@@ -34,11 +36,26 @@ pub fn routing(service: Service) -> impl FnOnce(&mut ServiceConfig) {
             Cors::default()
         };
         let static_path = service.static_path.clone();
+        let media_path = service.media_storage.root().to_path_buf();
 
         cfg.app_data(Data::new(service))
             .app_data(FormConfig::default().limit(1024 * 1024 * 20))
+            .app_data(
+                MultipartFormConfig::default()
+                    .total_limit(1024 * 1024 * 20)
+                    .memory_limit(1024 * 1024 * 20),
+            )
             .service(Files::new("/static", static_path))
-            .service(scope("/api").wrap(cors).configure(api))
+            .service(Files::new("/media", media_path))
+            .service(
+                // 管理者には非公開の記事がある日もカレンダーに表示する
+                scope("/api")
+                    .wrap(AppContextService)
+                    .wrap(IdentityMiddleware::default())
+                    .wrap(session())
+                    .wrap(cors)
+                    .configure(api),
+            )
             .service(
                 scope("")
                     .wrap(
@@ -50,18 +67,19 @@ pub fn routing(service: Service) -> impl FnOnce(&mut ServiceConfig) {
                     )
                     .wrap(AppContextService)
                     .wrap(IdentityMiddleware::default())
-                    .wrap(session)
+                    .wrap(session())
                     .configure(posts)
                     .configure(atom)
                     .configure(auth)
-                    .configure(about)
                     .service(
                         scope("/admin")
                             .service(scope("").guard(fn_guard(admin_guard)).configure(admin))
                             .default_service(route().to(|| async {
                                 HttpResponse::Unauthorized().body("Unauthorized")
                             })),
-                    ),
+                    )
+                    // `/{name}`はほかのルーティングに当てはまらなかったときにだけ使う
+                    .configure(pages),
             );
     }
 }
@@ -75,18 +93,35 @@ fn posts(cfg: &mut ServiceConfig) {
         .service(
             resource(r"/{year:\d{4}}-{month:\d{2}}-{day:\d{2}}")
                 .route(get().to(posts::posts_with_date)),
-        );
+        )
+        .service(
+            resource(r"/{year:\d{4}}-{month:\d{2}}-{day:\d{2}}/{slug}")
+                .route(get().to(posts::post_with_slug)),
+        )
+        .service(
+            resource(r"/on/{month:\d{2}}-{day:\d{2}}").route(get().to(posts::posts_on_this_day)),
+        )
+        .service(resource("/archive").route(get().to(archive::archive)))
+        .service(resource(r"/archive/{year:\d{4}}").route(get().to(archive::year)))
+        .service(resource("/random").route(get().to(posts::random)))
+        .service(resource("/tags/{name}").route(get().to(posts::posts_with_tag)))
+        .service(resource(r"/series/{id:\d+}").route(get().to(series::series)));
 }
 
 fn atom(cfg: &mut ServiceConfig) {
-    cfg.service(resource("/atom").route(get().to(atom::all_posts)));
+    cfg.service(resource("/atom").route(get().to(atom::all_posts)))
+        .service(resource("/tags/{name}/atom").route(get().to(atom::posts_with_tag)));
 }
 
 fn api(cfg: &mut ServiceConfig) {
     cfg.service(
         resource(r"/days/{year:\d{4}}-{month:\d{2}}").route(get().to(api::days_in_year_month)),
     )
-    .service(resource("/year_months").route(get().to(api::year_months)));
+    .service(resource(r"/on/{month:\d{2}}-{day:\d{2}}").route(get().to(api::posts_on_this_day)))
+    .service(resource("/random").route(get().to(api::random_posts)))
+    .service(resource("/year_months").route(get().to(api::year_months)))
+    .service(resource("/tags").route(get().to(api::tags)))
+    .service(resource("/stats/monthly").route(get().to(api::monthly_stats)));
 }
 
 fn auth(cfg: &mut ServiceConfig) {
@@ -102,6 +137,31 @@ fn admin(cfg: &mut ServiceConfig) {
         .service(resource("/create").route(post().to(admin::create)))
         .service(resource("/update").route(post().to(admin::update)))
         .service(resource("/delete").route(post().to(admin::delete)))
+        .service(resource("/revisions").route(get().to(admin::revisions)))
+        .service(resource("/revision").route(get().to(admin::revision)))
+        .service(resource("/diff").route(get().to(admin::diff)))
+        .service(resource("/restore").route(post().to(admin::restore)))
+        .service(resource("/pin").route(post().to(admin::pin)))
+        .service(resource("/unpin").route(post().to(admin::unpin)))
+        .service(resource("/trash").route(get().to(admin::trash)))
+        .service(resource("/trash/restore").route(post().to(admin::restore_from_trash)))
+        .service(resource("/trash/purge").route(post().to(admin::purge)))
+        .service(resource("/pages").route(get().to(admin::pages)))
+        .service(resource("/pages/new").route(get().to(admin::new_page_form)))
+        .service(resource("/pages/edit").route(get().to(admin::edit_page_form)))
+        .service(resource("/pages/save").route(post().to(admin::save_page)))
+        .service(resource("/pages/delete").route(post().to(admin::delete_page)))
+        .service(resource("/media").route(get().to(admin::media)))
+        .service(resource("/media/upload").route(post().to(admin::upload_media)))
+        .service(resource("/media/delete").route(post().to(admin::delete_media)))
+        .service(resource("/series").route(get().to(admin::series)))
+        .service(resource("/series/edit").route(get().to(admin::edit_series_form)))
+        .service(resource("/series/create").route(post().to(admin::create_series)))
+        .service(resource("/series/update").route(post().to(admin::update_series)))
+        .service(resource("/series/add").route(post().to(admin::add_post_to_series)))
+        .service(resource("/series/remove").route(post().to(admin::remove_post_from_series)))
+        .service(resource("/series/move").route(post().to(admin::move_post_in_series)))
+        .service(resource("/series/delete").route(post().to(admin::delete_series)))
         .service(resource("").route(get().to(|| async {
             HttpResponse::Found()
                 .append_header((header::LOCATION, "/admin/"))
@@ -109,12 +169,99 @@ fn admin(cfg: &mut ServiceConfig) {
         })));
 }
 
-fn about(cfg: &mut ServiceConfig) {
-    cfg.service(resource("/about").route(get().to(about::about)));
+fn pages(cfg: &mut ServiceConfig) {
+    cfg.service(resource("/about").route(get().to(pages::about)))
+        .service(resource("/{name}").route(get().to(pages::page)));
 }
 
 fn admin_guard(ctx: &GuardContext) -> bool {
     ctx.req_data()
         .get::<AppContext>()
-        .map_or(false, |ctx| ctx.is_authorized)
+        .is_some_and(|ctx| ctx.is_authorized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{test, App};
+    use infrastructure::memory::MemoryStore;
+    use pretty_assertions::assert_eq;
+
+    #[actix_web::test]
+    async fn post_id_is_not_taken_as_year() -> anyhow::Result<()> {
+        // IDが2021の記事があり、2021年にも記事がある
+        let store = MemoryStore::from_seed(
+            r#"{
+                "posts": [
+                    { "title": "Old", "body": "old", "created_at": "2021-05-01T12:00:00Z" },
+                    { "id": 2021, "title": "Post 2021", "body": "hello", "created_at": "2024-01-01T12:00:00Z" }
+                ]
+            }"#,
+        )?;
+        let app =
+            test::init_service(App::new().configure(routing(Service::for_test(store)?))).await;
+
+        let res =
+            test::call_service(&app, test::TestRequest::get().uri("/2021").to_request()).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = String::from_utf8(test::read_body(res).await.to_vec())?;
+        assert!(body.contains("Post 2021"));
+
+        let res = test::call_service(
+            &app,
+            test::TestRequest::get().uri("/archive/2021").to_request(),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = String::from_utf8(test::read_body(res).await.to_vec())?;
+        assert!(body.contains("Old"));
+        assert!(!body.contains("Post 2021"));
+
+        // 記事のない年はIDとして扱わない
+        let res = test::call_service(
+            &app,
+            test::TestRequest::get().uri("/archive/2020").to_request(),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn atom_links_to_next_page() -> anyhow::Result<()> {
+        // フィードの1ページに収まらない数の記事がある
+        let posts = (1..=11)
+            .map(|i| {
+                format!(r#"{{ "title": "Post {i}", "body": "hello", "created_at": "2024-01-{i:02}T12:00:00Z" }}"#)
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let store = MemoryStore::from_seed(&format!(r#"{{ "posts": [{posts}] }}"#))?;
+        let app =
+            test::init_service(App::new().configure(routing(Service::for_test(store)?))).await;
+
+        let res =
+            test::call_service(&app, test::TestRequest::get().uri("/atom").to_request()).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = String::from_utf8(test::read_body(res).await.to_vec())?;
+        let next = body
+            .split(r#"<link rel="next" type="application/atom+xml" href=""#)
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .expect("rel=\"next\" link");
+        let next_path = &next[next.find("/atom?before=").expect("cursor URL")..];
+
+        let res =
+            test::call_service(&app, test::TestRequest::get().uri(next_path).to_request()).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = String::from_utf8(test::read_body(res).await.to_vec())?;
+        assert!(body.contains("Post 1<"));
+        assert!(!body.contains("Post 2<"));
+        // 自身へのリンクは先頭のページではなくカーソルのURLを指す
+        assert!(body.contains(&format!(
+            r#"<link rel="self" type="application/atom+xml" href="{next}""#
+        )));
+        assert!(!body.contains(r#"rel="next""#));
+        Ok(())
+    }
 }
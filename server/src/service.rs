@@ -1,18 +1,46 @@
-use super::Opts;
+use super::{Backend, Opts};
+use crate::presentation::posts::BodyAnalyzerImpl;
 use anyhow::{ensure, Context as _, Result};
-use application::models::Config;
+use application::{
+    adapters::{
+        MediaRepository, PagesRepository, PostQueryRepository, PostsRepository, SearchIndex,
+        SeriesRepository,
+    },
+    models::{Config, SearchEngine, SearchSettings},
+};
 use config::{builder::DefaultState, ConfigBuilder, File, FileFormat};
 use infrastructure::{
+    elasticsearch_index::ElasticsearchIndex,
+    fallback_search_index::FallbackSearchIndex,
     google_auth_cert_repository_impl::GoogleAuthCertRepositoryImpl,
-    posts_repository_impl::PostsRepositoryImpl, search_client::SearchClient,
+    image_processor_impl::ImageProcessorImpl,
+    local_media_storage::LocalMediaStorage,
+    media_repository_impl::MediaRepositoryImpl,
+    memory::{
+        MemoryMediaRepository, MemoryPagesRepository, MemoryPostQueryRepository,
+        MemoryPostsRepository, MemorySearchIndex, MemorySeriesRepository, MemoryStore,
+    },
+    pages_repository_impl::PagesRepositoryImpl,
+    post_query_repository_impl::PostQueryRepositoryImpl,
+    postgres,
+    postgres_search_index::PostgresSearchIndex,
+    posts_repository_impl::PostsRepositoryImpl,
+    series_repository_impl::SeriesRepositoryImpl,
 };
-use std::{env, path::PathBuf};
+use std::{env, path::PathBuf, sync::Arc};
 
 #[derive(Clone)] // FIXME: dieselのConnectionManagerがDebugを実装したらDebugにできる
 pub struct Service {
-    pub posts_repository: PostsRepositoryImpl,
+    pub posts_repository: Arc<dyn PostsRepository + Send + Sync>,
+    pub pages_repository: Arc<dyn PagesRepository + Send + Sync>,
+    pub series_repository: Arc<dyn SeriesRepository + Send + Sync>,
+    pub media_repository: Arc<dyn MediaRepository + Send + Sync>,
+    pub media_storage: LocalMediaStorage,
+    pub image_processor: ImageProcessorImpl,
+    pub body_analyzer: BodyAnalyzerImpl,
     pub cert_repository: GoogleAuthCertRepositoryImpl,
-    pub search_client: SearchClient,
+    pub post_query_repository: Arc<dyn PostQueryRepository + Send + Sync>,
+    pub search_index: Arc<dyn SearchIndex + Send + Sync>,
     pub admin_user_id: String,
     pub secret_key: String,
     pub static_path: PathBuf,
@@ -22,28 +50,33 @@ pub struct Service {
 
 impl Service {
     pub(crate) fn new(opts: &Opts) -> Result<Self> {
-        let es_url = url::Url::parse(&env::var("ES_URL")?)?;
-        let pg_url = url::Url::parse(&env::var("DATABASE_URL")?)?;
         let admin_user_id = env::var("ADMIN_USER_ID")?;
         let static_path = opts.static_path.clone();
         let secret_key = env::var("SECRET_KEY")?;
         ensure!(secret_key.len() >= 32, "SECRET_KEY is not long enough.");
 
-        if opts.migrate {
-            infrastructure::migration::migrate(&pg_url)?;
-        }
-
         let config_toml = include_str!("../../config.toml");
         let config = Self::get_config(config_toml)?;
 
-        let posts_repository = PostsRepositoryImpl::new(&pg_url)?;
+        let media_storage = LocalMediaStorage::new(&opts.media_path)?;
         let cert_repository = GoogleAuthCertRepositoryImpl::default();
-        let search_client = SearchClient::new(&es_url, &pg_url)?;
+
+        let repositories = match opts.backend {
+            Backend::Database => Repositories::database(opts, &config.search)?,
+            Backend::Memory => Repositories::memory(opts)?,
+        };
 
         Ok(Service {
-            posts_repository,
+            posts_repository: repositories.posts,
+            pages_repository: repositories.pages,
+            series_repository: repositories.series,
+            media_repository: repositories.media,
+            media_storage,
+            image_processor: ImageProcessorImpl,
+            body_analyzer: BodyAnalyzerImpl,
             cert_repository,
-            search_client,
+            post_query_repository: repositories.post_queries,
+            search_index: repositories.search_index,
             admin_user_id,
             secret_key,
             static_path,
@@ -96,3 +129,166 @@ impl Service {
         Ok(config)
     }
 }
+
+#[cfg(test)]
+impl Service {
+    /// `store`をメモリ上のリポジトリとして使うServiceを作ります。環境変数は読みません
+    pub(crate) fn for_test(store: MemoryStore) -> Result<Self> {
+        let config = ConfigBuilder::<DefaultState>::default()
+            .add_source(File::from_str(
+                include_str!("../../config.toml"),
+                FileFormat::Toml,
+            ))
+            .set_override("site.generator", "Nocturne")?
+            .set_override("site.hash", "")?
+            .set_override("auth.google_client_id", "")?
+            .set_override("auth.admin_user_id", "admin")?
+            .set_override("hatena_star_token", "")?
+            .set_override("ga_code", "")?
+            .build()?
+            .try_deserialize()?;
+        let repositories = Repositories::memory_store(store);
+        Ok(Service {
+            posts_repository: repositories.posts,
+            pages_repository: repositories.pages,
+            series_repository: repositories.series,
+            media_repository: repositories.media,
+            media_storage: LocalMediaStorage::new(std::env::temp_dir().join("nocturne-media"))?,
+            image_processor: ImageProcessorImpl,
+            body_analyzer: BodyAnalyzerImpl,
+            cert_repository: GoogleAuthCertRepositoryImpl::default(),
+            post_query_repository: repositories.post_queries,
+            search_index: repositories.search_index,
+            admin_user_id: "admin".to_owned(),
+            secret_key: "0".repeat(64),
+            static_path: PathBuf::from("./frontend/dist/assets"),
+            is_development: true,
+            config,
+        })
+    }
+}
+
+/// 記事などを保存する先。`--backend`で選びます
+struct Repositories {
+    posts: Arc<dyn PostsRepository + Send + Sync>,
+    pages: Arc<dyn PagesRepository + Send + Sync>,
+    series: Arc<dyn SeriesRepository + Send + Sync>,
+    media: Arc<dyn MediaRepository + Send + Sync>,
+    post_queries: Arc<dyn PostQueryRepository + Send + Sync>,
+    search_index: Arc<dyn SearchIndex + Send + Sync>,
+}
+
+impl Repositories {
+    /// `DATABASE_URL`のスキームでPostgreSQLかSQLiteかを選びます
+    fn database(opts: &Opts, search: &SearchSettings) -> Result<Self> {
+        ensure!(
+            opts.seed.is_none(),
+            "--seed can only be used with --backend memory."
+        );
+        let database_url = env::var("DATABASE_URL")?;
+        match database_url.strip_prefix("sqlite://") {
+            Some(path) => Self::sqlite(opts, path),
+            None => Self::postgres(opts, search),
+        }
+    }
+
+    /// PostgreSQLを使います。キーワード検索には設定に応じてElasticsearchかTantivyも使い、失敗したらPostgreSQLで検索し直します
+    ///
+    /// 各リポジトリはひとつのコネクションプールを共有します
+    fn postgres(opts: &Opts, search: &SearchSettings) -> Result<Self> {
+        let pg_url = url::Url::parse(&env::var("DATABASE_URL")?)?;
+        if opts.migrate {
+            infrastructure::migration::migrate(&pg_url)?;
+        }
+        let conn_pool = postgres::connect(&pg_url)?;
+        let posts = PostsRepositoryImpl::new(conn_pool.clone());
+        let search_index: Arc<dyn SearchIndex + Send + Sync> = match search.engine {
+            SearchEngine::Elasticsearch => {
+                let es_url = url::Url::parse(&env::var("ES_URL")?)?;
+                Arc::new(FallbackSearchIndex::new(
+                    ElasticsearchIndex::new(&es_url)?,
+                    PostgresSearchIndex::new(&posts),
+                ))
+            }
+            SearchEngine::Postgres => Arc::new(PostgresSearchIndex::new(&posts)),
+            SearchEngine::Tantivy => Self::tantivy_search_index(&posts, search)?,
+        };
+        Ok(Repositories {
+            post_queries: Arc::new(PostQueryRepositoryImpl::new(&posts)),
+            search_index,
+            posts: Arc::new(posts),
+            pages: Arc::new(PagesRepositoryImpl::new(conn_pool.clone())),
+            series: Arc::new(SeriesRepositoryImpl::new(conn_pool.clone())),
+            media: Arc::new(MediaRepositoryImpl::new(conn_pool)),
+        })
+    }
+
+    /// キーワード検索にTantivyの索引を使います。索引が空ならPostgreSQLの記事から作ります
+    #[cfg(feature = "tantivy")]
+    fn tantivy_search_index(
+        posts: &PostsRepositoryImpl,
+        search: &SearchSettings,
+    ) -> Result<Arc<dyn SearchIndex + Send + Sync>> {
+        use infrastructure::tantivy_index::TantivyIndex;
+        let index = TantivyIndex::open(&search.index_path, search.dictionary_path.as_deref())?;
+        index.rebuild_if_empty(posts)?;
+        Ok(Arc::new(FallbackSearchIndex::new(
+            index,
+            PostgresSearchIndex::new(posts),
+        )))
+    }
+
+    #[cfg(not(feature = "tantivy"))]
+    fn tantivy_search_index(
+        _posts: &PostsRepositoryImpl,
+        _search: &SearchSettings,
+    ) -> Result<Arc<dyn SearchIndex + Send + Sync>> {
+        anyhow::bail!("Tantivy is not supported. Build with the `tantivy` feature.")
+    }
+
+    /// SQLiteのファイル`path`に保存します。検索もSQLiteで行います
+    #[cfg(feature = "sqlite")]
+    fn sqlite(opts: &Opts, path: &str) -> Result<Self> {
+        use infrastructure::sqlite::{
+            self, SqliteMediaRepository, SqlitePagesRepository, SqlitePostQueryRepository,
+            SqlitePostsRepository, SqliteSearchIndex, SqliteSeriesRepository,
+        };
+        if opts.migrate {
+            sqlite::migrate(path)?;
+        }
+        let conn_pool = sqlite::connect(path)?;
+        Ok(Repositories {
+            posts: Arc::new(SqlitePostsRepository::new(conn_pool.clone())),
+            pages: Arc::new(SqlitePagesRepository::new(conn_pool.clone())),
+            series: Arc::new(SqliteSeriesRepository::new(conn_pool.clone())),
+            media: Arc::new(SqliteMediaRepository::new(conn_pool.clone())),
+            post_queries: Arc::new(SqlitePostQueryRepository::new(conn_pool.clone())),
+            search_index: Arc::new(SqliteSearchIndex::new(conn_pool)),
+        })
+    }
+
+    #[cfg(not(feature = "sqlite"))]
+    fn sqlite(_opts: &Opts, _path: &str) -> Result<Self> {
+        anyhow::bail!("SQLite is not supported. Build with the `sqlite` feature.")
+    }
+
+    /// メモリ上に保存します。`--seed`があればそのJSONファイルを読み込みます
+    fn memory(opts: &Opts) -> Result<Self> {
+        let store = match &opts.seed {
+            Some(path) => MemoryStore::from_seed_file(path)?,
+            None => MemoryStore::default(),
+        };
+        Ok(Self::memory_store(store))
+    }
+
+    fn memory_store(store: MemoryStore) -> Self {
+        Repositories {
+            posts: Arc::new(MemoryPostsRepository::new(store.clone())),
+            pages: Arc::new(MemoryPagesRepository::new(store.clone())),
+            series: Arc::new(MemorySeriesRepository::new(store.clone())),
+            media: Arc::new(MemoryMediaRepository::new(store.clone())),
+            post_queries: Arc::new(MemoryPostQueryRepository::new(store.clone())),
+            search_index: Arc::new(MemorySearchIndex::new(store)),
+        }
+    }
+}
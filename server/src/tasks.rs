@@ -0,0 +1,88 @@
+use crate::Service;
+use actix_web::rt::time;
+use application::use_cases::{
+    FillPostStatsUseCase, PublishScheduledPostsUseCase, PurgeExpiredPostsUseCase,
+};
+use chrono::Utc;
+use std::time::Duration;
+
+/// 予約投稿の公開日時を確認する間隔
+const PUBLISH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// ゴミ箱の保存期間を過ぎた記事を確認する間隔
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 公開日時を迎えた予約投稿を定期的に検索インデックスに反映します
+///
+/// 予約投稿も作成したときに検索インデックスに入れてあり、検索結果は公開日時で絞り込むので、
+/// 止まっていた間に公開日時を迎えた記事は拾い直さず、起動した時刻から確認します
+pub async fn publish_scheduled_posts(service: Service) {
+    let mut interval = time::interval(PUBLISH_INTERVAL);
+    let mut last_checked_at = Utc::now();
+    loop {
+        interval.tick().await;
+        let now = Utc::now();
+        match PublishScheduledPostsUseCase::execute(
+            &*service.posts_repository,
+            &*service.post_query_repository,
+            &*service.search_index,
+            last_checked_at,
+            now,
+        )
+        .await
+        {
+            Ok(posts) => {
+                for post in posts {
+                    log::info!("published scheduled post: {}", post.id);
+                }
+                last_checked_at = now;
+            }
+            Err(e) => {
+                // 失敗したときは次回に同じ範囲から確認し直す
+                log::warn!("failed to publish scheduled posts: {e}");
+            }
+        }
+    }
+}
+
+/// ゴミ箱に入れてから保存期間を過ぎた記事を定期的に完全に削除します
+pub async fn purge_expired_posts(service: Service) {
+    let mut interval = time::interval(PURGE_INTERVAL);
+    let retention = chrono::Duration::days(service.config.trash.retention_days.into());
+    loop {
+        interval.tick().await;
+        match PurgeExpiredPostsUseCase::execute(
+            &*service.posts_repository,
+            &*service.post_query_repository,
+            Utc::now() - retention,
+        )
+        .await
+        {
+            Ok(post_ids) => {
+                for post_id in post_ids {
+                    log::info!("purged post: {}", post_id);
+                }
+            }
+            Err(e) => log::warn!("failed to purge expired posts: {e}"),
+        }
+    }
+}
+
+/// 本文の統計をまだ数えていない記事について数えます
+///
+/// 統計を保存するようになる前に書かれた記事のために、起動時に一度だけ実行します
+pub async fn fill_post_stats(service: Service) {
+    match FillPostStatsUseCase::execute(
+        &*service.posts_repository,
+        &*service.post_query_repository,
+        &service.body_analyzer,
+    )
+    .await
+    {
+        Ok(post_ids) if !post_ids.is_empty() => {
+            log::info!("filled stats of {} posts", post_ids.len());
+        }
+        Ok(_) => {}
+        Err(e) => log::warn!("failed to fill post stats: {e}"),
+    }
+}